@@ -1,32 +1,53 @@
 use core::fmt;
 use std::{
+    env,
     fs::{read_to_string, write, File},
     io::Write,
     path::Path,
     sync::Arc,
+    time::Instant,
 };
 
-use miden_lib::{notes::create_p2id_note, transaction::TransactionKernel};
+use miden_lib::{
+    notes::{create_p2id_note, create_swap_note},
+    transaction::TransactionKernel,
+};
 use miden_objects::{
     accounts::AccountId,
-    assets::{Asset, FungibleAsset},
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
     crypto::rand::RpoRandomCoin,
     notes::NoteType,
-    transaction::{TransactionArgs, TransactionMeasurements, TransactionScript},
+    transaction::{
+        ExecutedTransaction, ProvenTransaction, TransactionArgs, TransactionMeasurements,
+        TransactionScript, TransactionWitness,
+    },
     Felt,
 };
-use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
+use miden_tx::{
+    prune_witness,
+    testing::{
+        mock_chain::{Auth, MockChain},
+        TransactionContextBuilder,
+    },
+    LocalTransactionProver, ProvingOptions, TransactionExecutor, TransactionProver,
+    TransactionVerifier,
+};
 use vm_processor::ONE;
 
 mod utils;
 use utils::{
     get_account_with_basic_authenticated_wallet, get_new_pk_and_authenticator,
-    write_bench_results_to_json, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+    write_bench_results_to_json, write_verification_results_to_json,
+    write_witness_size_results_to_json, VerificationMeasurements, WitnessSizeMeasurements,
+    ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
     ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER, DEFAULT_AUTH_SCRIPT,
 };
 pub enum Benchmark {
     Simple,
     P2ID,
+    VerifyBatch,
+    WitnessSizeP2ID,
+    WitnessSizeSwap,
 }
 
 impl fmt::Display for Benchmark {
@@ -34,11 +55,27 @@ impl fmt::Display for Benchmark {
         match self {
             Benchmark::Simple => write!(f, "simple"),
             Benchmark::P2ID => write!(f, "p2id"),
+            Benchmark::VerifyBatch => write!(f, "verify_batch"),
+            Benchmark::WitnessSizeP2ID => write!(f, "witness_size_p2id"),
+            Benchmark::WitnessSizeSwap => write!(f, "witness_size_swap"),
         }
     }
 }
 
+/// Command-line flag which enables the batch verification benchmark and checks that pruned
+/// transaction witnesses can still be proven. Both prove transactions, so they are much slower
+/// than the other benchmarks.
+const VERIFY_FLAG: &str = "--verify";
+
 fn main() -> Result<(), String> {
+    let mut run_verification = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            VERIFY_FLAG => run_verification = true,
+            _ => return Err(format!("Unknown argument {arg}, expected {VERIFY_FLAG}")),
+        }
+    }
+
     // create a template file for benchmark results
     let path = Path::new("bin/bench-tx/bench-tx.json");
     let mut file = File::create(path).map_err(|e| e.to_string())?;
@@ -53,6 +90,25 @@ fn main() -> Result<(), String> {
     // store benchmark results in the JSON file
     write_bench_results_to_json(path, benchmark_results)?;
 
+    // run the verification benchmark if requested and store its results in the same file
+    if run_verification {
+        let verification_results = benchmark_verify_batch()?;
+        write_verification_results_to_json(path, Benchmark::VerifyBatch, verification_results)?;
+    }
+
+    // compare the sizes of full and pruned transaction witnesses, and prove the pruned witnesses
+    // if requested
+    write_witness_size_results_to_json(
+        path,
+        Benchmark::WitnessSizeP2ID,
+        benchmark_witness_size(execute_p2id()?, run_verification)?,
+    )?;
+    write_witness_size_results_to_json(
+        path,
+        Benchmark::WitnessSizeSwap,
+        benchmark_witness_size(execute_swap()?, run_verification)?,
+    )?;
+
     Ok(())
 }
 
@@ -86,6 +142,35 @@ pub fn benchmark_default_tx() -> Result<TransactionMeasurements, String> {
 
 /// Runs the transaction which consumes a P2ID note into a basic wallet.
 pub fn benchmark_p2id() -> Result<TransactionMeasurements, String> {
+    Ok(execute_p2id()?.into())
+}
+
+/// Compares the size of the witness of the provided transaction with the size of its pruned
+/// witness.
+///
+/// If `prove_pruned_witness` is set, also checks that the pruned witness can still be proven.
+pub fn benchmark_witness_size(
+    executed_transaction: ExecutedTransaction,
+    prove_pruned_witness: bool,
+) -> Result<WitnessSizeMeasurements, String> {
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    let pruned_witness = prune_witness(tx_witness.clone());
+    let measurements = WitnessSizeMeasurements::new(&tx_witness, &pruned_witness);
+
+    if prove_pruned_witness {
+        LocalTransactionProver::new(ProvingOptions::default())
+            .prove(pruned_witness)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(measurements)
+}
+
+// TRANSACTIONS
+// ================================================================================================
+
+/// Executes the transaction which consumes a P2ID note into a basic wallet.
+fn execute_p2id() -> Result<ExecutedTransaction, String> {
     // Create assets
     let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
     let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
@@ -133,9 +218,102 @@ pub fn benchmark_p2id() -> Result<TransactionMeasurements, String> {
     let tx_args_target = TransactionArgs::with_tx_script(tx_script_target);
 
     // execute transaction
-    let executed_transaction = executor
+    executor
         .execute_transaction(target_account_id, block_ref, &note_ids, tx_args_target)
-        .unwrap();
+        .map_err(|e| e.to_string())
+}
 
-    Ok(executed_transaction.into())
+/// Executes the transaction which consumes a SWAP note with a basic wallet holding the requested
+/// asset.
+fn execute_swap() -> Result<ExecutedTransaction, String> {
+    let mut chain = MockChain::new();
+    let faucet = chain.add_existing_faucet(Auth::NoAuth, "POL", 100000u64);
+    let offered_asset = faucet.mint(100);
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let requested_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account = chain.add_existing_wallet(Auth::BasicAuth, vec![offered_asset]);
+    let target_account = chain.add_existing_wallet(Auth::BasicAuth, vec![requested_asset]);
+
+    // Create the note containing the SWAP script
+    let (note, _payback_note) = create_swap_note(
+        sender_account.id(),
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        Felt::new(27),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    chain.add_note(note);
+    chain.seal_block(None);
+
+    let tx_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, [], TransactionKernel::assembler())
+            .unwrap();
+
+    chain
+        .build_tx_context(target_account.id())
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .map_err(|e| e.to_string())
+}
+
+/// Number of transactions verified by the batch verification benchmark.
+const NUM_VERIFIED_TRANSACTIONS: usize = 32;
+
+/// Compares the throughput of verifying transactions one at a time with the throughput of
+/// [TransactionVerifier::verify_batch].
+pub fn benchmark_verify_batch() -> Result<VerificationMeasurements, String> {
+    let transaction = prove_default_tx()?;
+    let transactions = vec![transaction; NUM_VERIFIED_TRANSACTIONS];
+    let verifier = TransactionVerifier::new(0);
+
+    let start = Instant::now();
+    for transaction in transactions.iter() {
+        verifier.verify(transaction.clone()).map_err(|e| e.to_string())?;
+    }
+    let sequential = start.elapsed();
+
+    let start = Instant::now();
+    let results = verifier.verify_batch(&transactions);
+    let batch = start.elapsed();
+    results.into_iter().collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())?;
+
+    Ok(VerificationMeasurements::new(NUM_VERIFIED_TRANSACTIONS, sequential, batch))
+}
+
+/// Executes and proves the default transaction with empty transaction script and two default
+/// notes.
+fn prove_default_tx() -> Result<ProvenTransaction, String> {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor: TransactionExecutor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .map_err(|e| e.to_string())?;
+
+    LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.into())
+        .map_err(|e| e.to_string())
 }
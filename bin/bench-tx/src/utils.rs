@@ -1,13 +1,14 @@
 extern crate alloc;
 pub use alloc::{collections::BTreeMap, string::String};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use miden_lib::accounts::{auth::RpoFalcon512, wallets::BasicWallet};
 use miden_objects::{
     accounts::{Account, AccountId, AuthSecretKey},
     assets::{Asset, AssetVault},
     crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
-    transaction::TransactionMeasurements,
+    transaction::{TransactionMeasurements, TransactionWitness},
+    utils::Serializable,
     Felt, Word,
 };
 use miden_tx::auth::{BasicAuthenticator, TransactionAuthenticator};
@@ -22,6 +23,7 @@ use super::{read_to_string, write, Benchmark, Path};
 // ================================================================================================
 
 pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0x200000000000001f; // 2305843009213693983
+pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 = 0x300000000000002f; // 3458764513820540975
 pub const ACCOUNT_ID_SENDER: u64 = 0x800000000000001f; // 9223372036854775839
 pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = 0x900000000000003f; // 10376293541461622847
 
@@ -58,6 +60,61 @@ impl From<TransactionMeasurements> for MeasurementsPrinter {
     }
 }
 
+// VERIFICATION MEASUREMENTS
+// ================================================================================================
+
+/// Results of the batch verification benchmark.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationMeasurements {
+    num_transactions: usize,
+    sequential_ms: u128,
+    batch_ms: u128,
+    sequential_tx_per_sec: f64,
+    batch_tx_per_sec: f64,
+}
+
+impl VerificationMeasurements {
+    pub fn new(num_transactions: usize, sequential: Duration, batch: Duration) -> Self {
+        let throughput = |duration: Duration| num_transactions as f64 / duration.as_secs_f64();
+
+        VerificationMeasurements {
+            num_transactions,
+            sequential_ms: sequential.as_millis(),
+            batch_ms: batch.as_millis(),
+            sequential_tx_per_sec: throughput(sequential),
+            batch_tx_per_sec: throughput(batch),
+        }
+    }
+}
+
+// WITNESS SIZE MEASUREMENTS
+// ================================================================================================
+
+/// Results of the witness size benchmark.
+#[derive(Debug, Clone, Serialize)]
+pub struct WitnessSizeMeasurements {
+    witness_bytes: usize,
+    pruned_witness_bytes: usize,
+    advice_witness_bytes: usize,
+    pruned_advice_witness_bytes: usize,
+    reduction_percent: f64,
+}
+
+impl WitnessSizeMeasurements {
+    pub fn new(tx_witness: &TransactionWitness, pruned_witness: &TransactionWitness) -> Self {
+        let witness_bytes = tx_witness.to_bytes().len();
+        let pruned_witness_bytes = pruned_witness.to_bytes().len();
+
+        WitnessSizeMeasurements {
+            witness_bytes,
+            pruned_witness_bytes,
+            advice_witness_bytes: tx_witness.advice_witness.to_bytes().len(),
+            pruned_advice_witness_bytes: pruned_witness.advice_witness.to_bytes().len(),
+            reduction_percent: 100.0 * (1.0 - pruned_witness_bytes as f64 / witness_bytes as f64),
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -117,3 +174,39 @@ pub fn write_bench_results_to_json(
 
     Ok(())
 }
+
+pub fn write_verification_results_to_json(
+    path: &Path,
+    benchmark: Benchmark,
+    measurements: VerificationMeasurements,
+) -> Result<(), String> {
+    write_measurements_to_json(path, benchmark, measurements)
+}
+
+pub fn write_witness_size_results_to_json(
+    path: &Path,
+    benchmark: Benchmark,
+    measurements: WitnessSizeMeasurements,
+) -> Result<(), String> {
+    write_measurements_to_json(path, benchmark, measurements)
+}
+
+fn write_measurements_to_json(
+    path: &Path,
+    benchmark: Benchmark,
+    measurements: impl Serialize,
+) -> Result<(), String> {
+    let benchmark_file = read_to_string(path).map_err(|e| e.to_string())?;
+    let mut benchmark_json: Value = from_str(&benchmark_file).map_err(|e| e.to_string())?;
+
+    benchmark_json[benchmark.to_string()] =
+        serde_json::to_value(measurements).map_err(|e| e.to_string())?;
+
+    write(
+        path,
+        to_string_pretty(&benchmark_json).expect("failed to convert json to String"),
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
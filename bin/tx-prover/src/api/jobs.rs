@@ -0,0 +1,188 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use miden_tx_prover::generated::{JobState, JobStatusResponse, ProveTransactionResponse};
+use tonic::Status;
+
+/// Default time for which the jobs are kept after they finish.
+pub const DEFAULT_JOB_RETENTION: Duration = Duration::from_secs(600);
+
+// JOB TABLE
+// ================================================================================================
+
+/// In-memory table of the proving jobs submitted to a worker.
+///
+/// Finished jobs, together with their results, are kept for the configured retention period. Expired
+/// jobs are no longer reported, and they are removed when a new job is submitted afterwards.
+pub struct JobTable {
+    jobs: Mutex<HashMap<String, Job>>,
+    retention: Duration,
+}
+
+/// A proving job and its result.
+struct Job {
+    state: JobState,
+    submitted_at: u64,
+    started_at: Option<u64>,
+    finished_at: Option<u64>,
+    result: Option<Result<ProveTransactionResponse, String>>,
+}
+
+impl JobTable {
+    /// Returns a new [JobTable] which keeps finished jobs for the specified period.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            jobs: Mutex::new(HashMap::new()),
+            retention,
+        }
+    }
+
+    /// Adds a new queued job with the specified ID, and removes the expired jobs.
+    ///
+    /// # Errors
+    /// Returns an `already_exists` status if a job with the same ID is in the table.
+    pub fn insert(&self, job_id: String) -> Result<(), Status> {
+        let now = now();
+        let mut jobs = self.jobs.lock().expect("job table lock should not be poisoned");
+        jobs.retain(|_, job| !job.is_expired(now, self.retention));
+
+        if jobs.contains_key(&job_id) {
+            return Err(Status::already_exists(format!("Job {job_id} already exists")));
+        }
+
+        jobs.insert(
+            job_id,
+            Job {
+                state: JobState::Queued,
+                submitted_at: now,
+                started_at: None,
+                finished_at: None,
+                result: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Marks the specified job as being proven.
+    pub fn start(&self, job_id: &str) {
+        self.update(job_id, |job| {
+            job.state = JobState::Proving;
+            job.started_at = Some(now());
+        });
+    }
+
+    /// Marks the specified job as finished and stores its result.
+    pub fn finish(&self, job_id: &str, result: Result<ProveTransactionResponse, String>) {
+        self.update(job_id, |job| {
+            job.state = if result.is_ok() {
+                JobState::Done
+            } else {
+                JobState::Failed
+            };
+            job.finished_at = Some(now());
+            job.result = Some(result);
+        });
+    }
+
+    /// Returns the status of the specified job, or `None` if the job is not in the table or has
+    /// expired.
+    pub fn status(&self, job_id: &str) -> Option<JobStatusResponse> {
+        let jobs = self.jobs.lock().expect("job table lock should not be poisoned");
+        let job = jobs.get(job_id).filter(|job| !job.is_expired(now(), self.retention))?;
+
+        let mut status = JobStatusResponse {
+            job_id: job_id.to_string(),
+            state: 0,
+            submitted_at: job.submitted_at,
+            started_at: job.started_at,
+            finished_at: job.finished_at,
+            error: job.result.as_ref().and_then(|result| result.clone().err()),
+        };
+        status.set_state(job.state);
+        Some(status)
+    }
+
+    /// Returns the result of the specified job.
+    ///
+    /// # Errors
+    /// Returns:
+    /// - A `not_found` status if the job is not in the table or has expired.
+    /// - A `failed_precondition` status if the job has not finished yet.
+    /// - An `aborted` status if proving the transaction failed.
+    pub fn result(&self, job_id: &str) -> Result<ProveTransactionResponse, Status> {
+        let jobs = self.jobs.lock().expect("job table lock should not be poisoned");
+        let job = jobs
+            .get(job_id)
+            .filter(|job| !job.is_expired(now(), self.retention))
+            .ok_or_else(|| job_not_found(job_id))?;
+
+        match &job.result {
+            Some(Ok(response)) => Ok(response.clone()),
+            Some(Err(err)) => Err(Status::aborted(format!("Job {job_id} failed: {err}"))),
+            None => Err(Status::failed_precondition(format!("Job {job_id} has not finished yet"))),
+        }
+    }
+
+    /// Applies the provided update to the specified job, if it is in the table.
+    fn update(&self, job_id: &str, update: impl FnOnce(&mut Job)) {
+        let mut jobs = self.jobs.lock().expect("job table lock should not be poisoned");
+        if let Some(job) = jobs.get_mut(job_id) {
+            update(job);
+        }
+    }
+}
+
+impl Job {
+    /// Returns true if the job finished more than `retention` ago.
+    fn is_expired(&self, now: u64, retention: Duration) -> bool {
+        self.finished_at
+            .is_some_and(|finished_at| finished_at.saturating_add(retention.as_secs()) <= now)
+    }
+}
+
+impl Default for JobTable {
+    fn default() -> Self {
+        Self::new(DEFAULT_JOB_RETENTION)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the `not_found` status for the specified job.
+pub fn job_not_found(job_id: &str) -> Status {
+    Status::not_found(format!("Job {job_id} not found"))
+}
+
+/// Returns the current time in seconds since the UNIX epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("current time should be after the UNIX epoch")
+        .as_secs()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::JobTable;
+
+    #[test]
+    fn expired_jobs_are_not_reported() {
+        let jobs = JobTable::new(Duration::ZERO);
+        jobs.insert("job".to_string()).unwrap();
+        assert!(jobs.status("job").is_some());
+
+        // with zero retention, the job expires as soon as it finishes
+        jobs.finish("job", Err("proving failed".to_string()));
+        assert!(jobs.status("job").is_none());
+        assert_eq!(jobs.result("job").unwrap_err().code(), tonic::Code::NotFound);
+    }
+}
@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use axum::{routing::get, Router};
+use miden_objects::transaction::{ProvenTransaction, TransactionMeasurements};
+use miden_tx::TransactionProverError;
+use once_cell::sync::Lazy;
+use prometheus::{
+    exponential_buckets, register_histogram, register_int_counter, register_int_counter_vec,
+    register_int_gauge, Encoder, Histogram, IntCounter, IntCounterVec, IntGauge, TextEncoder,
+};
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+// WORKER METRICS
+// ================================================================================================
+
+/// Number of proving requests received by the worker, by gRPC method.
+pub static REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "tx_prover_worker_requests_total",
+        "Number of proving requests received by the worker",
+        &["method"]
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of proving requests rejected because the queue of the worker was full.
+pub static REJECTED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_worker_rejected_requests_total",
+        "Number of proving requests rejected because the queue was full"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of transactions proven by the worker, by result.
+pub static PROOFS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "tx_prover_worker_proofs_total",
+        "Number of transactions proven by the worker",
+        &["result"]
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of proving requests served from the proof cache of the worker.
+pub static CACHED_PROOFS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_worker_cached_proofs_total",
+        "Number of proving requests served from the proof cache"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Time taken to prove transactions.
+pub static PROVING_DURATION: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "tx_prover_worker_proving_duration_seconds",
+        "Time taken to prove a transaction",
+        exponential_buckets(0.25, 2.0, 12).expect("buckets should be valid")
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of transactions waiting for a prover.
+pub static QUEUED_PROOFS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "tx_prover_worker_queued_proofs",
+        "Number of transactions waiting for a prover"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of transactions being proven.
+pub static ACTIVE_PROOFS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!("tx_prover_worker_active_proofs", "Number of transactions being proven")
+        .expect("metric should be registered once")
+});
+
+// TRANSACTION METRICS
+// ================================================================================================
+
+/// Number of VM cycles of the proven transactions, as measured while building their execution
+/// trace, i.e., before the trace is padded to the next power of two.
+pub static TRANSACTION_CYCLES: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "tx_prover_transaction_cycles",
+        "Number of VM cycles of the proven transactions",
+        exponential_buckets(1024.0, 2.0, 14).expect("buckets should be valid")
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of input notes of the proven transactions.
+pub static TRANSACTION_INPUT_NOTES: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "tx_prover_transaction_input_notes",
+        "Number of input notes of the proven transactions",
+        exponential_buckets(1.0, 2.0, 11).expect("buckets should be valid")
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of output notes of the proven transactions.
+pub static TRANSACTION_OUTPUT_NOTES: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "tx_prover_transaction_output_notes",
+        "Number of output notes of the proven transactions",
+        exponential_buckets(1.0, 2.0, 11).expect("buckets should be valid")
+    )
+    .expect("metric should be registered once")
+});
+
+// HELPERS
+// ================================================================================================
+
+/// Records the result of proving a transaction with `num_input_notes` input notes in `duration`.
+pub fn record_proof(
+    num_input_notes: usize,
+    result: &Result<(ProvenTransaction, TransactionMeasurements), TransactionProverError>,
+    duration: Duration,
+) {
+    PROVING_DURATION.observe(duration.as_secs_f64());
+    TRANSACTION_INPUT_NOTES.observe(num_input_notes as f64);
+
+    match result {
+        Ok((proven_transaction, measurements)) => {
+            PROOFS.with_label_values(&["success"]).inc();
+            TRANSACTION_OUTPUT_NOTES.observe(proven_transaction.output_notes().num_notes() as f64);
+            TRANSACTION_CYCLES.observe(measurements.total_cycles() as f64);
+        },
+        Err(_) => PROOFS.with_label_values(&["failure"]).inc(),
+    }
+}
+
+/// Serves the metrics of the process in the Prometheus text format at the `/metrics` path.
+pub async fn serve_metrics(listener: TcpListener) {
+    match listener.local_addr() {
+        Ok(address) => info!("Metrics endpoint listening on {address}"),
+        Err(err) => error!("Failed to get the address of the metrics endpoint: {err}"),
+    }
+
+    let router = Router::new().route("/metrics", get(encode_metrics));
+    if let Err(err) = axum::serve(listener, router).await {
+        error!("Metrics endpoint failed: {err}");
+    }
+}
+
+/// Returns the metrics registered in the default registry in the Prometheus text format.
+async fn encode_metrics() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    if let Err(err) = TextEncoder::new().encode(&prometheus::gather(), &mut buffer) {
+        error!("Failed to encode metrics: {err}");
+    }
+    buffer
+}
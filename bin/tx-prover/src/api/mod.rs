@@ -1,31 +1,136 @@
+use std::{sync::Arc, time::Duration};
+
+use jobs::{job_not_found, JobTable};
+use metrics::{CACHED_PROOFS, REQUESTS};
 use miden_objects::transaction::TransactionWitness;
-use miden_tx::{
-    utils::{Deserializable, Serializable},
-    LocalTransactionProver, TransactionProver,
-};
-use miden_tx_prover::generated::{
-    api_server::{Api as ProverApi, ApiServer},
-    ProveTransactionRequest, ProveTransactionResponse,
+use miden_tx::{utils::Deserializable, ProvingOptions};
+use miden_tx_prover::{
+    generated::{
+        api_server::{Api as ProverApi, ApiServer},
+        GetQueueDepthRequest, GetQueueDepthResponse, HealthRequest, HealthResponse, JobRequest,
+        JobStatusResponse, ProveTransactionRequest, ProveTransactionResponse,
+        SubmitProvingJobResponse,
+    },
+    JOB_ID_HEADER, WITNESS_COMMITMENT_HEADER,
 };
-use tokio::{net::TcpListener, sync::Mutex};
+use pool::ProverPool;
+use tokio::net::TcpListener;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
+use crate::cache::{CacheLookup, ProofCache};
+
+pub mod jobs;
+pub mod metrics;
+pub mod pool;
+
 pub struct RpcListener {
     pub api_service: ApiServer<ProverRpcApi>,
     pub listener: TcpListener,
 }
 
 impl RpcListener {
-    pub fn new(listener: TcpListener) -> Self {
-        let api_service = ApiServer::new(ProverRpcApi::default());
+    pub fn new(
+        listener: TcpListener,
+        proving_options: ProvingOptions,
+        job_retention: Duration,
+        prover_pool: ProverPool,
+        proof_cache: ProofCache<ProveTransactionResponse>,
+    ) -> Self {
+        let api_service = ApiServer::new(ProverRpcApi::new(
+            proving_options,
+            job_retention,
+            prover_pool,
+            proof_cache,
+        ));
         Self { listener, api_service }
     }
 }
 
 #[derive(Default)]
 pub struct ProverRpcApi {
-    local_prover: Mutex<LocalTransactionProver>,
+    /// Provers shared by the direct proving requests and the proving jobs.
+    prover_pool: Arc<ProverPool>,
+    /// Options used for requests which do not specify their own proving options.
+    proving_options: ProvingOptions,
+    /// Proving jobs submitted through [ProverApi::submit_proving_job].
+    jobs: Arc<JobTable>,
+    /// Recently proven transactions, by commitment to the request which proved them.
+    proof_cache: Arc<ProofCache<ProveTransactionResponse>>,
+}
+
+impl ProverRpcApi {
+    pub fn new(
+        proving_options: ProvingOptions,
+        job_retention: Duration,
+        prover_pool: ProverPool,
+        proof_cache: ProofCache<ProveTransactionResponse>,
+    ) -> Self {
+        Self {
+            prover_pool: Arc::new(prover_pool),
+            proving_options,
+            jobs: Arc::new(JobTable::new(job_retention)),
+            proof_cache: Arc::new(proof_cache),
+        }
+    }
+
+    /// Returns the commitment to the provided request, which is the key of its proof in the
+    /// proof cache.
+    ///
+    /// # Errors
+    /// Returns an `invalid_argument` status if the request carries a commitment which does not
+    /// match its content, so that the proxy never caches a proof under the wrong commitment.
+    fn witness_commitment(request: &Request<ProveTransactionRequest>) -> Result<String, Status> {
+        let commitment = request.get_ref().witness_commitment();
+        if let Some(header) = request.metadata().get(WITNESS_COMMITMENT_HEADER) {
+            if header.to_str().map_err(invalid_argument)? != commitment {
+                return Err(Status::invalid_argument("Witness commitment does not match request"));
+            }
+        }
+        Ok(commitment)
+    }
+
+    /// Returns the ID of the job queried by the provided request.
+    ///
+    /// # Errors
+    /// Returns an `invalid_argument` status if the request carries a job ID header which does not
+    /// match the job ID of its body, since the proxy routes job queries by the header.
+    fn job_id(request: Request<JobRequest>) -> Result<String, Status> {
+        let header = match request.metadata().get(JOB_ID_HEADER) {
+            Some(header) => Some(header.to_str().map_err(invalid_argument)?.to_string()),
+            None => None,
+        };
+        let job_id = request.into_inner().job_id;
+        if header.is_some_and(|header| header != job_id) {
+            return Err(Status::invalid_argument("Job ID header does not match request"));
+        }
+        Ok(job_id)
+    }
+
+    /// Parses the transaction witness and the proving options of the provided request, using the
+    /// default proving options of the worker if the request does not specify its own.
+    fn parse_request(
+        &self,
+        request: ProveTransactionRequest,
+    ) -> Result<(TransactionWitness, ProvingOptions), Status> {
+        let proving_options = match request.proving_options {
+            Some(proving_options) => {
+                ProvingOptions::try_from(proving_options).map_err(invalid_argument)?
+            },
+            None => self.proving_options.clone(),
+        };
+
+        let transaction_witness = TransactionWitness::read_from_bytes(&request.transaction_witness)
+            .map_err(invalid_argument)?;
+
+        Ok((transaction_witness, proving_options))
+    }
+
+    /// Returns the pool which proves the transactions of this API.
+    #[cfg(test)]
+    pub fn prover_pool(&self) -> &Arc<ProverPool> {
+        &self.prover_pool
+    }
 }
 
 // We need to implement Send and Sync for the generated code to be able to use the prover in the
@@ -40,20 +145,111 @@ impl ProverApi for ProverRpcApi {
         request: Request<ProveTransactionRequest>,
     ) -> Result<Response<ProveTransactionResponse>, tonic::Status> {
         info!("Received request to prove transaction");
+        REQUESTS.with_label_values(&["prove_transaction"]).inc();
 
-        // Try to acquire a permit without waiting
-        let prover = self
-            .local_prover
-            .try_lock()
-            .map_err(|_| Status::resource_exhausted("Server is busy handling another request"))?;
+        // Serve the proof from the cache, or wait for an identical request in flight
+        let commitment = Self::witness_commitment(&request)?;
+        let pending_proof = match self.proof_cache.lookup(&commitment).await {
+            CacheLookup::Hit(response) => {
+                info!("Serving cached proof");
+                CACHED_PROOFS.inc();
+                return Ok(Response::new(response));
+            },
+            CacheLookup::Miss(pending_proof) => pending_proof,
+        };
 
-        let transaction_witness =
-            TransactionWitness::read_from_bytes(&request.get_ref().transaction_witness)
-                .map_err(invalid_argument)?;
+        let (transaction_witness, proving_options) = self.parse_request(request.into_inner())?;
 
-        let proof = prover.prove(transaction_witness).map_err(internal_error)?;
+        // Wait in the queue until a prover is available, unless the queue is already full
+        let ticket = self.prover_pool.enqueue()?;
+        let response = ticket
+            .prove(transaction_witness, proving_options, || {})
+            .await
+            .map_err(internal_error)?;
+        pending_proof.complete(response.clone());
 
-        Ok(Response::new(ProveTransactionResponse { proven_transaction: proof.to_bytes() }))
+        Ok(Response::new(response))
+    }
+
+    async fn submit_proving_job(
+        &self,
+        request: Request<ProveTransactionRequest>,
+    ) -> Result<Response<SubmitProvingJobResponse>, Status> {
+        REQUESTS.with_label_values(&["submit_proving_job"]).inc();
+
+        // Use the job ID assigned by the proxy, if any
+        let job_id = match request.metadata().get(JOB_ID_HEADER) {
+            Some(job_id) => job_id.to_str().map_err(invalid_argument)?.to_string(),
+            None => rand::random::<u64>().to_string(),
+        };
+
+        let commitment = Self::witness_commitment(&request)?;
+        let (transaction_witness, proving_options) = self.parse_request(request.into_inner())?;
+        let ticket = self.prover_pool.enqueue()?;
+        self.jobs.insert(job_id.clone())?;
+        info!("Received proving job {job_id}");
+
+        // The job waits in the queue until a prover is available, unless its proof is cached or
+        // in flight
+        let jobs = self.jobs.clone();
+        let proof_cache = self.proof_cache.clone();
+        let task_job_id = job_id.clone();
+        tokio::spawn(async move {
+            let pending_proof = match proof_cache.lookup(&commitment).await {
+                CacheLookup::Hit(response) => {
+                    info!("Serving cached proof for proving job {task_job_id}");
+                    CACHED_PROOFS.inc();
+                    jobs.start(&task_job_id);
+                    jobs.finish(&task_job_id, Ok(response));
+                    return;
+                },
+                CacheLookup::Miss(pending_proof) => pending_proof,
+            };
+
+            let result = ticket
+                .prove(transaction_witness, proving_options, || jobs.start(&task_job_id))
+                .await
+                .map_err(|err| format!("{:?}", err));
+            if let Ok(response) = &result {
+                pending_proof.complete(response.clone());
+            }
+            jobs.finish(&task_job_id, result);
+        });
+
+        Ok(Response::new(SubmitProvingJobResponse { job_id }))
+    }
+
+    async fn get_job_status(
+        &self,
+        request: Request<JobRequest>,
+    ) -> Result<Response<JobStatusResponse>, Status> {
+        let job_id = Self::job_id(request)?;
+        let status = self.jobs.status(&job_id).ok_or_else(|| job_not_found(&job_id))?;
+
+        Ok(Response::new(status))
+    }
+
+    async fn get_job_result(
+        &self,
+        request: Request<JobRequest>,
+    ) -> Result<Response<ProveTransactionResponse>, Status> {
+        let job_id = Self::job_id(request)?;
+
+        Ok(Response::new(self.jobs.result(&job_id)?))
+    }
+
+    async fn get_queue_depth(
+        &self,
+        _request: Request<GetQueueDepthRequest>,
+    ) -> Result<Response<GetQueueDepthResponse>, Status> {
+        Ok(Response::new(self.prover_pool.queue_depth()))
+    }
+
+    async fn health(
+        &self,
+        _request: Request<HealthRequest>,
+    ) -> Result<Response<HealthResponse>, Status> {
+        Ok(Response::new(HealthResponse {}))
     }
 }
 
@@ -0,0 +1,166 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
+use miden_objects::transaction::TransactionWitness;
+use miden_tx::{LocalTransactionProver, ProvingOptions, TransactionProverError};
+use miden_tx_prover::generated::{GetQueueDepthResponse, ProveTransactionResponse};
+use tokio::sync::Semaphore;
+use tonic::Status;
+use tracing::info;
+
+use super::metrics::{record_proof, ACTIVE_PROOFS, QUEUED_PROOFS, REJECTED_REQUESTS};
+
+/// Default maximum number of transactions proven at the same time by a worker.
+pub const DEFAULT_MAX_CONCURRENT_PROOFS: usize = 1;
+
+/// Default maximum number of transactions waiting to be proven by a worker.
+pub const DEFAULT_MAX_QUEUED_PROOFS: usize = 10;
+
+// PROVER POOL
+// ================================================================================================
+
+/// A pool of [LocalTransactionProver]s which proves up to a configured number of transactions at
+/// the same time.
+///
+/// Transactions which arrive while all provers are busy wait in a bounded queue until a prover is
+/// released. Only when the queue is full are new transactions rejected.
+pub struct ProverPool {
+    /// Permits to prove a transaction, one for each prover in the pool.
+    permits: Semaphore,
+    /// Idle provers, which are re-used for transactions proven with the same options.
+    provers: Mutex<Vec<LocalTransactionProver>>,
+    /// Number of transactions waiting for a prover.
+    queued_proofs: AtomicUsize,
+    max_concurrent_proofs: usize,
+    max_queued_proofs: usize,
+}
+
+impl ProverPool {
+    /// Returns a new [ProverPool] which proves up to `max_concurrent_proofs` transactions at the
+    /// same time, and queues up to `max_queued_proofs` transactions.
+    ///
+    /// Every transaction takes a place in the queue until a prover is available for it, even if
+    /// a prover is idle.
+    ///
+    /// # Panics
+    /// Panics if `max_concurrent_proofs` or `max_queued_proofs` is zero.
+    pub fn new(max_concurrent_proofs: usize, max_queued_proofs: usize) -> Self {
+        assert!(max_concurrent_proofs > 0, "pool should contain at least one prover");
+        assert!(max_queued_proofs > 0, "queue should hold at least one transaction");
+        Self {
+            permits: Semaphore::new(max_concurrent_proofs),
+            provers: Mutex::new(Vec::new()),
+            queued_proofs: AtomicUsize::new(0),
+            max_concurrent_proofs,
+            max_queued_proofs,
+        }
+    }
+
+    /// Reserves a place in the queue of this pool for a new transaction.
+    ///
+    /// # Errors
+    /// Returns a `resource_exhausted` status if the queue is full.
+    pub fn enqueue(self: &Arc<Self>) -> Result<QueueTicket, Status> {
+        self.queued_proofs
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < self.max_queued_proofs).then_some(queued + 1)
+            })
+            .map_err(|_| {
+                REJECTED_REQUESTS.inc();
+                Status::resource_exhausted("Prover queue is full")
+            })?;
+        QUEUED_PROOFS.inc();
+
+        Ok(QueueTicket { pool: self.clone() })
+    }
+
+    /// Returns the current depth of the queue of this pool.
+    pub fn queue_depth(&self) -> GetQueueDepthResponse {
+        GetQueueDepthResponse {
+            queued_proofs: self.queued_proofs.load(Ordering::SeqCst) as u32,
+            active_proofs: (self.max_concurrent_proofs - self.permits.available_permits()) as u32,
+            max_concurrent_proofs: self.max_concurrent_proofs as u32,
+            max_queued_proofs: self.max_queued_proofs as u32,
+        }
+    }
+
+    /// Returns an idle prover which uses the provided proving options, creating a new one if
+    /// none is available.
+    fn take_prover(&self, proving_options: ProvingOptions) -> LocalTransactionProver {
+        let mut provers = self.provers.lock().expect("prover pool lock should not be poisoned");
+        match provers.iter().position(|prover| prover.proof_options() == &proving_options) {
+            Some(index) => provers.swap_remove(index),
+            None => LocalTransactionProver::new(proving_options),
+        }
+    }
+
+    /// Returns the provided prover to the idle provers of this pool.
+    fn release_prover(&self, prover: LocalTransactionProver) {
+        let mut provers = self.provers.lock().expect("prover pool lock should not be poisoned");
+        provers.push(prover);
+    }
+}
+
+impl Default for ProverPool {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_PROOFS, DEFAULT_MAX_QUEUED_PROOFS)
+    }
+}
+
+// QUEUE TICKET
+// ================================================================================================
+
+/// A place in the queue of a [ProverPool], which is released when the ticket is dropped.
+pub struct QueueTicket {
+    pool: Arc<ProverPool>,
+}
+
+impl QueueTicket {
+    /// Waits until a prover of the pool is available and proves the transaction with it.
+    ///
+    /// `on_start` is called when the transaction leaves the queue and starts being proven.
+    pub async fn prove(
+        self,
+        transaction_witness: TransactionWitness,
+        proving_options: ProvingOptions,
+        on_start: impl FnOnce(),
+    ) -> Result<ProveTransactionResponse, TransactionProverError> {
+        let pool = self.pool.clone();
+        let _permit = pool.permits.acquire().await.expect("semaphore should not be closed");
+
+        // Leave the queue
+        drop(self);
+        on_start();
+
+        let num_input_notes = transaction_witness.tx_inputs.input_notes().num_notes();
+        let prover = pool.take_prover(proving_options);
+        let started_at = Instant::now();
+        ACTIVE_PROOFS.inc();
+        let (prover, result) = tokio::task::spawn_blocking(move || {
+            let result = prover.prove_with_measurements(transaction_witness);
+            (prover, result)
+        })
+        .await
+        .expect("proving task should not panic");
+        ACTIVE_PROOFS.dec();
+        record_proof(num_input_notes, &result, started_at.elapsed());
+        pool.release_prover(prover);
+
+        let (proof, _) = result?;
+        info!("Transaction proven with {} bits of security", proof.proof().security_level());
+
+        Ok(ProveTransactionResponse::from(proof))
+    }
+}
+
+impl Drop for QueueTicket {
+    fn drop(&mut self) {
+        self.pool.queued_proofs.fetch_sub(1, Ordering::SeqCst);
+        QUEUED_PROOFS.dec();
+    }
+}
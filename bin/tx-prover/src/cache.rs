@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::watch;
+
+/// Default maximum number of proofs kept in a [ProofCache].
+pub const DEFAULT_PROOF_CACHE_SIZE: usize = 100;
+
+/// Default time for which a proof is kept in a [ProofCache].
+pub const DEFAULT_PROOF_CACHE_TTL: Duration = Duration::from_secs(600);
+
+// PROOF CACHE
+// ================================================================================================
+
+/// A cache of proofs, keyed by the commitment to the request which produced them.
+///
+/// Proofs are kept for a configured time, and the oldest proofs are evicted once the cache holds
+/// the configured number of proofs. A cache of size zero stores nothing.
+///
+/// Identical requests which arrive while the first one is being proven do not trigger a new
+/// proof: they wait for the proof in flight and receive it once it is cached. If the proof in
+/// flight fails, one of the waiting requests proves the transaction instead.
+pub struct ProofCache<V> {
+    entries: Mutex<HashMap<String, CacheEntry<V>>>,
+    max_entries: usize,
+    ttl: Duration,
+}
+
+/// An entry of a [ProofCache].
+enum CacheEntry<V> {
+    /// A proof in flight. The receiver is notified once the proof is cached or abandoned.
+    Pending(watch::Receiver<()>),
+    /// A cached proof.
+    Ready { value: V, cached_at: Instant },
+}
+
+/// Result of a [ProofCache] lookup.
+pub enum CacheLookup<V> {
+    /// The proof was cached.
+    Hit(V),
+    /// The proof was not cached, and must be generated by the caller.
+    Miss(PendingProof<V>),
+}
+
+impl<V: Clone> ProofCache<V> {
+    /// Returns a new [ProofCache] which keeps up to `max_entries` proofs for `ttl` each.
+    pub fn new(max_entries: usize, ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            max_entries,
+            ttl,
+        }
+    }
+
+    /// Returns the proof cached for the provided key.
+    ///
+    /// If the proof for the key is in flight, this method waits until it is cached or abandoned.
+    /// If the proof is not cached, the key is marked as in flight until the returned
+    /// [PendingProof] is completed or dropped.
+    pub async fn lookup(self: &Arc<Self>, key: &str) -> CacheLookup<V> {
+        if self.max_entries == 0 {
+            return CacheLookup::Miss(PendingProof {
+                cache: self.clone(),
+                key: None,
+                _notifier: None,
+            });
+        }
+
+        loop {
+            // We use a new scope to release the lock before waiting for the proof in flight
+            let mut receiver = {
+                let mut entries = self.lock_entries();
+                match entries.get(key) {
+                    Some(CacheEntry::Ready { value, cached_at })
+                        if cached_at.elapsed() < self.ttl =>
+                    {
+                        return CacheLookup::Hit(value.clone());
+                    },
+                    Some(CacheEntry::Pending(receiver)) => receiver.clone(),
+                    _ => {
+                        let (notifier, receiver) = watch::channel(());
+                        entries.insert(key.to_string(), CacheEntry::Pending(receiver));
+                        return CacheLookup::Miss(PendingProof {
+                            cache: self.clone(),
+                            key: Some(key.to_string()),
+                            _notifier: Some(notifier),
+                        });
+                    },
+                }
+            };
+
+            // The notifier is dropped once the proof is cached or abandoned
+            let _ = receiver.changed().await;
+        }
+    }
+
+    /// Caches the provided proof, evicting the expired proofs and, if the cache is full, the
+    /// oldest ones.
+    fn insert(&self, key: String, value: V) {
+        let mut entries = self.lock_entries();
+        entries.retain(|_, entry| match entry {
+            CacheEntry::Ready { cached_at, .. } => cached_at.elapsed() < self.ttl,
+            CacheEntry::Pending(_) => true,
+        });
+
+        let mut cached_proofs = entries
+            .iter()
+            .filter_map(|(key, entry)| match entry {
+                CacheEntry::Ready { cached_at, .. } => Some((*cached_at, key.clone())),
+                CacheEntry::Pending(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if cached_proofs.len() >= self.max_entries {
+            cached_proofs.sort_unstable();
+            for (_, key) in &cached_proofs[..=cached_proofs.len() - self.max_entries] {
+                entries.remove(key);
+            }
+        }
+
+        entries.insert(key, CacheEntry::Ready { value, cached_at: Instant::now() });
+    }
+}
+
+impl<V: Clone> Default for ProofCache<V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROOF_CACHE_SIZE, DEFAULT_PROOF_CACHE_TTL)
+    }
+}
+
+impl<V> ProofCache<V> {
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, CacheEntry<V>>> {
+        self.entries.lock().expect("proof cache lock should not be poisoned")
+    }
+}
+
+// PENDING PROOF
+// ================================================================================================
+
+/// A proof in flight in a [ProofCache].
+///
+/// Identical requests wait for the proof until it is completed with [PendingProof::complete], or
+/// until this value is dropped, in which case the proof is abandoned.
+pub struct PendingProof<V> {
+    cache: Arc<ProofCache<V>>,
+    /// Key of the proof, or `None` if the cache is disabled.
+    key: Option<String>,
+    /// Notifies the waiting requests when dropped.
+    _notifier: Option<watch::Sender<()>>,
+}
+
+impl<V: Clone> PendingProof<V> {
+    /// Caches the provided proof, and hands it to the requests waiting for it.
+    pub fn complete(mut self, value: V) {
+        if let Some(key) = self.key.take() {
+            self.cache.insert(key, value);
+        }
+    }
+}
+
+impl<V> Drop for PendingProof<V> {
+    fn drop(&mut self) {
+        // Abandon the proof if it was not completed
+        if let Some(key) = &self.key {
+            let mut entries = self.cache.lock_entries();
+            if let Some(CacheEntry::Pending(_)) = entries.get(key) {
+                entries.remove(key);
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::{CacheLookup, ProofCache};
+
+    #[tokio::test]
+    async fn proof_cache_expiry_and_eviction() {
+        let cache = Arc::new(ProofCache::new(1, Duration::from_millis(300)));
+
+        // A proof in flight is handed to the identical requests waiting for it
+        let CacheLookup::Miss(pending_proof) = cache.lookup("a").await else {
+            panic!("proof should not be cached");
+        };
+        let waiting_request = tokio::spawn({
+            let cache = cache.clone();
+            async move { matches!(cache.lookup("a").await, CacheLookup::Hit(1)) }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting_request.is_finished());
+        pending_proof.complete(1);
+        assert!(waiting_request.await.unwrap());
+
+        // An abandoned proof is proven by one of the requests waiting for it
+        let CacheLookup::Miss(pending_proof) = cache.lookup("b").await else {
+            panic!("proof should not be cached");
+        };
+        let waiting_request = tokio::spawn({
+            let cache = cache.clone();
+            async move { matches!(cache.lookup("b").await, CacheLookup::Miss(_)) }
+        });
+        drop(pending_proof);
+        assert!(waiting_request.await.unwrap());
+
+        // The oldest proof is evicted once the cache is full
+        let CacheLookup::Miss(pending_proof) = cache.lookup("c").await else {
+            panic!("proof should not be cached");
+        };
+        pending_proof.complete(3);
+        assert!(matches!(cache.lookup("a").await, CacheLookup::Miss(_)));
+        assert!(matches!(cache.lookup("c").await, CacheLookup::Hit(3)));
+
+        // Proofs expire after the TTL
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(matches!(cache.lookup("c").await, CacheLookup::Miss(_)));
+
+        // A cache of size zero stores nothing
+        let cache = Arc::new(ProofCache::new(0, Duration::from_secs(60)));
+        let CacheLookup::Miss(pending_proof) = cache.lookup("a").await else {
+            panic!("proof should not be cached");
+        };
+        pending_proof.complete(1);
+        assert!(matches!(cache.lookup("a").await, CacheLookup::Miss(_)));
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fs::File, io::Write};
 
 use clap::Parser;
+use miden_objects::utils::bytes_to_hex_string;
 use miden_tx_prover::PROVER_SERVICE_CONFIG_FILE_NAME;
 
 use crate::commands::ProxyConfig;
@@ -14,7 +15,7 @@ impl Init {
     ///
     /// This method will create a new config file names
     /// [miden_tx_prover::PROVER_SERVICE_CONFIG_FILE_NAME] in the current working directory with
-    /// default values.
+    /// default values, and a random token for the admin endpoint of the proxy.
     pub fn execute(&self) -> Result<(), String> {
         let mut current_dir = std::env::current_dir().map_err(|err| err.to_string())?;
         current_dir.push(PROVER_SERVICE_CONFIG_FILE_NAME);
@@ -27,7 +28,10 @@ impl Init {
             .to_string());
         }
 
-        let cli_config = ProxyConfig::default();
+        let cli_config = ProxyConfig {
+            admin_token: Some(generate_admin_token()),
+            ..ProxyConfig::default()
+        };
 
         let config_as_toml_string = toml::to_string_pretty(&cli_config)
             .map_err(|err| format!("Error formatting config: {err}"))?;
@@ -47,3 +51,8 @@ impl Init {
         Ok(())
     }
 }
+
+/// Returns a random token for the admin endpoint of the proxy, encoded in hex.
+fn generate_admin_token() -> String {
+    bytes_to_hex_string(rand::random::<[u8; 32]>())
+}
@@ -1,18 +1,29 @@
 use clap::Parser;
 use init::Init;
+use offline::{Prove, Verify};
 use proxy::StartProxy;
 use serde::{Deserialize, Serialize};
+use update_workers::{AddWorker, RemoveWorker};
 use worker::StartWorker;
 
+use crate::{
+    cache::{DEFAULT_PROOF_CACHE_SIZE, DEFAULT_PROOF_CACHE_TTL},
+    proxy::selection::LoadBalancingStrategy,
+};
+
 pub mod init;
+pub mod offline;
 pub mod proxy;
+pub mod update_workers;
 pub mod worker;
 
 /// Configuration of the proxy.
 ///
 /// It is stored in a TOML file, which will be created by the `init` command.
-/// It allows manual modification of the configuration file.
+/// It allows manual modification of the configuration file. Settings missing from the file are
+/// set to their default values, so that configuration files created by older versions still load.
 #[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProxyConfig {
     /// List of workers used by the proxy.
     pub workers: Vec<WorkerConfig>,
@@ -24,12 +35,45 @@ pub struct ProxyConfig {
     pub timeout_secs: u64,
     /// Maximum time in seconds to establish a connection.
     pub connection_timeout_secs: u64,
-    /// Maximum number of items in the queue.
+    /// Maximum number of items in the queue of each worker.
     pub max_queue_items: usize,
+    /// Strategy used to select the worker which receives a request.
+    pub load_balancing_strategy: LoadBalancingStrategy,
     /// Maximum number of retries per request.
     pub max_retries_per_request: usize,
-    /// Maximum number of requests per second per IP address.
+    /// Maximum number of requests per second per IP address, for clients without an API key.
     pub max_req_per_sec: isize,
+    /// Time in seconds for which the worker of a proving job is remembered after the result of the
+    /// job is returned or, until then, after the job was last submitted or queried.
+    pub job_retention_secs: u64,
+    /// Interval in seconds between the health checks of the workers.
+    pub health_check_interval_secs: u64,
+    /// Maximum time in seconds for a worker to respond to a health check.
+    pub health_check_timeout_secs: u64,
+    /// Number of consecutive successful health checks after which an unhealthy worker is added
+    /// back to the rotation.
+    pub healthy_threshold: usize,
+    /// Number of consecutive failed health checks after which a worker is removed from the
+    /// rotation.
+    pub unhealthy_threshold: usize,
+    /// Host of the admin endpoint of the proxy. By default, the endpoint is only reachable from
+    /// the machine running the proxy.
+    pub admin_host: String,
+    /// Port of the admin endpoint of the proxy.
+    pub admin_port: u16,
+    /// Token which must be sent as a bearer token in the `Authorization` header of every request
+    /// to the admin endpoint. If not set, the admin endpoint is disabled.
+    pub admin_token: Option<String>,
+    /// Port of the Prometheus metrics endpoint of the proxy.
+    pub metrics_port: u16,
+    /// API keys accepted by the proxy. If any API key is configured, requests without a known API
+    /// key are rejected.
+    pub api_keys: Vec<ApiKeyConfig>,
+    /// Maximum number of proofs kept in the proof cache of the proxy. A size of zero disables
+    /// the cache.
+    pub proof_cache_size: usize,
+    /// Time in seconds for which a proof is kept in the proof cache of the proxy.
+    pub proof_cache_ttl_secs: u64,
 }
 
 impl Default for ProxyConfig {
@@ -41,25 +85,73 @@ impl Default for ProxyConfig {
             timeout_secs: 100,
             connection_timeout_secs: 10,
             max_queue_items: 10,
+            load_balancing_strategy: LoadBalancingStrategy::RoundRobin,
             max_retries_per_request: 1,
             max_req_per_sec: 5,
+            job_retention_secs: 600,
+            health_check_interval_secs: 10,
+            health_check_timeout_secs: 5,
+            healthy_threshold: 2,
+            unhealthy_threshold: 3,
+            admin_host: "127.0.0.1".into(),
+            admin_port: 8090,
+            admin_token: None,
+            metrics_port: 6192,
+            api_keys: vec![],
+            proof_cache_size: DEFAULT_PROOF_CACHE_SIZE,
+            proof_cache_ttl_secs: DEFAULT_PROOF_CACHE_TTL.as_secs(),
         }
     }
 }
 
+impl ProxyConfig {
+    /// Adds the provided worker to the list of workers.
+    ///
+    /// Returns `false` if the worker is already in the list.
+    pub fn add_worker(&mut self, worker: WorkerConfig) -> bool {
+        if self.workers.contains(&worker) {
+            return false;
+        }
+        self.workers.push(worker);
+        true
+    }
+
+    /// Removes the provided worker from the list of workers.
+    ///
+    /// Returns `false` if the worker is not in the list.
+    pub fn remove_worker(&mut self, worker: &WorkerConfig) -> bool {
+        let num_workers = self.workers.len();
+        self.workers.retain(|w| w != worker);
+        self.workers.len() != num_workers
+    }
+}
+
 /// Configuration for a worker
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkerConfig {
     pub host: String,
     pub port: u16,
 }
 
 impl WorkerConfig {
-    fn new(host: &str, port: u16) -> Self {
+    pub fn new(host: &str, port: u16) -> Self {
         Self { host: host.into(), port }
     }
 }
 
+/// Configuration for an API key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKeyConfig {
+    /// Name of the client which uses the key, used in the logs and metrics of the proxy.
+    pub name: String,
+    /// Secret key sent by the client.
+    pub key: String,
+    /// Maximum number of requests per second made with the key.
+    pub max_req_per_sec: isize,
+    /// Maximum number of proofs requested with the key per day.
+    pub max_proofs_per_day: u64,
+}
+
 /// Root CLI struct
 #[derive(Parser, Debug)]
 #[clap(
@@ -86,6 +178,21 @@ pub enum Command {
     StartWorker(StartWorker),
     /// Starts the proxy defined in the config file.
     StartProxy(StartProxy),
+    /// Adds a worker to the proxy.
+    ///
+    /// The worker is added to the running proxy through its admin endpoint, and to the config
+    /// file.
+    AddWorker(AddWorker),
+    /// Removes a worker from the proxy.
+    ///
+    /// The proxy stops sending new requests to the worker, and the worker is removed from the
+    /// config file.
+    RemoveWorker(RemoveWorker),
+    /// Proves a serialized transaction witness locally and writes the proven transaction to a
+    /// file.
+    Prove(Prove),
+    /// Verifies a serialized proven transaction at the chosen security level.
+    Verify(Verify),
 }
 
 /// CLI entry point
@@ -99,6 +206,18 @@ impl Cli {
                 rt.block_on(worker_init.execute())
             },
             Command::StartProxy(proxy_init) => proxy_init.execute(),
+            Command::AddWorker(add_worker) => {
+                let rt = tokio::runtime::Runtime::new()
+                    .map_err(|e| format!("Failed to create runtime: {:?}", e))?;
+                rt.block_on(add_worker.execute())
+            },
+            Command::RemoveWorker(remove_worker) => {
+                let rt = tokio::runtime::Runtime::new()
+                    .map_err(|e| format!("Failed to create runtime: {:?}", e))?;
+                rt.block_on(remove_worker.execute())
+            },
+            Command::Prove(prove) => prove.execute(),
+            Command::Verify(verify) => verify.execute(),
             Command::Init(init) => {
                 // Init does not require async, so run directly
                 init.execute()
@@ -106,3 +225,43 @@ impl Cli {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProxyConfig, WorkerConfig};
+
+    #[test]
+    fn config_without_new_settings_loads() {
+        // Settings missing from the configuration file are set to their default values
+        let config: ProxyConfig = toml::from_str(
+            r#"
+            host = "127.0.0.1"
+            port = 8082
+            workers = [{ host = "127.0.0.1", port = 8083 }]
+            "#,
+        )
+        .unwrap();
+        let default_config = ProxyConfig::default();
+        assert_eq!(config.host, "127.0.0.1");
+        assert_eq!(config.workers, vec![WorkerConfig::new("127.0.0.1", 8083)]);
+        assert_eq!(config.job_retention_secs, default_config.job_retention_secs);
+        assert_eq!(config.proof_cache_size, default_config.proof_cache_size);
+
+        // The admin endpoint is only reachable locally, and disabled without an admin token
+        assert_eq!(config.admin_host, "127.0.0.1");
+        assert_eq!(config.admin_token, None);
+    }
+
+    #[test]
+    fn workers_are_added_and_removed_from_config() {
+        let mut config = ProxyConfig::default();
+        let worker = WorkerConfig::new("127.0.0.1", 50056);
+        assert!(config.add_worker(worker.clone()));
+        assert!(!config.add_worker(worker.clone()));
+        assert!(config.remove_worker(&worker));
+        assert!(!config.remove_worker(&worker));
+    }
+}
@@ -0,0 +1,125 @@
+use std::{path::PathBuf, time::Instant};
+
+use clap::Parser;
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    MIN_PROOF_SECURITY_LEVEL,
+};
+use miden_tx::{
+    utils::{Deserializable, Serializable},
+    HashFunction, LocalTransactionProver, ProvingOptions, TransactionProver, TransactionVerifier,
+};
+use miden_tx_prover::generated;
+
+use super::worker::parse_hash_function;
+
+/// Proves a serialized transaction witness locally.
+#[derive(Debug, Parser)]
+pub struct Prove {
+    /// The file containing the serialized `TransactionWitness`
+    #[clap(long)]
+    witness: PathBuf,
+    /// The file to which the serialized `ProvenTransaction` is written
+    #[clap(long)]
+    out: PathBuf,
+    /// The security level, in bits, of the generated proof
+    #[clap(long, default_value_t = MIN_PROOF_SECURITY_LEVEL)]
+    security_level: u32,
+    /// The hash function used to generate the proof (one of `blake3_192`, `blake3_256`,
+    /// `rpo_256` or `rpx_256`). If not provided, the BLAKE3 variant matching the security level
+    /// is used
+    #[clap(long, value_parser = parse_hash_function)]
+    hash_function: Option<HashFunction>,
+}
+
+impl Prove {
+    /// Proves a serialized transaction witness locally.
+    ///
+    /// This method reads the transaction witness from the provided file, proves it with a
+    /// [LocalTransactionProver] and writes the proven transaction to the output file. A summary
+    /// of the proven transaction is printed.
+    pub fn execute(&self) -> Result<(), String> {
+        let proving_options = ProvingOptions::try_from(generated::ProvingOptions::new(
+            self.security_level,
+            self.hash_function,
+        ))
+        .map_err(|err| err.to_string())?;
+
+        let witness_bytes = std::fs::read(&self.witness).map_err(|err| {
+            format!("Failed to read transaction witness from {}: {err}", self.witness.display())
+        })?;
+        let witness = TransactionWitness::read_from_bytes(&witness_bytes)
+            .map_err(|err| format!("Failed to deserialize transaction witness: {err}"))?;
+
+        let started_at = Instant::now();
+        let proven_transaction = LocalTransactionProver::new(proving_options)
+            .prove(witness)
+            .map_err(|err| format!("Failed to prove transaction: {err}"))?;
+        let elapsed = started_at.elapsed();
+
+        std::fs::write(&self.out, proven_transaction.to_bytes()).map_err(|err| {
+            format!("Failed to write proven transaction to {}: {err}", self.out.display())
+        })?;
+
+        print_summary(&proven_transaction);
+        println!("Proving time: {:.3}s", elapsed.as_secs_f64());
+        println!("Proven transaction written to {}", self.out.display());
+
+        Ok(())
+    }
+}
+
+/// Verifies a serialized proven transaction.
+#[derive(Debug, Parser)]
+pub struct Verify {
+    /// The file containing the serialized `ProvenTransaction`
+    #[clap(long)]
+    tx: PathBuf,
+    /// The minimum security level, in bits, which the proof must have
+    #[clap(long, default_value_t = MIN_PROOF_SECURITY_LEVEL)]
+    security_level: u32,
+}
+
+impl Verify {
+    /// Verifies a serialized proven transaction.
+    ///
+    /// This method reads the proven transaction from the provided file and verifies it with a
+    /// [TransactionVerifier] at the chosen security level. A summary of the transaction is printed
+    /// before it is verified, so that it is available even if the verification fails.
+    pub fn execute(&self) -> Result<(), String> {
+        let tx_bytes = std::fs::read(&self.tx).map_err(|err| {
+            format!("Failed to read proven transaction from {}: {err}", self.tx.display())
+        })?;
+        let proven_transaction = ProvenTransaction::read_from_bytes(&tx_bytes)
+            .map_err(|err| format!("Failed to deserialize proven transaction: {err}"))?;
+
+        print_summary(&proven_transaction);
+
+        let started_at = Instant::now();
+        TransactionVerifier::new(self.security_level)
+            .verify(proven_transaction)
+            .map_err(|err| format!("Transaction verification failed: {err}"))?;
+
+        println!("Verification time: {:.3}s", started_at.elapsed().as_secs_f64());
+        println!("Transaction verified at {} bits of security", self.security_level);
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Prints the ID, account, notes and proof security level of the provided transaction.
+fn print_summary(proven_transaction: &ProvenTransaction) {
+    let account_update = proven_transaction.account_update();
+
+    println!("Transaction ID: {}", proven_transaction.id());
+    println!("Account: {}", proven_transaction.account_id());
+    println!("Initial account state: {}", account_update.init_state_hash());
+    println!("Final account state: {}", account_update.final_state_hash());
+    println!("Input notes: {}", proven_transaction.input_notes().num_notes());
+    println!("Output notes: {}", proven_transaction.output_notes().num_notes());
+    println!("Reference block: {}", proven_transaction.block_ref());
+    println!("Proof security level: {} bits", proven_transaction.proof().security_level());
+}
@@ -1,10 +1,27 @@
+use std::{sync::Arc, time::Duration};
+
 use clap::Parser;
+use miden_tx_prover::PROVER_SERVICE_QUOTAS_FILE_NAME;
 use pingora::{
-    apps::HttpServerOptions, lb::LoadBalancer as PingoraLoadBalancer, prelude::Opt, server::Server,
+    apps::HttpServerOptions,
+    lb::{Backends, LoadBalancer as PingoraLoadBalancer},
+    prelude::{background_service, Opt},
+    server::Server,
+    services::listening::Service,
 };
 use pingora_proxy::http_proxy_service;
+use tracing::warn;
 
-use crate::{proxy::LoadBalancer, utils::load_config_from_file};
+use crate::{
+    proxy::{
+        admin::AdminService,
+        api_keys::{ApiKeys, QuotaStore, QUOTA_STORE_INTERVAL},
+        discovery::{worker_backend, WorkerDiscovery},
+        health_check::WorkerHealthCheck,
+        LoadBalancer,
+    },
+    utils::load_config_from_file,
+};
 
 /// Starts the proxy defined in the config file.
 #[derive(Debug, Parser)]
@@ -14,22 +31,62 @@ impl StartProxy {
     /// Starts the proxy defined in the config file.
     ///
     /// This method will first read the config file to get the list of workers to start. It will
-    /// then start a proxy with each worker as a backend.
+    /// then start a proxy with each worker as a backend, together with the background services
+    /// which check the health of the workers, store the quota usage of the API keys and serve the
+    /// admin and metrics endpoints. The admin
+    /// endpoint allows adding and removing workers while the proxy is running.
     pub fn execute(&self) -> Result<(), String> {
         let mut server = Server::new(Some(Opt::default())).expect("Failed to create server");
         server.bootstrap();
 
         let proxy_config = load_config_from_file()?;
 
-        let workers = proxy_config
-            .workers
-            .iter()
-            .map(|worker| format!("{}:{}", worker.host, worker.port));
-
         let workers =
-            PingoraLoadBalancer::try_from_iter(workers).expect("PROVER_WORKERS is invalid");
+            proxy_config.workers.iter().map(worker_backend).collect::<Result<Vec<_>, _>>()?;
+
+        // Workers can be added and removed at runtime through the admin endpoint. The workers of
+        // the load balancer are loaded when its background service starts.
+        let discovery = WorkerDiscovery::new(workers);
+        let mut workers =
+            PingoraLoadBalancer::from_backends(Backends::new(Box::new(discovery.clone())));
+
+        // Check the health of the workers periodically in the background
+        workers.set_health_check(Box::new(WorkerHealthCheck::new(&proxy_config)));
+        workers.health_check_frequency =
+            Some(Duration::from_secs(proxy_config.health_check_interval_secs));
+        workers.parallel_health_check = true;
+        let health_check_service = background_service("health check", workers);
+        let workers = health_check_service.task();
 
-        let worker_lb = LoadBalancer::new(workers, &proxy_config);
+        // The admin endpoint can change the workers which receive the transactions of the
+        // clients, so it is only served if requests to it can be authenticated
+        let admin_service = match &proxy_config.admin_token {
+            Some(admin_token) => Some(background_service(
+                "admin",
+                AdminService::new(
+                    workers.clone(),
+                    discovery,
+                    format!("{}:{}", proxy_config.admin_host, proxy_config.admin_port),
+                    admin_token.clone(),
+                ),
+            )),
+            None => {
+                warn!("No admin token is configured, the admin endpoint is disabled");
+                None
+            },
+        };
+
+        let mut quotas_path = std::env::current_dir().map_err(|err| err.to_string())?;
+        quotas_path.push(PROVER_SERVICE_QUOTAS_FILE_NAME);
+        let api_keys = Arc::new(ApiKeys::new(&proxy_config.api_keys, quotas_path)?);
+
+        // Store the quota usage of the API keys periodically in the background
+        let quota_store_service = background_service(
+            "quota store",
+            QuotaStore::new(api_keys.clone(), QUOTA_STORE_INTERVAL),
+        );
+
+        let worker_lb = LoadBalancer::new(workers, api_keys, &proxy_config);
 
         // Set up the load balancer
         let mut lb = http_proxy_service(&server.configuration, worker_lb);
@@ -45,6 +102,17 @@ impl StartProxy {
         logic.server_options = Some(http_server_options);
 
         server.add_service(lb);
+        server.add_service(health_check_service);
+        server.add_service(quota_store_service);
+        if let Some(admin_service) = admin_service {
+            server.add_service(admin_service);
+        }
+
+        // Export the metrics of the proxy
+        let mut metrics_service = Service::prometheus_http_service();
+        metrics_service.add_tcp(&format!("{}:{}", proxy_host, proxy_config.metrics_port));
+        server.add_service(metrics_service);
+
         server.run_forever();
     }
 }
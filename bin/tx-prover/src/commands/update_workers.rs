@@ -0,0 +1,117 @@
+use clap::Parser;
+use reqwest::Method;
+
+use crate::{
+    commands::{ProxyConfig, WorkerConfig},
+    utils::{load_config_from_file, save_config_to_file},
+};
+
+/// Adds a worker to the proxy defined in the config file.
+#[derive(Debug, Parser)]
+pub struct AddWorker {
+    /// Host of the worker.
+    #[clap(long, default_value = "0.0.0.0")]
+    host: String,
+    /// Port of the worker.
+    #[clap(long)]
+    port: u16,
+}
+
+impl AddWorker {
+    /// Adds a worker to the proxy defined in the config file.
+    ///
+    /// This method will send the worker to the admin endpoint of the proxy, which starts sending
+    /// requests to it and adds it to the config file. If the proxy is not running, the worker is
+    /// only added to the config file.
+    pub async fn execute(&self) -> Result<(), String> {
+        let worker = WorkerConfig::new(&self.host, self.port);
+        update_workers(Method::POST, worker, |config, worker| {
+            config.add_worker(worker.clone()).then_some(()).ok_or(format!(
+                "Worker {}:{} is already in the config file",
+                worker.host, worker.port
+            ))
+        })
+        .await
+    }
+}
+
+/// Removes a worker from the proxy defined in the config file.
+#[derive(Debug, Parser)]
+pub struct RemoveWorker {
+    /// Host of the worker.
+    #[clap(long, default_value = "0.0.0.0")]
+    host: String,
+    /// Port of the worker.
+    #[clap(long)]
+    port: u16,
+}
+
+impl RemoveWorker {
+    /// Removes a worker from the proxy defined in the config file.
+    ///
+    /// This method will send the worker to the admin endpoint of the proxy, which stops sending
+    /// new requests to it and removes it from the config file. The requests already queued for
+    /// the worker are still processed by it. If the proxy is not running, the worker is only
+    /// removed from the config file.
+    pub async fn execute(&self) -> Result<(), String> {
+        let worker = WorkerConfig::new(&self.host, self.port);
+        update_workers(Method::DELETE, worker, |config, worker| {
+            config
+                .remove_worker(worker)
+                .then_some(())
+                .ok_or(format!("Worker {}:{} is not in the config file", worker.host, worker.port))
+        })
+        .await
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Sends the worker to the `/workers` admin endpoint of the proxy with the provided method,
+/// authenticated with the admin token of the config file.
+///
+/// If the proxy can not be reached, or if no admin token is configured, the provided update is
+/// applied to the config file instead.
+async fn update_workers(
+    method: Method,
+    worker: WorkerConfig,
+    update_config: impl FnOnce(&mut ProxyConfig, &WorkerConfig) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut config = load_config_from_file()?;
+    let Some(admin_token) = config.admin_token.clone() else {
+        update_config(&mut config, &worker)?;
+        save_config_to_file(&config)?;
+        println!(
+            "No admin token is configured, config file successfully updated. Restart the proxy \
+            to apply the change"
+        );
+        return Ok(());
+    };
+    let url = format!("http://{}:{}/workers", config.admin_host, config.admin_port);
+
+    match reqwest::Client::new()
+        .request(method, &url)
+        .bearer_auth(admin_token)
+        .json(&worker)
+        .send()
+        .await
+    {
+        Ok(response) if response.status().is_success() => {
+            println!("Workers of the proxy successfully updated");
+            Ok(())
+        },
+        Ok(response) => {
+            let status = response.status();
+            let message = response.text().await.unwrap_or_default();
+            Err(format!("Failed to update the workers of the proxy ({status}): {message}"))
+        },
+        Err(err) if err.is_connect() => {
+            update_config(&mut config, &worker)?;
+            save_config_to_file(&config)?;
+            println!("Proxy is not running, config file successfully updated");
+            Ok(())
+        },
+        Err(err) => Err(format!("Failed to reach the proxy at {url}: {err}")),
+    }
+}
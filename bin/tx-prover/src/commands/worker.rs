@@ -1,9 +1,22 @@
+use std::time::Duration;
+
 use clap::Parser;
+use miden_objects::MIN_PROOF_SECURITY_LEVEL;
+use miden_tx::{HashFunction, ProvingOptions};
+use miden_tx_prover::generated;
 use tokio::net::TcpListener;
 use tokio_stream::wrappers::TcpListenerStream;
 use tracing::info;
 
-use crate::api::RpcListener;
+use crate::{
+    api::{
+        jobs::DEFAULT_JOB_RETENTION,
+        metrics::serve_metrics,
+        pool::{ProverPool, DEFAULT_MAX_CONCURRENT_PROOFS, DEFAULT_MAX_QUEUED_PROOFS},
+        RpcListener,
+    },
+    cache::{ProofCache, DEFAULT_PROOF_CACHE_SIZE, DEFAULT_PROOF_CACHE_TTL},
+};
 
 /// Starts a worker.
 #[derive(Debug, Parser)]
@@ -14,6 +27,44 @@ pub struct StartWorker {
     /// The port of the worker
     #[clap(short, long, default_value = "50051")]
     port: u16,
+    /// The security level, in bits, of the proofs generated for requests which do not specify
+    /// their own proving options
+    #[clap(long, default_value_t = MIN_PROOF_SECURITY_LEVEL)]
+    security_level: u32,
+    /// The hash function used to generate proofs for requests which do not specify their own
+    /// proving options (one of `blake3_192`, `blake3_256`, `rpo_256` or `rpx_256`). If not
+    /// provided, the BLAKE3 variant matching the security level is used
+    #[clap(long, value_parser = parse_hash_function)]
+    hash_function: Option<HashFunction>,
+    /// The time, in seconds, for which finished proving jobs and their results are kept
+    #[clap(long, default_value_t = DEFAULT_JOB_RETENTION.as_secs())]
+    job_retention_secs: u64,
+    /// The maximum number of transactions proven at the same time
+    #[clap(
+        long,
+        default_value_t = DEFAULT_MAX_CONCURRENT_PROOFS,
+        value_parser = parse_max_concurrent_proofs
+    )]
+    max_concurrent_proofs: usize,
+    /// The maximum number of transactions waiting to be proven. Requests arriving when the queue
+    /// is full are rejected
+    #[clap(
+        long,
+        default_value_t = DEFAULT_MAX_QUEUED_PROOFS,
+        value_parser = parse_max_queued_proofs
+    )]
+    max_queued_proofs: usize,
+    /// The maximum number of proofs kept in the proof cache, which serves the proof of a
+    /// transaction again to identical requests. A size of zero disables the cache
+    #[clap(long, default_value_t = DEFAULT_PROOF_CACHE_SIZE)]
+    proof_cache_size: usize,
+    /// The time, in seconds, for which a proof is kept in the proof cache
+    #[clap(long, default_value_t = DEFAULT_PROOF_CACHE_TTL.as_secs())]
+    proof_cache_ttl_secs: u64,
+    /// The port of the Prometheus metrics endpoint of the worker, served at `/metrics` on the
+    /// host of the worker. If not provided, metrics are not exported
+    #[clap(long)]
+    metrics_port: Option<u16>,
 }
 
 impl StartWorker {
@@ -21,17 +72,37 @@ impl StartWorker {
     ///
     /// This method receives the host and port from the CLI and starts a worker on that address.
     /// In case that one of the parameters is not provided, it will default to `0.0.0.0` for the
-    /// host and `50051` for the port.
+    /// host and `50051` for the port. If a metrics port is provided, the metrics of the worker are
+    /// also served on that port.
+    ///
+    /// The proving options of the worker are validated before the worker is started.
     pub async fn execute(&self) -> Result<(), String> {
+        let proving_options = ProvingOptions::try_from(generated::ProvingOptions::new(
+            self.security_level,
+            self.hash_function,
+        ))
+        .map_err(|err| err.to_string())?;
+
         let worker_addr = format!("{}:{}", self.host, self.port);
-        let rpc =
-            RpcListener::new(TcpListener::bind(&worker_addr).await.map_err(|err| err.to_string())?);
+        let rpc = RpcListener::new(
+            TcpListener::bind(&worker_addr).await.map_err(|err| err.to_string())?,
+            proving_options,
+            Duration::from_secs(self.job_retention_secs),
+            ProverPool::new(self.max_concurrent_proofs, self.max_queued_proofs),
+            ProofCache::new(self.proof_cache_size, Duration::from_secs(self.proof_cache_ttl_secs)),
+        );
 
         info!(
             "Server listening on {}",
             rpc.listener.local_addr().map_err(|err| err.to_string())?
         );
 
+        if let Some(metrics_port) = self.metrics_port {
+            let metrics_addr = format!("{}:{}", self.host, metrics_port);
+            let listener = TcpListener::bind(&metrics_addr).await.map_err(|err| err.to_string())?;
+            tokio::spawn(serve_metrics(listener));
+        }
+
         tonic::transport::Server::builder()
             .accept_http1(true)
             .add_service(tonic_web::enable(rpc.api_service))
@@ -42,3 +113,47 @@ impl StartWorker {
         Ok(())
     }
 }
+
+/// Parses a hash function from its name in the protobuf definition, ignoring case.
+pub(crate) fn parse_hash_function(name: &str) -> Result<HashFunction, String> {
+    generated::HashFunction::from_str_name(&name.to_uppercase())
+        .map(HashFunction::from)
+        .ok_or_else(|| format!("unknown hash function: {name}"))
+}
+
+/// Parses the maximum number of concurrent proofs, which must be at least one.
+fn parse_max_concurrent_proofs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("at least one proof must be allowed at a time".to_string()),
+        Ok(value) => Ok(value),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Parses the maximum number of queued proofs, which must be at least one since every request
+/// passes through the queue before it is proven.
+fn parse_max_queued_proofs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("at least one proof must be allowed in the queue".to_string()),
+        Ok(value) => Ok(value),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_max_concurrent_proofs, parse_max_queued_proofs};
+
+    #[test]
+    fn pool_limits_must_be_positive() {
+        assert!(parse_max_concurrent_proofs("0").is_err());
+        assert_eq!(parse_max_concurrent_proofs("2"), Ok(2));
+
+        // The queue must hold at least one request, since every request passes through it
+        assert!(parse_max_queued_proofs("0").is_err());
+        assert_eq!(parse_max_queued_proofs("1"), Ok(1));
+    }
+}
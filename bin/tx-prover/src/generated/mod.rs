@@ -1,6 +1,12 @@
-use miden_objects::transaction::ProvenTransaction;
+use alloc::{format, string::String};
+
+use miden_objects::{
+    crypto::hash::blake::Blake3_256, transaction::ProvenTransaction, MIN_PROOF_SECURITY_LEVEL,
+};
 use miden_tx::utils::{Deserializable, DeserializationError, Serializable};
 
+use crate::RemoteTransactionProverError;
+
 #[cfg(all(feature = "std", target_arch = "wasm32"))]
 compile_error!("The `std` feature cannot be used when targeting `wasm32`.");
 
@@ -19,7 +25,10 @@ pub use nostd::api::*;
 
 impl From<ProvenTransaction> for ProveTransactionResponse {
     fn from(value: ProvenTransaction) -> Self {
-        ProveTransactionResponse { proven_transaction: value.to_bytes() }
+        ProveTransactionResponse {
+            security_level: value.proof().security_level(),
+            proven_transaction: value.to_bytes(),
+        }
     }
 }
 
@@ -30,3 +39,127 @@ impl TryFrom<ProveTransactionResponse> for ProvenTransaction {
         ProvenTransaction::read_from_bytes(&response.proven_transaction)
     }
 }
+
+impl From<miden_tx::HashFunction> for HashFunction {
+    fn from(value: miden_tx::HashFunction) -> Self {
+        match value {
+            miden_tx::HashFunction::Blake3_192 => HashFunction::Blake3192,
+            miden_tx::HashFunction::Blake3_256 => HashFunction::Blake3256,
+            miden_tx::HashFunction::Rpo256 => HashFunction::Rpo256,
+            miden_tx::HashFunction::Rpx256 => HashFunction::Rpx256,
+        }
+    }
+}
+
+impl From<HashFunction> for miden_tx::HashFunction {
+    fn from(value: HashFunction) -> Self {
+        match value {
+            HashFunction::Blake3192 => miden_tx::HashFunction::Blake3_192,
+            HashFunction::Blake3256 => miden_tx::HashFunction::Blake3_256,
+            HashFunction::Rpo256 => miden_tx::HashFunction::Rpo256,
+            HashFunction::Rpx256 => miden_tx::HashFunction::Rpx256,
+        }
+    }
+}
+
+// WITNESS COMMITMENT
+// ================================================================================================
+
+impl ProveTransactionRequest {
+    /// Returns the hex-encoded BLAKE3 hash of this request, which commits to the serialized
+    /// transaction witness and the requested proving options.
+    ///
+    /// Requests with the same commitment yield the same proven transaction, so the commitment
+    /// identifies the proofs cached by the workers and the proxy.
+    pub fn witness_commitment(&self) -> String {
+        String::from(Blake3_256::hash(&prost::Message::encode_to_vec(self)))
+    }
+}
+
+// PROVING OPTIONS
+// ================================================================================================
+
+/// Maximum security level, in bits, which can be requested from the prover.
+const MAX_PROOF_SECURITY_LEVEL: u32 = 128;
+
+impl ProvingOptions {
+    /// Returns new [ProvingOptions] targeting the specified security level and, optionally, using
+    /// the specified hash function.
+    pub fn new(security_level: u32, hash_function: Option<miden_tx::HashFunction>) -> Self {
+        ProvingOptions {
+            security_level,
+            hash_function: hash_function.map(|hash_fn| HashFunction::from(hash_fn) as i32),
+        }
+    }
+}
+
+impl TryFrom<ProvingOptions> for miden_tx::ProvingOptions {
+    type Error = RemoteTransactionProverError;
+
+    /// Returns the preset [miden_tx::ProvingOptions] which achieve the requested security level
+    /// with the requested hash function.
+    ///
+    /// Security levels up to 96 bits are proven with the 96-bit presets and security levels up to
+    /// 128 bits with the 128-bit presets. If the hash function is not specified, BLAKE3 with the
+    /// output size matching the security level is used.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The security level is lower than [MIN_PROOF_SECURITY_LEVEL] or higher than 128 bits.
+    /// - The hash function is unknown or cannot be used at the requested security level.
+    fn try_from(options: ProvingOptions) -> Result<Self, Self::Error> {
+        let security_level = options.security_level;
+        if !(MIN_PROOF_SECURITY_LEVEL..=MAX_PROOF_SECURITY_LEVEL).contains(&security_level) {
+            return Err(RemoteTransactionProverError::InvalidProvingOptions(format!(
+                "security level must be between {MIN_PROOF_SECURITY_LEVEL} and \
+                {MAX_PROOF_SECURITY_LEVEL} bits, but {security_level} bits were requested"
+            )));
+        }
+
+        let hash_function = options
+            .hash_function
+            .map(|hash_fn| {
+                HashFunction::try_from(hash_fn).map_err(|_| {
+                    RemoteTransactionProverError::InvalidProvingOptions(format!(
+                        "unknown hash function {hash_fn}"
+                    ))
+                })
+            })
+            .transpose()?;
+
+        let proving_options = if security_level <= 96 {
+            match hash_function {
+                None | Some(HashFunction::Blake3192) => Self::with_96_bit_security(false),
+                Some(HashFunction::Rpo256) => Self::with_96_bit_security(true),
+                Some(HashFunction::Rpx256) => Self::with_96_bit_security_rpx(),
+                Some(hash_fn @ HashFunction::Blake3256) => {
+                    return Err(unsupported_hash_function(hash_fn, security_level))
+                },
+            }
+        } else {
+            match hash_function {
+                None | Some(HashFunction::Blake3256) => Self::with_128_bit_security(false),
+                Some(HashFunction::Rpo256) => Self::with_128_bit_security(true),
+                Some(HashFunction::Rpx256) => Self::with_128_bit_security_rpx(),
+                Some(hash_fn @ HashFunction::Blake3192) => {
+                    return Err(unsupported_hash_function(hash_fn, security_level))
+                },
+            }
+        };
+
+        Ok(proving_options)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn unsupported_hash_function(
+    hash_function: HashFunction,
+    security_level: u32,
+) -> RemoteTransactionProverError {
+    RemoteTransactionProverError::InvalidProvingOptions(format!(
+        "hash function {} cannot be used at {security_level} bits of security",
+        hash_function.as_str_name()
+    ))
+}
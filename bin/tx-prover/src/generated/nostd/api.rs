@@ -1,13 +1,148 @@
 // This file is @generated by prost-build.
+/// Options which define how the transaction is to be proven.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProvingOptions {
+    /// Target security level of the proof in bits. It must not be lower than the minimum proof
+    /// security level accepted by the network.
+    #[prost(uint32, tag = "1")]
+    pub security_level: u32,
+    /// Hash function used to generate the proof. If not set, the BLAKE3 variant matching the
+    /// security level is used.
+    #[prost(enumeration = "HashFunction", optional, tag = "2")]
+    pub hash_function: ::core::option::Option<i32>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionRequest {
     #[prost(bytes = "vec", tag = "1")]
     pub transaction_witness: ::prost::alloc::vec::Vec<u8>,
+    /// Options used to prove the transaction. If not set, the worker uses its own options.
+    #[prost(message, optional, tag = "2")]
+    pub proving_options: ::core::option::Option<ProvingOptions>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionResponse {
     #[prost(bytes = "vec", tag = "1")]
     pub proven_transaction: ::prost::alloc::vec::Vec<u8>,
+    /// Conjectured security level of the generated proof in bits.
+    #[prost(uint32, tag = "2")]
+    pub security_level: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitProvingJobResponse {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobRequest {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobStatusResponse {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "JobState", tag = "2")]
+    pub state: i32,
+    /// Time at which the job was submitted, in seconds since the UNIX epoch.
+    #[prost(uint64, tag = "3")]
+    pub submitted_at: u64,
+    /// Time at which proving started, in seconds since the UNIX epoch.
+    #[prost(uint64, optional, tag = "4")]
+    pub started_at: ::core::option::Option<u64>,
+    /// Time at which the job finished, in seconds since the UNIX epoch.
+    #[prost(uint64, optional, tag = "5")]
+    pub finished_at: ::core::option::Option<u64>,
+    /// Reason of the failure of a failed job.
+    #[prost(string, optional, tag = "6")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetQueueDepthRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetQueueDepthResponse {
+    /// Number of transactions waiting for a prover.
+    #[prost(uint32, tag = "1")]
+    pub queued_proofs: u32,
+    /// Number of transactions being proven.
+    #[prost(uint32, tag = "2")]
+    pub active_proofs: u32,
+    /// Maximum number of transactions proven at the same time.
+    #[prost(uint32, tag = "3")]
+    pub max_concurrent_proofs: u32,
+    /// Maximum number of transactions waiting for a prover. Requests arriving when the queue is
+    /// full are rejected.
+    #[prost(uint32, tag = "4")]
+    pub max_queued_proofs: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HealthRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HealthResponse {}
+/// Hash function used to generate the STARK proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum HashFunction {
+    Blake3192 = 0,
+    Blake3256 = 1,
+    Rpo256 = 2,
+    Rpx256 = 3,
+}
+impl HashFunction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Blake3192 => "BLAKE3_192",
+            Self::Blake3256 => "BLAKE3_256",
+            Self::Rpo256 => "RPO_256",
+            Self::Rpx256 => "RPX_256",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BLAKE3_192" => Some(Self::Blake3192),
+            "BLAKE3_256" => Some(Self::Blake3256),
+            "RPO_256" => Some(Self::Rpo256),
+            "RPX_256" => Some(Self::Rpx256),
+            _ => None,
+        }
+    }
+}
+/// State of a proving job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum JobState {
+    Queued = 0,
+    Proving = 1,
+    Done = 2,
+    Failed = 3,
+}
+impl JobState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Queued => "QUEUED",
+            Self::Proving => "PROVING",
+            Self::Done => "DONE",
+            Self::Failed => "FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QUEUED" => Some(Self::Queued),
+            "PROVING" => Some(Self::Proving),
+            "DONE" => Some(Self::Done),
+            "FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
 }
 /// Generated client implementations.
 pub mod api_client {
@@ -110,5 +245,112 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("api.Api", "ProveTransaction"));
             self.inner.unary(req, path, codec).await
         }
+        /// Queues a transaction to be proven and returns the ID of the proving job without waiting for
+        /// the proof.
+        pub async fn submit_proving_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProveTransactionRequest>,
+        ) -> core::result::Result<
+            tonic::Response<super::SubmitProvingJobResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/SubmitProvingJob");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "SubmitProvingJob"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_job_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JobRequest>,
+        ) -> core::result::Result<
+            tonic::Response<super::JobStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetJobStatus");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetJobStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the proven transaction of a successfully completed proving job.
+        pub async fn get_job_result(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JobRequest>,
+        ) -> core::result::Result<
+            tonic::Response<super::ProveTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetJobResult");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetJobResult"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the number of transactions being proven and waiting to be proven by the worker.
+        pub async fn get_queue_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetQueueDepthRequest>,
+        ) -> core::result::Result<
+            tonic::Response<super::GetQueueDepthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetQueueDepth");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetQueueDepth"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns successfully if the worker is able to serve requests.
+        pub async fn health(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HealthRequest>,
+        ) -> core::result::Result<tonic::Response<super::HealthResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        alloc::format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/Health");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "Health"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
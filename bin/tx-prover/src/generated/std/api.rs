@@ -1,13 +1,148 @@
 // This file is @generated by prost-build.
+/// Options which define how the transaction is to be proven.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProvingOptions {
+    /// Target security level of the proof in bits. It must not be lower than the minimum proof
+    /// security level accepted by the network.
+    #[prost(uint32, tag = "1")]
+    pub security_level: u32,
+    /// Hash function used to generate the proof. If not set, the BLAKE3 variant matching the
+    /// security level is used.
+    #[prost(enumeration = "HashFunction", optional, tag = "2")]
+    pub hash_function: ::core::option::Option<i32>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionRequest {
     #[prost(bytes = "vec", tag = "1")]
     pub transaction_witness: ::prost::alloc::vec::Vec<u8>,
+    /// Options used to prove the transaction. If not set, the worker uses its own options.
+    #[prost(message, optional, tag = "2")]
+    pub proving_options: ::core::option::Option<ProvingOptions>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProveTransactionResponse {
     #[prost(bytes = "vec", tag = "1")]
     pub proven_transaction: ::prost::alloc::vec::Vec<u8>,
+    /// Conjectured security level of the generated proof in bits.
+    #[prost(uint32, tag = "2")]
+    pub security_level: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitProvingJobResponse {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobRequest {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JobStatusResponse {
+    #[prost(string, tag = "1")]
+    pub job_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "JobState", tag = "2")]
+    pub state: i32,
+    /// Time at which the job was submitted, in seconds since the UNIX epoch.
+    #[prost(uint64, tag = "3")]
+    pub submitted_at: u64,
+    /// Time at which proving started, in seconds since the UNIX epoch.
+    #[prost(uint64, optional, tag = "4")]
+    pub started_at: ::core::option::Option<u64>,
+    /// Time at which the job finished, in seconds since the UNIX epoch.
+    #[prost(uint64, optional, tag = "5")]
+    pub finished_at: ::core::option::Option<u64>,
+    /// Reason of the failure of a failed job.
+    #[prost(string, optional, tag = "6")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetQueueDepthRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetQueueDepthResponse {
+    /// Number of transactions waiting for a prover.
+    #[prost(uint32, tag = "1")]
+    pub queued_proofs: u32,
+    /// Number of transactions being proven.
+    #[prost(uint32, tag = "2")]
+    pub active_proofs: u32,
+    /// Maximum number of transactions proven at the same time.
+    #[prost(uint32, tag = "3")]
+    pub max_concurrent_proofs: u32,
+    /// Maximum number of transactions waiting for a prover. Requests arriving when the queue is
+    /// full are rejected.
+    #[prost(uint32, tag = "4")]
+    pub max_queued_proofs: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HealthRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HealthResponse {}
+/// Hash function used to generate the STARK proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum HashFunction {
+    Blake3192 = 0,
+    Blake3256 = 1,
+    Rpo256 = 2,
+    Rpx256 = 3,
+}
+impl HashFunction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Blake3192 => "BLAKE3_192",
+            Self::Blake3256 => "BLAKE3_256",
+            Self::Rpo256 => "RPO_256",
+            Self::Rpx256 => "RPX_256",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BLAKE3_192" => Some(Self::Blake3192),
+            "BLAKE3_256" => Some(Self::Blake3256),
+            "RPO_256" => Some(Self::Rpo256),
+            "RPX_256" => Some(Self::Rpx256),
+            _ => None,
+        }
+    }
+}
+/// State of a proving job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum JobState {
+    Queued = 0,
+    Proving = 1,
+    Done = 2,
+    Failed = 3,
+}
+impl JobState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Queued => "QUEUED",
+            Self::Proving => "PROVING",
+            Self::Done => "DONE",
+            Self::Failed => "FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QUEUED" => Some(Self::Queued),
+            "PROVING" => Some(Self::Proving),
+            "DONE" => Some(Self::Done),
+            "FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
 }
 /// Generated client implementations.
 pub mod api_client {
@@ -121,6 +256,113 @@ pub mod api_client {
             req.extensions_mut().insert(GrpcMethod::new("api.Api", "ProveTransaction"));
             self.inner.unary(req, path, codec).await
         }
+        /// Queues a transaction to be proven and returns the ID of the proving job without waiting for
+        /// the proof.
+        pub async fn submit_proving_job(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProveTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitProvingJobResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/SubmitProvingJob");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "SubmitProvingJob"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_job_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JobRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::JobStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetJobStatus");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetJobStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the proven transaction of a successfully completed proving job.
+        pub async fn get_job_result(
+            &mut self,
+            request: impl tonic::IntoRequest<super::JobRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProveTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetJobResult");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetJobResult"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the number of transactions being proven and waiting to be proven by the worker.
+        pub async fn get_queue_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetQueueDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetQueueDepthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/GetQueueDepth");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "GetQueueDepth"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns successfully if the worker is able to serve requests.
+        pub async fn health(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HealthRequest>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/api.Api/Health");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("api.Api", "Health"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -143,6 +385,43 @@ pub mod api_server {
             tonic::Response<super::ProveTransactionResponse>,
             tonic::Status,
         >;
+        /// Queues a transaction to be proven and returns the ID of the proving job without waiting for
+        /// the proof.
+        async fn submit_proving_job(
+            &self,
+            request: tonic::Request<super::ProveTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitProvingJobResponse>,
+            tonic::Status,
+        >;
+        async fn get_job_status(
+            &self,
+            request: tonic::Request<super::JobRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::JobStatusResponse>,
+            tonic::Status,
+        >;
+        /// Returns the proven transaction of a successfully completed proving job.
+        async fn get_job_result(
+            &self,
+            request: tonic::Request<super::JobRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProveTransactionResponse>,
+            tonic::Status,
+        >;
+        /// Returns the number of transactions being proven and waiting to be proven by the worker.
+        async fn get_queue_depth(
+            &self,
+            request: tonic::Request<super::GetQueueDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetQueueDepthResponse>,
+            tonic::Status,
+        >;
+        /// Returns successfully if the worker is able to serve requests.
+        async fn health(
+            &self,
+            request: tonic::Request<super::HealthRequest>,
+        ) -> std::result::Result<tonic::Response<super::HealthResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct ApiServer<T> {
@@ -265,6 +544,223 @@ pub mod api_server {
                     };
                     Box::pin(fut)
                 }
+                "/api.Api/SubmitProvingJob" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitProvingJobSvc<T: Api>(pub Arc<T>);
+                    impl<
+                        T: Api,
+                    > tonic::server::UnaryService<super::ProveTransactionRequest>
+                    for SubmitProvingJobSvc<T> {
+                        type Response = super::SubmitProvingJobResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProveTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::submit_proving_job(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubmitProvingJobSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api.Api/GetJobStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetJobStatusSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::JobRequest>
+                    for GetJobStatusSvc<T> {
+                        type Response = super::JobStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::JobRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_job_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetJobStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api.Api/GetJobResult" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetJobResultSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::JobRequest>
+                    for GetJobResultSvc<T> {
+                        type Response = super::ProveTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::JobRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_job_result(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetJobResultSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api.Api/GetQueueDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetQueueDepthSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::GetQueueDepthRequest>
+                    for GetQueueDepthSvc<T> {
+                        type Response = super::GetQueueDepthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetQueueDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::get_queue_depth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetQueueDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api.Api/Health" => {
+                    #[allow(non_camel_case_types)]
+                    struct HealthSvc<T: Api>(pub Arc<T>);
+                    impl<T: Api> tonic::server::UnaryService<super::HealthRequest>
+                    for HealthSvc<T> {
+                        type Response = super::HealthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HealthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Api>::health(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HealthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
@@ -7,7 +7,7 @@ pub mod generated;
 #[cfg(feature = "async")]
 mod prover;
 #[cfg(feature = "async")]
-pub use prover::RemoteTransactionProver;
+pub use prover::{ProvingJob, RemoteTransactionProver, RetryPolicy};
 
 /// Contains the protobuf definitions
 pub const PROTO_MESSAGES: &str = include_str!("../proto/api.proto");
@@ -15,6 +15,19 @@ pub const PROTO_MESSAGES: &str = include_str!("../proto/api.proto");
 /// Name of the configuration file
 pub const PROVER_SERVICE_CONFIG_FILE_NAME: &str = "miden-tx-prover.toml";
 
+/// Name of the file in which the proxy stores the proofs requested with each API key
+pub const PROVER_SERVICE_QUOTAS_FILE_NAME: &str = "miden-tx-prover-quotas.toml";
+
+/// Name of the request header which carries the ID of a proving job
+pub const JOB_ID_HEADER: &str = "x-job-id";
+
+/// Name of the request header which carries the API key of the client
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Name of the request header which carries the commitment to a proof request, as returned by
+/// [generated::ProveTransactionRequest::witness_commitment]
+pub const WITNESS_COMMITMENT_HEADER: &str = "x-witness-commitment";
+
 /// ERRORS
 /// ===============================================================================================
 
@@ -25,6 +38,21 @@ pub enum RemoteTransactionProverError {
 
     /// Indicates that the connection to the server failed.
     ConnectionFailed(String),
+
+    /// Indicates that the requested proving options are invalid.
+    InvalidProvingOptions(String),
+
+    /// Indicates that a request to the server failed.
+    RequestFailed(String),
+
+    /// Indicates that the response of the server could not be deserialized.
+    InvalidResponse(String),
+
+    /// Indicates that the server failed to prove the transaction of a proving job.
+    JobFailed(String),
+
+    /// Indicates that no endpoint could serve a request after all retries.
+    Unavailable(String),
 }
 
 impl std::fmt::Display for RemoteTransactionProverError {
@@ -36,6 +64,21 @@ impl std::fmt::Display for RemoteTransactionProverError {
             RemoteTransactionProverError::ConnectionFailed(endpoint) => {
                 write!(f, "Failed to connect to transaction prover at: {}", endpoint)
             },
+            RemoteTransactionProverError::InvalidProvingOptions(reason) => {
+                write!(f, "Invalid proving options: {}", reason)
+            },
+            RemoteTransactionProverError::RequestFailed(reason) => {
+                write!(f, "Request to transaction prover failed: {}", reason)
+            },
+            RemoteTransactionProverError::InvalidResponse(reason) => {
+                write!(f, "Invalid response from transaction prover: {}", reason)
+            },
+            RemoteTransactionProverError::JobFailed(reason) => {
+                write!(f, "Proving job failed: {}", reason)
+            },
+            RemoteTransactionProverError::Unavailable(reason) => {
+                write!(f, "Transaction prover unavailable: {}", reason)
+            },
         }
     }
 }
@@ -1,4 +1,5 @@
 pub mod api;
+mod cache;
 pub mod commands;
 mod proxy;
 mod utils;
@@ -24,6 +25,7 @@ fn main() -> Result<(), String> {
 mod test {
     use std::time::Duration;
 
+    use clap::Parser;
     use miden_lib::transaction::TransactionKernel;
     use miden_objects::{
         accounts::account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
@@ -31,43 +33,39 @@ mod test {
         notes::NoteType,
         testing::account_code::DEFAULT_AUTH_SCRIPT,
         transaction::{ProvenTransaction, TransactionScript, TransactionWitness},
+        MIN_PROOF_SECURITY_LEVEL,
     };
     use miden_tx::{
         testing::mock_chain::{Auth, MockChain},
         utils::Serializable,
+        HashFunction, LocalTransactionProver,
     };
-    use miden_tx_prover::generated::{
-        api_client::ApiClient, api_server::ApiServer, ProveTransactionRequest,
+    use miden_tx_prover::{
+        generated::{
+            api_client::ApiClient,
+            api_server::{Api, ApiServer},
+            GetQueueDepthRequest, JobRequest, JobState, ProveTransactionRequest, ProvingOptions,
+        },
+        JOB_ID_HEADER, WITNESS_COMMITMENT_HEADER,
     };
+    use pingora::lb::{health_check::HealthCheck, Backend};
     use tokio::net::TcpListener;
     use tonic::Request;
 
-    use crate::api::ProverRpcApi;
-
-    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
-    async fn test_prove_transaction() {
-        // Start the server in the background
-        let listener = TcpListener::bind("127.0.0.1:50052").await.unwrap();
-        let api_service = ApiServer::new(ProverRpcApi::default());
-
-        // Spawn the server as a background task
-        tokio::spawn(async move {
-            tonic::transport::Server::builder()
-                .accept_http1(true)
-                .add_service(tonic_web::enable(api_service))
-                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
-                .await
-                .unwrap();
-        });
-
-        // Give the server some time to start
-        tokio::time::sleep(Duration::from_secs(1)).await;
-
-        // Set up a gRPC client to send the request
-        let mut client = ApiClient::connect("http://127.0.0.1:50052").await.unwrap();
-        let mut client_2 = ApiClient::connect("http://127.0.0.1:50052").await.unwrap();
+    use crate::{
+        api::{
+            jobs::DEFAULT_JOB_RETENTION, metrics::serve_metrics, pool::ProverPool, ProverRpcApi,
+        },
+        cache::ProofCache,
+        commands::{
+            offline::{Prove, Verify},
+            ProxyConfig,
+        },
+        proxy::health_check::WorkerHealthCheck,
+    };
 
-        // Create a mock transaction to send to the server
+    /// Returns the witness of a mock transaction which consumes a P2ID note.
+    fn mock_transaction_witness() -> TransactionWitness {
         let mut mock_chain = MockChain::new();
         let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
 
@@ -95,14 +93,43 @@ mod test {
 
         let executed_transaction = tx_context.execute().unwrap();
 
-        let transaction_witness = TransactionWitness::from(executed_transaction);
+        TransactionWitness::from(executed_transaction)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    async fn test_prove_transaction() {
+        // Start the server in the background
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let api_service = ApiServer::new(ProverRpcApi::default());
+
+        // Spawn the server as a background task
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .accept_http1(true)
+                .add_service(tonic_web::enable(api_service))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+                .await
+                .unwrap();
+        });
+
+        // Give the server some time to start
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        // Set up a gRPC client to send the request
+        let mut client = ApiClient::connect(address.clone()).await.unwrap();
+        let mut client_2 = ApiClient::connect(address).await.unwrap();
+
+        let transaction_witness = mock_transaction_witness();
 
         let request_1 = Request::new(ProveTransactionRequest {
             transaction_witness: transaction_witness.to_bytes(),
+            proving_options: None,
         });
 
         let request_2 = Request::new(ProveTransactionRequest {
             transaction_witness: transaction_witness.to_bytes(),
+            proving_options: Some(ProvingOptions::new(MIN_PROOF_SECURITY_LEVEL, None)),
         });
 
         // Send both requests concurrently
@@ -113,16 +140,234 @@ mod test {
 
         let (response_1, response_2) = (t1.await.unwrap(), t2.await.unwrap());
 
-        // Check the success response
-        assert!(response_1.is_ok() || response_2.is_ok());
+        // The second request waits in the queue until the first one is proven, so both succeed
+        for response in [response_1, response_2] {
+            let response = response.unwrap().into_inner();
+            assert!(response.security_level >= MIN_PROOF_SECURITY_LEVEL);
+
+            // Cast into a ProvenTransaction
+            let _proven_transaction: ProvenTransaction =
+                response.try_into().expect("Failed to convert response");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prove_transaction_rejects_requests_when_queue_is_full() {
+        let api = ProverRpcApi::new(
+            LocalTransactionProver::default().proof_options().clone(),
+            DEFAULT_JOB_RETENTION,
+            ProverPool::new(1, 1),
+            ProofCache::default(),
+        );
+
+        let request = Request::new(GetQueueDepthRequest {});
+        let queue_depth = api.get_queue_depth(request).await.unwrap().into_inner();
+        assert_eq!(queue_depth.max_concurrent_proofs, 1);
+        assert_eq!(queue_depth.max_queued_proofs, 1);
+
+        // Requests are rejected while the queue is held by another request
+        let _ticket = api.prover_pool().enqueue().unwrap();
+        let transaction_witness = mock_transaction_witness().to_bytes();
+
+        let request = Request::new(ProveTransactionRequest {
+            transaction_witness: transaction_witness.clone(),
+            proving_options: None,
+        });
+        let status = api.prove_transaction(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+        let request = Request::new(ProveTransactionRequest {
+            transaction_witness,
+            proving_options: None,
+        });
+        let status = api.submit_proving_job(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+        let request = Request::new(GetQueueDepthRequest {});
+        let queue_depth = api.get_queue_depth(request).await.unwrap().into_inner();
+        assert_eq!(queue_depth.queued_proofs, 1);
+    }
+
+    #[tokio::test]
+    async fn test_prove_transaction_rejects_invalid_proving_options() {
+        let api = ProverRpcApi::default();
+
+        let below_minimum = ProvingOptions::new(MIN_PROOF_SECURITY_LEVEL - 1, None);
+        let unsupported_hash = ProvingOptions::new(128, Some(HashFunction::Blake3_192));
+
+        for proving_options in [below_minimum, unsupported_hash] {
+            let request = Request::new(ProveTransactionRequest {
+                transaction_witness: vec![],
+                proving_options: Some(proving_options),
+            });
 
-        // Check the failure response
-        assert!(response_1.is_err() || response_2.is_err());
+            let status = api.prove_transaction(request).await.unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_proving_job_lifecycle() {
+        let api = ProverRpcApi::default();
+
+        let request = Request::new(ProveTransactionRequest {
+            transaction_witness: mock_transaction_witness().to_bytes(),
+            proving_options: None,
+        });
+        let job_id = api.submit_proving_job(request).await.unwrap().into_inner().job_id;
 
-        let response_success = response_1.or(response_2).unwrap();
+        // Poll the job status until the job finishes
+        let status = loop {
+            let request = Request::new(JobRequest { job_id: job_id.clone() });
+            let status = api.get_job_status(request).await.unwrap().into_inner();
+            if !matches!(status.state(), JobState::Queued | JobState::Proving) {
+                break status;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        };
+        assert_eq!(status.state(), JobState::Done);
+        assert!(status.submitted_at <= status.started_at.unwrap());
+        assert!(status.started_at.unwrap() <= status.finished_at.unwrap());
 
-        // Cast into a ProvenTransaction
+        let request = Request::new(JobRequest { job_id: job_id.clone() });
+        let response = api.get_job_result(request).await.unwrap().into_inner();
         let _proven_transaction: ProvenTransaction =
-            response_success.into_inner().try_into().expect("Failed to convert response");
+            response.try_into().expect("Failed to convert response");
+
+        // Unknown jobs are reported as not found
+        let request = Request::new(JobRequest { job_id: "unknown".to_string() });
+        let status = api.get_job_status(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        // The job ID header used by the proxy for routing must match the queried job
+        let mut request = Request::new(JobRequest { job_id });
+        request.metadata_mut().insert(JOB_ID_HEADER, "other".parse().unwrap());
+        let status = api.get_job_status(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_of_workers() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let api_service = ApiServer::new(ProverRpcApi::default());
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(api_service)
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+                .await
+                .unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let health_check = WorkerHealthCheck::new(&ProxyConfig::default());
+
+        // A running worker passes the health check
+        let worker = Backend::new(&address.to_string()).unwrap();
+        health_check.check(&worker).await.unwrap();
+
+        // A worker which cannot be reached fails the health check
+        let closed_address = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let worker = Backend::new(&closed_address.to_string()).unwrap();
+        assert!(health_check.check(&worker).await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_worker_metrics() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let metrics_url = format!("http://{}/metrics", listener.local_addr().unwrap());
+        tokio::spawn(serve_metrics(listener));
+
+        let api = ProverRpcApi::default();
+        let request = Request::new(ProveTransactionRequest {
+            transaction_witness: mock_transaction_witness().to_bytes(),
+            proving_options: None,
+        });
+        api.prove_transaction(request).await.unwrap();
+
+        // The proof and the characteristics of the transaction are exported
+        let metrics = reqwest::get(metrics_url).await.unwrap().text().await.unwrap();
+        for metric in [
+            "tx_prover_worker_requests_total{method=\"prove_transaction\"}",
+            "tx_prover_worker_proofs_total{result=\"success\"}",
+            "tx_prover_worker_proving_duration_seconds_count",
+            "tx_prover_transaction_cycles_count",
+            "tx_prover_transaction_input_notes_count",
+            "tx_prover_transaction_output_notes_count",
+        ] {
+            assert!(metrics.contains(metric), "missing metric {metric}");
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_identical_proof_requests_are_proven_once() {
+        // With a single queue slot, the third request is only served if the identical requests
+        // wait for the proof in flight instead of being queued
+        let api = ProverRpcApi::new(
+            LocalTransactionProver::default().proof_options().clone(),
+            DEFAULT_JOB_RETENTION,
+            ProverPool::new(1, 1),
+            ProofCache::default(),
+        );
+        let transaction_witness = mock_transaction_witness().to_bytes();
+        let request = || {
+            Request::new(ProveTransactionRequest {
+                transaction_witness: transaction_witness.clone(),
+                proving_options: None,
+            })
+        };
+
+        let (response_1, response_2, response_3) = tokio::join!(
+            api.prove_transaction(request()),
+            api.prove_transaction(request()),
+            api.prove_transaction(request())
+        );
+        let proven_transaction = response_1.unwrap().into_inner().proven_transaction;
+        assert_eq!(response_2.unwrap().into_inner().proven_transaction, proven_transaction);
+        assert_eq!(response_3.unwrap().into_inner().proven_transaction, proven_transaction);
+
+        // A commitment which does not match the request is rejected
+        let mut request = request();
+        request
+            .metadata_mut()
+            .insert(WITNESS_COMMITMENT_HEADER, "0x00".parse().unwrap());
+        let status = api.prove_transaction(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_prove_and_verify_offline() {
+        let dir = std::env::temp_dir();
+        let id = rand::random::<u64>();
+        let witness_path = dir.join(format!("witness-{id}.bin"));
+        let tx_path = dir.join(format!("proven-tx-{id}.bin"));
+        std::fs::write(&witness_path, mock_transaction_witness().to_bytes()).unwrap();
+
+        let prove = Prove::try_parse_from([
+            "prove",
+            "--witness",
+            witness_path.to_str().unwrap(),
+            "--out",
+            tx_path.to_str().unwrap(),
+        ])
+        .unwrap();
+        prove.execute().unwrap();
+
+        let verify = Verify::try_parse_from(["verify", "--tx", tx_path.to_str().unwrap()]).unwrap();
+        verify.execute().unwrap();
+
+        // The proof does not reach a security level above the one it was generated at
+        let verify = Verify::try_parse_from([
+            "verify",
+            "--tx",
+            tx_path.to_str().unwrap(),
+            "--security-level",
+            "128",
+        ])
+        .unwrap();
+        assert!(verify.execute().is_err());
+
+        std::fs::remove_file(witness_path).unwrap();
+        std::fs::remove_file(tx_path).unwrap();
     }
 }
@@ -1,12 +1,35 @@
 use alloc::{
     boxed::Box,
+    format,
     string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::Serializable,
+};
+use miden_tx::{
+    utils::sync::RwLock, LocalTransactionProver, ProvingOptions, TransactionProver,
+    TransactionProverError,
+};
+use tonic::metadata::AsciiMetadataValue;
+
+use crate::{
+    generated::{self, api_client::ApiClient, JobStatusResponse},
+    RemoteTransactionProverError, API_KEY_HEADER, JOB_ID_HEADER, WITNESS_COMMITMENT_HEADER,
 };
 
-use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
-use miden_tx::{utils::sync::RwLock, TransactionProver, TransactionProverError};
+#[cfg(target_arch = "wasm32")]
+type Client = ApiClient<tonic_web_wasm_client::Client>;
 
-use crate::{generated::api_client::ApiClient, RemoteTransactionProverError};
+#[cfg(not(target_arch = "wasm32"))]
+type Client = ApiClient<tonic::transport::Channel>;
 
 // REMOTE TRANSACTION PROVER
 // ================================================================================================
@@ -18,51 +41,396 @@ use crate::{generated::api_client::ApiClient, RemoteTransactionProverError};
 /// transport. Otherwise, it uses the built-in `tonic::transport` for native platforms.
 ///
 /// The transport layer connection is established lazily when the first transaction is proven.
+///
+/// Unless proving options are set via [RemoteTransactionProver::with_proving_options], the
+/// transactions are proven with the proving options configured on the server.
+///
+/// By default, each transaction is proven with a single request which waits for the proof. Large
+/// transactions can instead be submitted as proving jobs whose status is then polled, see
+/// [RemoteTransactionProver::with_job_polling].
+///
+/// Servers which require clients to authenticate expect an API key, which can be set via
+/// [RemoteTransactionProver::with_api_key].
+///
+/// A prover can be created with several endpoints via [RemoteTransactionProver::from_endpoints].
+/// Requests which fail with a transient error are retried on the other endpoints and then, after
+/// a backoff, on all endpoints again, as configured by the [RetryPolicy]. Once the retries are
+/// exhausted, transactions can be proven locally instead, see
+/// [RemoteTransactionProver::with_local_fallback].
 pub struct RemoteTransactionProver {
-    #[cfg(target_arch = "wasm32")]
-    client: RwLock<Option<ApiClient<tonic_web_wasm_client::Client>>>,
+    endpoints: Vec<RemoteEndpoint>,
+
+    /// Index of the endpoint which served the last successful request, and to which requests are
+    /// sent first.
+    current_endpoint: AtomicUsize,
 
-    #[cfg(not(target_arch = "wasm32"))]
-    client: RwLock<Option<ApiClient<tonic::transport::Channel>>>,
+    proving_options: Option<generated::ProvingOptions>,
 
-    endpoint: String,
+    #[cfg(feature = "std")]
+    poll_interval: Option<Duration>,
+
+    api_key: Option<AsciiMetadataValue>,
+
+    retry_policy: RetryPolicy,
+
+    fallback: Option<LocalTransactionProver>,
+}
+
+/// An endpoint of a [RemoteTransactionProver], with its client once connected.
+struct RemoteEndpoint {
+    address: String,
+    client: RwLock<Option<Client>>,
 }
 
 impl RemoteTransactionProver {
     /// Creates a new [RemoteTransactionProver] with the specified gRPC server endpoint. The
     /// endpoint should be in the format `{protocol}://{hostname}:{port}`.
     pub fn new(endpoint: &str) -> Self {
+        Self::from_endpoint_addresses(vec![endpoint.to_string()])
+    }
+
+    /// Creates a new [RemoteTransactionProver] with the specified gRPC server endpoints, in order
+    /// of preference. The endpoints should be in the format `{protocol}://{hostname}:{port}`.
+    ///
+    /// # Errors
+    /// Returns an error if no endpoint is provided.
+    pub fn from_endpoints(endpoints: &[&str]) -> Result<Self, RemoteTransactionProverError> {
+        if endpoints.is_empty() {
+            return Err(RemoteTransactionProverError::InvalidEndpoint(
+                "no endpoint provided".to_string(),
+            ));
+        }
+
+        Ok(Self::from_endpoint_addresses(
+            endpoints.iter().map(|endpoint| endpoint.to_string()).collect(),
+        ))
+    }
+
+    /// Returns this [RemoteTransactionProver] configured to request proofs generated with the
+    /// specified proving options.
+    ///
+    /// # Errors
+    /// Returns an error if the proving options target a security level lower than
+    /// [miden_objects::MIN_PROOF_SECURITY_LEVEL], or cannot be satisfied by the server.
+    pub fn with_proving_options(
+        mut self,
+        proving_options: generated::ProvingOptions,
+    ) -> Result<Self, RemoteTransactionProverError> {
+        ProvingOptions::try_from(proving_options)?;
+        self.proving_options = Some(proving_options);
+        Ok(self)
+    }
+
+    /// Returns this [RemoteTransactionProver] configured to prove transactions by submitting a
+    /// proving job and polling its status at the specified interval until the job finishes.
+    #[cfg(feature = "std")]
+    pub fn with_job_polling(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Returns this [RemoteTransactionProver] configured to send the specified API key with each
+    /// request.
+    ///
+    /// # Errors
+    /// Returns an error if the API key is not a valid header value.
+    pub fn with_api_key(mut self, api_key: &str) -> Result<Self, RemoteTransactionProverError> {
+        let api_key = api_key.parse().map_err(|_| {
+            RemoteTransactionProverError::RequestFailed("invalid API key".to_string())
+        })?;
+        self.api_key = Some(api_key);
+        Ok(self)
+    }
+
+    /// Returns this [RemoteTransactionProver] configured to retry the requests which fail with a
+    /// transient error with the specified policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns this [RemoteTransactionProver] configured to prove transactions with the specified
+    /// local prover when no endpoint is available after all retries.
+    ///
+    /// Transactions proven locally use the proving options of the local prover. Requests which
+    /// are rejected by an endpoint, for instance because of an invalid witness, do not fall back to
+    /// the local prover.
+    pub fn with_local_fallback(mut self, prover: LocalTransactionProver) -> Self {
+        self.fallback = Some(prover);
+        self
+    }
+
+    /// Submits a job to prove the provided transaction witness, and returns the submitted job.
+    ///
+    /// The job is submitted to the first available endpoint, which also receives the subsequent
+    /// status and result queries of the job, even if other requests fail over to another endpoint
+    /// in the meantime.
+    pub async fn submit_proving_job(
+        &self,
+        tx_witness: &TransactionWitness,
+    ) -> Result<ProvingJob, RemoteTransactionProverError> {
+        let (message, commitment) = self.prove_message(tx_witness);
+        let (endpoint, response) = self
+            .send(None, |mut client| {
+                let request = self.prove_request(&message, &commitment);
+                async move { client.submit_proving_job(request).await }
+            })
+            .await?;
+
+        Ok(ProvingJob { id: response.job_id, endpoint })
+    }
+
+    /// Returns the status of the specified proving job, which is queried from the endpoint which
+    /// accepted the job.
+    pub async fn get_job_status(
+        &self,
+        job: &ProvingJob,
+    ) -> Result<JobStatusResponse, RemoteTransactionProverError> {
+        let job_id_header = parse_job_id(&job.id)?;
+        let (_, response) = self
+            .send(Some(job.endpoint), |mut client| {
+                let request = self.job_request(&job.id, &job_id_header);
+                async move { client.get_job_status(request).await }
+            })
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Returns the transaction proven by the specified proving job, which is queried from the
+    /// endpoint which accepted the job.
+    ///
+    /// # Errors
+    /// Returns an error if the job has not finished yet or failed.
+    pub async fn get_job_result(
+        &self,
+        job: &ProvingJob,
+    ) -> Result<ProvenTransaction, RemoteTransactionProverError> {
+        let job_id_header = parse_job_id(&job.id)?;
+        let (_, response) = self
+            .send(Some(job.endpoint), |mut client| {
+                let request = self.job_request(&job.id, &job_id_header);
+                async move { client.get_job_result(request).await }
+            })
+            .await?;
+
+        ProvenTransaction::try_from(response)
+            .map_err(|err| RemoteTransactionProverError::InvalidResponse(err.to_string()))
+    }
+
+    /// Returns a new [RemoteTransactionProver] with the specified endpoint addresses and the
+    /// default configuration.
+    fn from_endpoint_addresses(addresses: Vec<String>) -> Self {
         RemoteTransactionProver {
-            endpoint: endpoint.to_string(),
-            client: RwLock::new(None),
+            endpoints: addresses
+                .into_iter()
+                .map(|address| RemoteEndpoint { address, client: RwLock::new(None) })
+                .collect(),
+            current_endpoint: AtomicUsize::new(0),
+            proving_options: None,
+            #[cfg(feature = "std")]
+            poll_interval: None,
+            api_key: None,
+            retry_policy: RetryPolicy::default(),
+            fallback: None,
         }
     }
 
-    /// Establishes a connection to the remote transaction prover server. The connection is
-    /// mantained for the lifetime of the prover. If the connection is already established, this
-    /// method does nothing.
-    async fn connect(&self) -> Result<(), RemoteTransactionProverError> {
-        let mut client = self.client.write();
-        if client.is_some() {
-            return Ok(());
+    /// Proves the provided transaction witness with the remote endpoints.
+    async fn prove_remotely(
+        &self,
+        tx_witness: &TransactionWitness,
+    ) -> Result<ProvenTransaction, RemoteTransactionProverError> {
+        #[cfg(feature = "std")]
+        if let Some(poll_interval) = self.poll_interval {
+            return self.prove_with_polling(tx_witness, poll_interval).await;
+        }
+
+        let (message, commitment) = self.prove_message(tx_witness);
+        let (_, response) = self
+            .send(None, |mut client| {
+                let request = self.prove_request(&message, &commitment);
+                async move { client.prove_transaction(request).await }
+            })
+            .await?;
+
+        // Deserialize the response bytes back into a ProvenTransaction.
+        ProvenTransaction::try_from(response)
+            .map_err(|err| RemoteTransactionProverError::InvalidResponse(err.to_string()))
+    }
+
+    /// Proves the provided transaction witness by submitting a proving job and polling its
+    /// status at the specified interval until the job finishes.
+    #[cfg(feature = "std")]
+    async fn prove_with_polling(
+        &self,
+        tx_witness: &TransactionWitness,
+        poll_interval: Duration,
+    ) -> Result<ProvenTransaction, RemoteTransactionProverError> {
+        let job = self.submit_proving_job(tx_witness).await?;
+
+        loop {
+            let status = self.get_job_status(&job).await?;
+            match status.state() {
+                generated::JobState::Queued | generated::JobState::Proving => {
+                    tokio::time::sleep(poll_interval).await
+                },
+                generated::JobState::Done => return self.get_job_result(&job).await,
+                generated::JobState::Failed => {
+                    return Err(RemoteTransactionProverError::JobFailed(
+                        status.error.unwrap_or_default(),
+                    ))
+                },
+            }
+        }
+    }
+
+    /// Sends a request with the provided function, retrying it as configured by the retry policy
+    /// of this prover, and returns the index of the endpoint which served the request together
+    /// with the response.
+    ///
+    /// If an endpoint is specified, the request is only sent to that endpoint. Otherwise, the
+    /// request is sent to the endpoint which served the last successful request, and fails over
+    /// to the other endpoints in turn.
+    ///
+    /// # Errors
+    /// Returns an [RemoteTransactionProverError::Unavailable] error if the request still fails
+    /// with a transient error after all retries, or the error of the first request which fails
+    /// with another error.
+    async fn send<T, F, Fut>(
+        &self,
+        endpoint: Option<usize>,
+        send: F,
+    ) -> Result<(usize, T), RemoteTransactionProverError>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<T>, tonic::Status>>,
+    {
+        let (first_endpoint, num_endpoints) = match endpoint {
+            Some(endpoint) => (endpoint, 1),
+            None => (self.current_endpoint.load(Ordering::Relaxed), self.endpoints.len()),
+        };
+
+        let mut last_error = String::new();
+        for retry in 0..=self.max_retries() {
+            if retry > 0 {
+                sleep(self.retry_policy.backoff(retry)).await;
+            }
+
+            for offset in 0..num_endpoints {
+                let index = (first_endpoint + offset) % self.endpoints.len();
+                let client = match self.client(index).await {
+                    Ok(client) => client,
+                    Err(err) => {
+                        last_error = err.to_string();
+                        continue;
+                    },
+                };
+
+                match send(client).await {
+                    Ok(response) => {
+                        self.current_endpoint.store(index, Ordering::Relaxed);
+                        return Ok((index, response.into_inner()));
+                    },
+                    Err(status) if is_transient(&status) => {
+                        last_error = format!("{}: {}", self.endpoints[index].address, status);
+                    },
+                    Err(status) => return Err(request_failed(status)),
+                }
+            }
+        }
+
+        Err(RemoteTransactionProverError::Unavailable(last_error))
+    }
+
+    /// Returns the maximum number of times a request is retried on all endpoints.
+    ///
+    /// Without the standard library, there is no timer to back off with, so requests are not
+    /// retried.
+    fn max_retries(&self) -> u32 {
+        if cfg!(feature = "std") {
+            self.retry_policy.max_retries
+        } else {
+            0
+        }
+    }
+
+    /// Returns a request with the provided message, which carries the API key of this prover, if
+    /// any.
+    fn request<T>(&self, message: T) -> tonic::Request<T> {
+        let mut request = tonic::Request::new(message);
+        if let Some(api_key) = &self.api_key {
+            request.metadata_mut().insert(API_KEY_HEADER, api_key.clone());
+        }
+        request
+    }
+
+    /// Returns the message of a request to prove the provided transaction witness, together with
+    /// the commitment to the message.
+    fn prove_message(
+        &self,
+        tx_witness: &TransactionWitness,
+    ) -> (generated::ProveTransactionRequest, AsciiMetadataValue) {
+        let message = generated::ProveTransactionRequest {
+            transaction_witness: tx_witness.to_bytes(),
+            proving_options: self.proving_options,
+        };
+        let commitment = message
+            .witness_commitment()
+            .parse()
+            .expect("hex-encoded commitment should be a valid header value");
+        (message, commitment)
+    }
+
+    /// Returns a request to prove a transaction with the provided message. The commitment to the
+    /// message is also set as a header so that the proxy can serve cached proofs without reading
+    /// the request body.
+    fn prove_request(
+        &self,
+        message: &generated::ProveTransactionRequest,
+        commitment: &AsciiMetadataValue,
+    ) -> tonic::Request<generated::ProveTransactionRequest> {
+        let mut request = self.request(message.clone());
+        request.metadata_mut().insert(WITNESS_COMMITMENT_HEADER, commitment.clone());
+        request
+    }
+
+    /// Returns a request for the specified proving job. The job ID is also set as a header so
+    /// that the proxy can route the request to the worker which received the job.
+    fn job_request(
+        &self,
+        job_id: &str,
+        job_id_header: &AsciiMetadataValue,
+    ) -> tonic::Request<generated::JobRequest> {
+        let mut request = self.request(generated::JobRequest { job_id: job_id.to_string() });
+        request.metadata_mut().insert(JOB_ID_HEADER, job_id_header.clone());
+        request
+    }
+
+    /// Returns a client connected to the specified endpoint. The connection is mantained for the
+    /// lifetime of the prover, and is established if it does not exist yet.
+    async fn client(&self, index: usize) -> Result<Client, RemoteTransactionProverError> {
+        let endpoint = &self.endpoints[index];
+        if let Some(client) = endpoint.client.read().clone() {
+            return Ok(client);
         }
 
         #[cfg(target_arch = "wasm32")]
         let new_client = {
-            let web_client = tonic_web_wasm_client::Client::new(self.endpoint.clone());
+            let web_client = tonic_web_wasm_client::Client::new(endpoint.address.clone());
             ApiClient::new(web_client)
         };
 
         #[cfg(not(target_arch = "wasm32"))]
         let new_client = {
-            ApiClient::connect(self.endpoint.clone()).await.map_err(|_| {
-                RemoteTransactionProverError::ConnectionFailed(self.endpoint.to_string())
+            ApiClient::connect(endpoint.address.clone()).await.map_err(|_| {
+                RemoteTransactionProverError::ConnectionFailed(endpoint.address.clone())
             })?
         };
 
-        *client = Some(new_client);
+        *endpoint.client.write() = Some(new_client.clone());
 
-        Ok(())
+        Ok(new_client)
     }
 }
 
@@ -72,35 +440,334 @@ impl TransactionProver for RemoteTransactionProver {
         &self,
         tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
-        use miden_objects::utils::Serializable;
-        self.connect().await.map_err(|err| {
-            TransactionProverError::InternalError(format!(
-                "Failed to connect to the remote prover: {}",
-                err
-            ))
-        })?;
+        match (self.prove_remotely(&tx_witness).await, &self.fallback) {
+            (Err(RemoteTransactionProverError::Unavailable(_)), Some(fallback)) => {
+                fallback.prove(tx_witness).await
+            },
+            (result, _) => {
+                result.map_err(|err| TransactionProverError::InternalError(err.to_string()))
+            },
+        }
+    }
+}
 
-        let mut client = self.client.write();
+// PROVING JOB
+// ================================================================================================
 
-        let request = tonic::Request::new(crate::generated::ProveTransactionRequest {
-            transaction_witness: tx_witness.to_bytes(),
-        });
+/// A proving job submitted by a [RemoteTransactionProver], as returned by
+/// [RemoteTransactionProver::submit_proving_job].
+///
+/// The job is bound to the endpoint which accepted it, so that its status and result are queried
+/// from that endpoint. A job can therefore only be queried with the prover which submitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvingJob {
+    id: String,
+    /// Index of the endpoint which accepted the job.
+    endpoint: usize,
+}
 
-        let response = client
-            .as_mut()
-            .expect("client should be connected")
-            .prove_transaction(request)
-            .await
-            .map_err(|err| TransactionProverError::InternalError(err.to_string()))?;
+impl ProvingJob {
+    /// Returns the ID of the job.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
 
-        // Deserialize the response bytes back into a ProvenTransaction.
+// RETRY POLICY
+// ================================================================================================
+
+/// Policy used by a [RemoteTransactionProver] to retry the requests which fail with a transient
+/// error.
+///
+/// Transient errors are failures to connect to an endpoint, and the `unavailable` and
+/// `resource_exhausted` gRPC statuses. A request which fails with a transient error is first sent
+/// to the other endpoints of the prover. Once all endpoints failed, the request is retried on all
+/// endpoints after a backoff, which doubles after each retry.
+///
+/// Without the `std` feature, there is no timer to wait on, so requests are only sent once to each
+/// endpoint and are not retried after all endpoints failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of times a request is retried on all endpoints after the first attempt.
+    pub max_retries: u32,
+    /// Backoff before the first retry.
+    pub initial_backoff: Duration,
+    /// Maximum backoff between two retries.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Returns the backoff before the specified retry, starting from 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let multiplier = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff.saturating_mul(multiplier).min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn request_failed(status: tonic::Status) -> RemoteTransactionProverError {
+    RemoteTransactionProverError::RequestFailed(status.to_string())
+}
+
+/// Returns `true` if a request which failed with the provided status may succeed when retried.
+fn is_transient(status: &tonic::Status) -> bool {
+    matches!(status.code(), tonic::Code::Unavailable | tonic::Code::ResourceExhausted)
+}
+
+/// Parses the provided job ID as a header value.
+fn parse_job_id(job_id: &str) -> Result<AsciiMetadataValue, RemoteTransactionProverError> {
+    job_id.parse().map_err(|_| {
+        RemoteTransactionProverError::RequestFailed(format!("invalid job ID {job_id}"))
+    })
+}
+
+/// Waits for the specified duration before a retry. Without the standard library, there is no
+/// timer to wait on, and requests are not retried, see [RemoteTransactionProver::max_retries].
+async fn sleep(duration: Duration) {
+    #[cfg(feature = "std")]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(not(feature = "std"))]
+    let _ = duration;
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use miden_objects::{
+        transaction::{ProvenTransaction, TransactionWitness},
+        ONE,
+    };
+    use miden_tx::{testing::TransactionContextBuilder, LocalTransactionProver, TransactionProver};
+    use tokio::net::TcpListener;
+    use tonic::{Code, Request, Response, Status};
+
+    use super::{RemoteTransactionProver, RetryPolicy};
+    use crate::generated::{
+        api_server::{Api, ApiServer},
+        GetQueueDepthRequest, GetQueueDepthResponse, HealthRequest, HealthResponse, JobRequest,
+        JobState, JobStatusResponse, ProveTransactionRequest, ProveTransactionResponse,
+        SubmitProvingJobResponse,
+    };
+
+    /// Endpoint on which no server listens.
+    const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1";
+
+    /// A retry policy which does not slow the tests down.
+    const FAST_RETRIES: RetryPolicy = RetryPolicy {
+        max_retries: 2,
+        initial_backoff: core::time::Duration::from_millis(10),
+        max_backoff: core::time::Duration::from_millis(20),
+    };
+
+    /// A server which fails the first proof requests it receives with the specified status code,
+    /// and then returns the specified response. Proving jobs always succeed, and each server
+    /// only knows the job it assigns its own job ID to.
+    struct FlakyServer {
+        failures: usize,
+        code: Code,
+        response: ProveTransactionResponse,
+        requests: Arc<AtomicUsize>,
+        job_id: String,
+    }
+
+    impl FlakyServer {
+        /// Returns an error if the provided request is not for the job of this server.
+        fn check_job(&self, request: &Request<JobRequest>) -> Result<(), Status> {
+            if request.get_ref().job_id != self.job_id {
+                return Err(Status::not_found("job not found"));
+            }
+            Ok(())
+        }
+    }
+
+    #[tonic::async_trait]
+    impl Api for FlakyServer {
+        async fn prove_transaction(
+            &self,
+            _request: Request<ProveTransactionRequest>,
+        ) -> Result<Response<ProveTransactionResponse>, Status> {
+            if self.requests.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(Status::new(self.code, "flaky server"));
+            }
+            Ok(Response::new(self.response.clone()))
+        }
+
+        async fn submit_proving_job(
+            &self,
+            _request: Request<ProveTransactionRequest>,
+        ) -> Result<Response<SubmitProvingJobResponse>, Status> {
+            Ok(Response::new(SubmitProvingJobResponse { job_id: self.job_id.clone() }))
+        }
+
+        async fn get_job_status(
+            &self,
+            request: Request<JobRequest>,
+        ) -> Result<Response<JobStatusResponse>, Status> {
+            self.check_job(&request)?;
+            Ok(Response::new(JobStatusResponse {
+                job_id: self.job_id.clone(),
+                state: JobState::Done.into(),
+                ..Default::default()
+            }))
+        }
+
+        async fn get_job_result(
+            &self,
+            request: Request<JobRequest>,
+        ) -> Result<Response<ProveTransactionResponse>, Status> {
+            self.check_job(&request)?;
+            Ok(Response::new(self.response.clone()))
+        }
+
+        async fn get_queue_depth(
+            &self,
+            _request: Request<GetQueueDepthRequest>,
+        ) -> Result<Response<GetQueueDepthResponse>, Status> {
+            Ok(Response::new(GetQueueDepthResponse::default()))
+        }
+
+        async fn health(
+            &self,
+            _request: Request<HealthRequest>,
+        ) -> Result<Response<HealthResponse>, Status> {
+            Ok(Response::new(HealthResponse {}))
+        }
+    }
+
+    /// Starts a server which fails the first `failures` proof requests with the specified status
+    /// code, and returns its endpoint together with the number of proof requests it received.
+    async fn start_flaky_server(
+        failures: usize,
+        code: Code,
+        proven_transaction: ProvenTransaction,
+    ) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server = FlakyServer {
+            failures,
+            code,
+            response: ProveTransactionResponse::from(proven_transaction),
+            requests: requests.clone(),
+            job_id: listener.local_addr().unwrap().port().to_string(),
+        };
+
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(ApiServer::new(server))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+
+        (endpoint, requests)
+    }
+
+    /// Returns the witness of a mock transaction.
+    async fn mock_transaction_witness() -> TransactionWitness {
+        let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+            .with_mock_notes_preserved()
+            .build();
+        TransactionWitness::from(tx_context.execute().await.unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_retries_transient_errors_and_fails_over_between_endpoints() {
+        let tx_witness = mock_transaction_witness().await;
+        let proven_transaction =
+            LocalTransactionProver::default().prove(tx_witness.clone()).await.unwrap();
+
+        // The first endpoint is unreachable and the second one is unavailable for the first
+        // request, so the transaction is proven by the second endpoint after one retry
+        let (endpoint, requests) =
+            start_flaky_server(1, Code::Unavailable, proven_transaction.clone()).await;
+        let prover = RemoteTransactionProver::from_endpoints(&[UNREACHABLE_ENDPOINT, &endpoint])
+            .unwrap()
+            .with_retry_policy(FAST_RETRIES);
+        let remote_proven_transaction = prover.prove(tx_witness.clone()).await.unwrap();
+        assert_eq!(remote_proven_transaction.id(), proven_transaction.id());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // Other errors are not retried
+        let (endpoint, requests) =
+            start_flaky_server(1, Code::InvalidArgument, proven_transaction).await;
+        let prover = RemoteTransactionProver::new(&endpoint).with_retry_policy(FAST_RETRIES);
+        assert!(prover.prove(tx_witness).await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // At least one endpoint is required
+        assert!(RemoteTransactionProver::from_endpoints(&[]).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_proving_jobs_are_queried_from_the_endpoint_which_accepted_them() {
+        let tx_witness = mock_transaction_witness().await;
         let proven_transaction =
-            ProvenTransaction::try_from(response.into_inner()).map_err(|_| {
-                TransactionProverError::InternalError(
-                    "Error deserializing received response".to_string(),
-                )
-            })?;
+            LocalTransactionProver::default().prove(tx_witness.clone()).await.unwrap();
+
+        // The first endpoint accepts proving jobs, but is unavailable for proof requests
+        let (job_endpoint, _) =
+            start_flaky_server(usize::MAX, Code::Unavailable, proven_transaction.clone()).await;
+        let (other_endpoint, _) = start_flaky_server(0, Code::Ok, proven_transaction.clone()).await;
+        let prover = RemoteTransactionProver::from_endpoints(&[&job_endpoint, &other_endpoint])
+            .unwrap()
+            .with_retry_policy(FAST_RETRIES);
+        let job = prover.submit_proving_job(&tx_witness).await.unwrap();
+
+        // The job is still queried from its endpoint after a proof request fails over to the
+        // other endpoint
+        prover.prove(tx_witness).await.unwrap();
+        let status = prover.get_job_status(&job).await.unwrap();
+        assert_eq!(status.job_id, job.id());
+        assert_eq!(status.state(), JobState::Done);
+        let job_proven_transaction = prover.get_job_result(&job).await.unwrap();
+        assert_eq!(job_proven_transaction.id(), proven_transaction.id());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_falls_back_to_local_prover_when_endpoints_are_unavailable() {
+        let tx_witness = mock_transaction_witness().await;
+        let account_id = tx_witness.tx_inputs.account().id();
+
+        // Without a fallback, the transaction cannot be proven
+        let prover = RemoteTransactionProver::from_endpoints(&[UNREACHABLE_ENDPOINT])
+            .unwrap()
+            .with_retry_policy(FAST_RETRIES);
+        assert!(prover.prove(tx_witness.clone()).await.is_err());
+
+        // With a fallback, the transaction is proven locally once the retries are exhausted
+        let prover = prover.with_local_fallback(LocalTransactionProver::default());
+        let proven_transaction = prover.prove(tx_witness).await.unwrap();
+        assert_eq!(proven_transaction.account_id(), account_id);
+    }
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_maximum() {
+        let retry_policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: core::time::Duration::from_millis(100),
+            max_backoff: core::time::Duration::from_millis(500),
+        };
 
-        Ok(proven_transaction)
+        let backoffs = (1..=5).map(|retry| retry_policy.backoff(retry).as_millis());
+        assert_eq!(backoffs.collect::<Vec<_>>(), vec![100, 200, 400, 500, 500]);
     }
 }
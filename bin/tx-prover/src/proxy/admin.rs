@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    extract::{Request, State},
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+    Json, Router,
+};
+use pingora::{
+    lb::LoadBalancer as PingoraLoadBalancer, prelude::RoundRobin, server::ShutdownWatch,
+    services::background::BackgroundService,
+};
+use serde::Serialize;
+use tokio::{net::TcpListener, sync::Mutex};
+use tracing::{error, info};
+
+use super::{
+    discovery::{lookup_worker_backend, WorkerDiscovery},
+    metrics::{remove_queue_depth, set_queue_depth},
+    LATENCIES, QUEUES,
+};
+use crate::{
+    commands::WorkerConfig,
+    utils::{load_config_from_file, save_config_to_file},
+};
+
+/// HTTP service exposing the state of the workers of the proxy.
+///
+/// Every request must carry the admin token of the proxy as a bearer token in its `Authorization`
+/// header, and is rejected with `401 Unauthorized` otherwise.
+///
+/// The service provides the following endpoints:
+/// - `GET /workers`: returns the address, health and queue length of each worker.
+/// - `POST /workers`: adds the worker in the JSON body to the config file and to the proxy.
+/// - `DELETE /workers`: removes the worker in the JSON body from the config file and from the
+///   proxy. The worker stops receiving new requests, but processes the requests already in its
+///   queue.
+///
+/// The proxy is only updated once the config file is saved, so that both stay consistent.
+pub struct AdminService {
+    state: Arc<AdminState>,
+    address: String,
+}
+
+/// State shared by the endpoints of the admin service.
+struct AdminState {
+    workers: Arc<PingoraLoadBalancer<RoundRobin>>,
+    discovery: WorkerDiscovery,
+    /// Token which authorizes the requests to the admin service.
+    token: String,
+    /// Lock which serializes the updates of the workers and of the config file.
+    update_lock: Mutex<()>,
+}
+
+/// Status of a worker, as returned by the admin service.
+#[derive(Serialize)]
+pub struct WorkerStatus {
+    /// Address of the worker.
+    pub address: String,
+    /// Whether the worker passes its health checks and receives new requests.
+    pub healthy: bool,
+    /// Whether the worker was removed from the proxy and is processing its remaining requests.
+    pub draining: bool,
+    /// Number of requests in the queue of the worker, including the one being processed.
+    pub queued_requests: usize,
+}
+
+/// Error response of the admin service.
+type AdminError = (StatusCode, String);
+
+impl AdminService {
+    pub fn new(
+        workers: Arc<PingoraLoadBalancer<RoundRobin>>,
+        discovery: WorkerDiscovery,
+        address: String,
+        token: String,
+    ) -> Self {
+        let state = AdminState {
+            workers,
+            discovery,
+            token,
+            update_lock: Mutex::new(()),
+        };
+        Self { state: Arc::new(state), address }
+    }
+}
+
+#[async_trait]
+impl BackgroundService for AdminService {
+    async fn start(&self, mut shutdown: ShutdownWatch) {
+        let listener = match TcpListener::bind(&self.address).await {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!("Failed to bind admin service to {}: {err}", self.address);
+                return;
+            },
+        };
+        info!("Admin service listening on {}", self.address);
+
+        let router = Router::new()
+            .route("/workers", get(list_workers).post(add_worker).delete(remove_worker))
+            .route_layer(middleware::from_fn_with_state(self.state.clone(), authorize))
+            .with_state(self.state.clone());
+
+        let result = axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                let _ = shutdown.changed().await;
+            })
+            .await;
+        if let Err(err) = result {
+            error!("Admin service failed: {err}");
+        }
+    }
+}
+
+// ENDPOINTS
+// ================================================================================================
+
+/// Returns the status of each worker of the proxy, including the workers being drained.
+async fn list_workers(State(state): State<Arc<AdminState>>) -> Json<Vec<WorkerStatus>> {
+    let queues = QUEUES.read().await;
+    let workers = state.workers.backends().get_backend();
+
+    let mut statuses: Vec<WorkerStatus> = workers
+        .iter()
+        .map(|worker| WorkerStatus {
+            address: worker.addr.to_string(),
+            healthy: state.workers.backends().ready(worker),
+            draining: false,
+            queued_requests: queues.get(worker).map_or(0, Vec::len),
+        })
+        .collect();
+
+    statuses.extend(queues.iter().filter(|(worker, _)| !workers.contains(*worker)).map(
+        |(worker, queue)| WorkerStatus {
+            address: worker.addr.to_string(),
+            healthy: false,
+            draining: true,
+            queued_requests: queue.len(),
+        },
+    ));
+
+    Json(statuses)
+}
+
+/// Adds a worker to the config file and to the proxy.
+///
+/// The config file is updated first, so that the proxy is left unchanged if it cannot be saved.
+async fn add_worker(
+    State(state): State<Arc<AdminState>>,
+    Json(worker): Json<WorkerConfig>,
+) -> Result<StatusCode, AdminError> {
+    let _guard = state.update_lock.lock().await;
+    let backend = lookup_worker_backend(&worker)
+        .await
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+
+    if state.discovery.contains(&backend) {
+        return Err((StatusCode::CONFLICT, format!("Worker {} already exists", backend.addr)));
+    }
+
+    let mut config = load_config_from_file().map_err(internal_error)?;
+    if config.add_worker(worker) {
+        save_config_to_file(&config).map_err(internal_error)?;
+    }
+
+    state.discovery.add(backend.clone());
+    state.workers.update().await.map_err(internal_error)?;
+    let queue_depth = QUEUES.write().await.entry(backend.clone()).or_default().len();
+    set_queue_depth(&backend, queue_depth);
+    info!("Worker {} added", backend.addr);
+
+    Ok(StatusCode::CREATED)
+}
+
+/// Removes a worker from the config file and from the proxy.
+///
+/// The config file is updated first, so that the proxy is left unchanged if it cannot be saved.
+/// The queue of the worker is kept until the requests already in it are processed.
+async fn remove_worker(
+    State(state): State<Arc<AdminState>>,
+    Json(worker): Json<WorkerConfig>,
+) -> Result<StatusCode, AdminError> {
+    let _guard = state.update_lock.lock().await;
+    let backend = lookup_worker_backend(&worker)
+        .await
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+
+    if !state.discovery.contains(&backend) {
+        return Err((StatusCode::NOT_FOUND, format!("Worker {} not found", backend.addr)));
+    }
+
+    let mut config = load_config_from_file().map_err(internal_error)?;
+    if config.remove_worker(&worker) {
+        save_config_to_file(&config).map_err(internal_error)?;
+    }
+
+    state.discovery.remove(&backend);
+    state.workers.update().await.map_err(internal_error)?;
+    {
+        let mut queues = QUEUES.write().await;
+        if queues.get(&backend).is_some_and(Vec::is_empty) {
+            queues.remove(&backend);
+            remove_queue_depth(&backend);
+        }
+    }
+    LATENCIES.write().await.remove(&backend);
+    info!("Worker {} removed", backend.addr);
+
+    Ok(StatusCode::OK)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Rejects the requests which do not carry the admin token of the proxy.
+async fn authorize(
+    State(state): State<Arc<AdminState>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AdminError> {
+    if !is_authorized(request.headers(), &state.token) {
+        return Err((StatusCode::UNAUTHORIZED, "Invalid or missing admin token".into()));
+    }
+    Ok(next.run(request).await)
+}
+
+/// Returns true if the provided headers carry the provided token as a bearer token.
+///
+/// The tokens are compared in constant time, so that the comparison does not leak the token.
+pub fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    let Some(provided) = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return false;
+    };
+
+    provided.len() == token.len()
+        && provided.bytes().zip(token.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// Returns the error response of an unexpected failure of the admin service.
+fn internal_error(err: impl ToString) -> AdminError {
+    let message = err.to_string();
+    error!("Failed to update the workers: {message}");
+    (StatusCode::INTERNAL_SERVER_ERROR, message)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{header::AUTHORIZATION, HeaderMap};
+
+    use super::is_authorized;
+
+    #[test]
+    fn admin_requests_require_admin_token() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_authorized(&headers, "admin-token"));
+        headers.insert(AUTHORIZATION, "Bearer wrong-token".parse().unwrap());
+        assert!(!is_authorized(&headers, "admin-token"));
+        headers.insert(AUTHORIZATION, "admin-token".parse().unwrap());
+        assert!(!is_authorized(&headers, "admin-token"));
+        headers.insert(AUTHORIZATION, "Bearer admin-token".parse().unwrap());
+        assert!(is_authorized(&headers, "admin-token"));
+    }
+}
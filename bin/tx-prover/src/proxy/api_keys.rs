@@ -0,0 +1,301 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use pingora::{server::ShutdownWatch, services::background::BackgroundService};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::commands::ApiKeyConfig;
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Interval at which the quota usage is written to the quota file.
+pub const QUOTA_STORE_INTERVAL: Duration = Duration::from_secs(5);
+
+// API KEYS
+// ================================================================================================
+
+/// API keys accepted by the proxy, together with the number of proofs generated with each key on
+/// the current day.
+///
+/// The quota usage is kept in memory, and written to a file by a [QuotaStore] so that the daily
+/// quotas are kept when the proxy restarts. Days start at midnight UTC.
+pub struct ApiKeys {
+    /// API keys accepted by the proxy, by key.
+    keys: HashMap<String, ApiKeyConfig>,
+    /// Path of the file in which the quota usage is stored.
+    quotas_path: PathBuf,
+    quotas: Mutex<QuotaState>,
+}
+
+/// Number of proofs generated with each API key on a given day.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct QuotaUsage {
+    /// Day of the usage, in days since the UNIX epoch.
+    day: u64,
+    /// Number of proofs generated on the day, by API key name.
+    proofs: HashMap<String, u64>,
+}
+
+/// In-memory state of the proof quotas.
+#[derive(Debug, Default)]
+struct QuotaState {
+    usage: QuotaUsage,
+    /// Number of proof requests in flight, by API key name.
+    reserved: HashMap<String, u64>,
+    /// Whether the usage changed since it was last written to the quota file.
+    dirty: bool,
+}
+
+impl ApiKeys {
+    /// Returns the provided API keys, with the quota usage stored in the specified file.
+    ///
+    /// # Errors
+    /// Returns an error if two API keys have the same key or name, or if the quota usage file
+    /// exists but cannot be read.
+    pub fn new(api_keys: &[ApiKeyConfig], quotas_path: PathBuf) -> Result<Self, String> {
+        let mut keys = HashMap::new();
+        for api_key in api_keys {
+            if keys.values().any(|key: &ApiKeyConfig| key.name == api_key.name) {
+                return Err(format!("Duplicate API key name {}", api_key.name));
+            }
+            if keys.insert(api_key.key.clone(), api_key.clone()).is_some() {
+                return Err(format!("Duplicate key for API key {}", api_key.name));
+            }
+        }
+
+        let usage = match std::fs::read_to_string(&quotas_path) {
+            Ok(quotas) => toml::from_str(&quotas).map_err(|err| {
+                format!("Failed to parse quota file {}: {err}", quotas_path.display())
+            })?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => QuotaUsage::default(),
+            Err(err) => {
+                return Err(format!("Failed to read quota file {}: {err}", quotas_path.display()))
+            },
+        };
+
+        Ok(Self {
+            keys,
+            quotas_path,
+            quotas: Mutex::new(QuotaState { usage, ..Default::default() }),
+        })
+    }
+
+    /// Returns `true` if clients must provide an API key, that is, if any API key is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Returns the configuration of the provided API key, or `None` if the key is unknown.
+    pub fn get(&self, key: &str) -> Option<&ApiKeyConfig> {
+        self.keys.get(key)
+    }
+
+    /// Reserves a proof from the daily quota of the provided API key for a proof request.
+    ///
+    /// The proof only counts towards the quota once the returned [ProofReservation] is used, and
+    /// the reservation is released if it is dropped instead. Reservations in flight count against
+    /// the quota, so that concurrent requests can not exceed it.
+    ///
+    /// Returns `None` if the daily proof quota of the key is already used up.
+    pub fn reserve_proof(self: &Arc<Self>, api_key: &ApiKeyConfig) -> Option<ProofReservation> {
+        let mut quotas = self.lock_quotas();
+        let used = quotas.usage().proofs.get(&api_key.name).copied().unwrap_or_default();
+        let reserved = quotas.reserved.entry(api_key.name.clone()).or_default();
+        if used + *reserved >= api_key.max_proofs_per_day {
+            return None;
+        }
+        *reserved += 1;
+
+        Some(ProofReservation {
+            api_keys: self.clone(),
+            name: Some(api_key.name.clone()),
+        })
+    }
+
+    /// Writes the quota usage to the quota file, if it changed since it was last written.
+    ///
+    /// A failure to store the usage only affects the quotas after a restart, so it is logged and
+    /// the usage is written again on the next call.
+    pub async fn store(&self) {
+        // We use a new scope to release the lock before writing the file
+        let quotas = {
+            let mut quotas = self.lock_quotas();
+            if !quotas.dirty {
+                return;
+            }
+            quotas.dirty = false;
+            toml::to_string(&quotas.usage)
+        };
+
+        let result = match quotas {
+            Ok(quotas) => tokio::fs::write(&self.quotas_path, quotas).await.map_err(|err| {
+                format!("Failed to write quota file {}: {err}", self.quotas_path.display())
+            }),
+            Err(err) => Err(format!("Failed to serialize quota usage: {err}")),
+        };
+        if let Err(err) = result {
+            error!("{err}");
+            self.lock_quotas().dirty = true;
+        }
+    }
+
+    fn lock_quotas(&self) -> MutexGuard<'_, QuotaState> {
+        self.quotas.lock().expect("quota lock should not be poisoned")
+    }
+}
+
+impl QuotaState {
+    /// Returns the quota usage of the current day.
+    fn usage(&mut self) -> &mut QuotaUsage {
+        let today = today();
+        if self.usage.day != today {
+            self.usage = QuotaUsage { day: today, proofs: HashMap::new() };
+            self.dirty = true;
+        }
+        &mut self.usage
+    }
+
+    /// Releases a proof reserved for the API key with the provided name.
+    fn release(&mut self, name: &str) {
+        if let Some(reserved) = self.reserved.get_mut(name) {
+            *reserved -= 1;
+            if *reserved == 0 {
+                self.reserved.remove(name);
+            }
+        }
+    }
+}
+
+// PROOF RESERVATION
+// ================================================================================================
+
+/// A proof reserved from the daily quota of an API key for a proof request in flight.
+///
+/// The proof counts towards the quota once [ProofReservation::use_quota] is called, and the
+/// reservation is released if this value is dropped instead.
+pub struct ProofReservation {
+    api_keys: Arc<ApiKeys>,
+    /// Name of the API key, or `None` once the reservation is used or released.
+    name: Option<String>,
+}
+
+impl ProofReservation {
+    /// Counts the reserved proof towards the daily quota of the API key.
+    pub fn use_quota(mut self) {
+        if let Some(name) = self.name.take() {
+            let mut quotas = self.api_keys.lock_quotas();
+            quotas.release(&name);
+            *quotas.usage().proofs.entry(name).or_default() += 1;
+            quotas.dirty = true;
+        }
+    }
+}
+
+impl Drop for ProofReservation {
+    fn drop(&mut self) {
+        if let Some(name) = self.name.take() {
+            self.api_keys.lock_quotas().release(&name);
+        }
+    }
+}
+
+// QUOTA STORE
+// ================================================================================================
+
+/// Background service which periodically writes the quota usage of the API keys to the quota
+/// file, and writes it a last time when the proxy shuts down.
+pub struct QuotaStore {
+    api_keys: Arc<ApiKeys>,
+    interval: Duration,
+}
+
+impl QuotaStore {
+    pub fn new(api_keys: Arc<ApiKeys>, interval: Duration) -> Self {
+        Self { api_keys, interval }
+    }
+}
+
+#[async_trait]
+impl BackgroundService for QuotaStore {
+    async fn start(&self, mut shutdown: ShutdownWatch) {
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => self.api_keys.store().await,
+                _ = shutdown.changed() => {
+                    self.api_keys.store().await;
+                    return;
+                },
+            }
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the current day in days since the UNIX epoch.
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("current time should be after the UNIX epoch")
+        .as_secs()
+        / SECONDS_PER_DAY
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::ApiKeys;
+    use crate::commands::ApiKeyConfig;
+
+    #[tokio::test]
+    async fn api_key_quotas_survive_restarts() {
+        let api_key = ApiKeyConfig {
+            name: "wallet".to_string(),
+            key: "secret".to_string(),
+            max_req_per_sec: 5,
+            max_proofs_per_day: 2,
+        };
+        let quotas_path =
+            std::env::temp_dir().join(format!("quotas-{}.toml", rand::random::<u64>()));
+
+        let api_keys = Arc::new(ApiKeys::new(&[api_key.clone()], quotas_path.clone()).unwrap());
+        assert!(api_keys.is_enabled());
+        assert_eq!(api_keys.get("secret"), Some(&api_key));
+        assert_eq!(api_keys.get("unknown"), None);
+
+        // Requests in flight count against the quota, but only successful proofs use it
+        let reservation_1 = api_keys.reserve_proof(&api_key).unwrap();
+        let reservation_2 = api_keys.reserve_proof(&api_key).unwrap();
+        assert!(api_keys.reserve_proof(&api_key).is_none());
+        drop(reservation_2);
+        reservation_1.use_quota();
+        api_keys.reserve_proof(&api_key).unwrap().use_quota();
+        assert!(api_keys.reserve_proof(&api_key).is_none());
+
+        // The quota usage is loaded again after a restart, once it is stored
+        api_keys.store().await;
+        let api_keys = Arc::new(ApiKeys::new(&[api_key.clone()], quotas_path.clone()).unwrap());
+        assert!(api_keys.reserve_proof(&api_key).is_none());
+        std::fs::remove_file(&quotas_path).unwrap();
+
+        // API keys must have distinct names
+        let other_key = ApiKeyConfig {
+            key: "other".to_string(),
+            ..api_key.clone()
+        };
+        assert!(ApiKeys::new(&[api_key, other_key], quotas_path).is_err());
+    }
+}
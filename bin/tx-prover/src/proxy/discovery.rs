@@ -0,0 +1,142 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    net::{SocketAddr, ToSocketAddrs},
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use pingora::lb::{discovery::ServiceDiscovery, Backend};
+use pingora_core::Result;
+
+use crate::commands::WorkerConfig;
+
+/// Service discovery which returns the workers registered in the proxy.
+///
+/// Unlike the static discovery of pingora, workers can be added and removed while the proxy is
+/// running. The workers of the load balancer are refreshed on its next update.
+#[derive(Clone, Default)]
+pub struct WorkerDiscovery {
+    workers: Arc<RwLock<BTreeSet<Backend>>>,
+}
+
+impl WorkerDiscovery {
+    /// Returns a new [WorkerDiscovery] with the provided workers.
+    pub fn new(workers: impl IntoIterator<Item = Backend>) -> Self {
+        Self {
+            workers: Arc::new(RwLock::new(workers.into_iter().collect())),
+        }
+    }
+
+    /// Returns `true` if the provided worker is registered.
+    pub fn contains(&self, worker: &Backend) -> bool {
+        self.workers
+            .read()
+            .expect("workers lock should not be poisoned")
+            .contains(worker)
+    }
+
+    /// Adds the provided worker.
+    ///
+    /// Returns `false` if the worker was already registered.
+    pub fn add(&self, worker: Backend) -> bool {
+        self.workers
+            .write()
+            .expect("workers lock should not be poisoned")
+            .insert(worker)
+    }
+
+    /// Removes the provided worker.
+    ///
+    /// Returns `false` if the worker was not registered.
+    pub fn remove(&self, worker: &Backend) -> bool {
+        self.workers
+            .write()
+            .expect("workers lock should not be poisoned")
+            .remove(worker)
+    }
+}
+
+#[async_trait]
+impl ServiceDiscovery for WorkerDiscovery {
+    async fn discover(&self) -> Result<(BTreeSet<Backend>, HashMap<u64, bool>)> {
+        let workers = self.workers.read().expect("workers lock should not be poisoned").clone();
+        Ok((workers, HashMap::new()))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the backend of the provided worker, resolving its host if needed.
+///
+/// The host is resolved with a blocking lookup, so this function must not be called from async
+/// code, which should use [lookup_worker_backend] instead.
+pub fn worker_backend(worker: &WorkerConfig) -> std::result::Result<Backend, String> {
+    let addresses = (worker.host.as_str(), worker.port)
+        .to_socket_addrs()
+        .map_err(|err| format!("Invalid worker address {}:{}: {err}", worker.host, worker.port))?;
+
+    first_backend(worker, addresses)
+}
+
+/// Returns the backend of the provided worker, resolving its host without blocking the runtime.
+pub async fn lookup_worker_backend(worker: &WorkerConfig) -> std::result::Result<Backend, String> {
+    let addresses = tokio::net::lookup_host((worker.host.as_str(), worker.port))
+        .await
+        .map_err(|err| format!("Invalid worker address {}:{}: {err}", worker.host, worker.port))?;
+
+    first_backend(worker, addresses)
+}
+
+/// Returns the backend of the first of the addresses the host of the provided worker resolved to.
+fn first_backend(
+    worker: &WorkerConfig,
+    mut addresses: impl Iterator<Item = SocketAddr>,
+) -> std::result::Result<Backend, String> {
+    let address = addresses
+        .next()
+        .ok_or(format!("Worker address {}:{} could not be resolved", worker.host, worker.port))?;
+
+    Backend::new(&address.to_string()).map_err(|err| err.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use pingora::{
+        lb::{Backend, Backends, LoadBalancer as PingoraLoadBalancer},
+        prelude::RoundRobin,
+    };
+
+    use super::WorkerDiscovery;
+
+    #[tokio::test]
+    async fn workers_are_added_and_removed_at_runtime() {
+        let worker_1 = Backend::new("127.0.0.1:50055").unwrap();
+        let worker_2 = Backend::new("127.0.0.1:50056").unwrap();
+
+        let discovery = WorkerDiscovery::new([worker_1.clone()]);
+        let workers: PingoraLoadBalancer<RoundRobin> =
+            PingoraLoadBalancer::from_backends(Backends::new(Box::new(discovery.clone())));
+        workers.update().await.unwrap();
+        assert_eq!(workers.select(b"", 256), Some(worker_1.clone()));
+
+        // An added worker receives requests after the update of the load balancer
+        assert!(discovery.add(worker_2.clone()));
+        assert!(!discovery.add(worker_2.clone()));
+        assert!(discovery.contains(&worker_2));
+        workers.update().await.unwrap();
+        assert_eq!(workers.backends().get_backend().len(), 2);
+
+        // A removed worker no longer receives requests
+        assert!(discovery.remove(&worker_1));
+        assert!(!discovery.remove(&worker_1));
+        assert!(!discovery.contains(&worker_1));
+        workers.update().await.unwrap();
+        for _ in 0..4 {
+            assert_eq!(workers.select(b"", 256), Some(worker_2.clone()));
+        }
+    }
+}
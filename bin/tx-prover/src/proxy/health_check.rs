@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use miden_tx_prover::generated::{api_client::ApiClient, HealthRequest};
+use pingora::{
+    lb::{health_check::HealthCheck, Backend},
+    prelude::*,
+};
+use pingora_core::Result;
+use tonic::transport::Endpoint;
+
+use crate::commands::ProxyConfig;
+
+/// Health check which calls the `Health` RPC of the workers.
+///
+/// A healthy worker is removed from the rotation after `unhealthy_threshold` consecutive failed
+/// checks, and an unhealthy worker is added back after `healthy_threshold` consecutive successful
+/// checks.
+pub struct WorkerHealthCheck {
+    timeout: Duration,
+    healthy_threshold: usize,
+    unhealthy_threshold: usize,
+}
+
+impl WorkerHealthCheck {
+    pub fn new(config: &ProxyConfig) -> Self {
+        Self {
+            timeout: Duration::from_secs(config.health_check_timeout_secs),
+            healthy_threshold: config.healthy_threshold,
+            unhealthy_threshold: config.unhealthy_threshold,
+        }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for WorkerHealthCheck {
+    /// Connects to the worker and calls its `Health` RPC, failing if the worker does not respond
+    /// successfully within the configured timeout.
+    async fn check(&self, target: &Backend) -> Result<()> {
+        let endpoint = Endpoint::from_shared(format!("http://{}", target.addr))
+            .map_err(|err| health_check_error(target, err))?
+            .connect_timeout(self.timeout)
+            .timeout(self.timeout);
+
+        let channel = endpoint.connect().await.map_err(|err| health_check_error(target, err))?;
+        ApiClient::new(channel)
+            .health(HealthRequest {})
+            .await
+            .map_err(|err| health_check_error(target, err))?;
+
+        Ok(())
+    }
+
+    /// Returns the number of consecutive checks with the provided result needed to change the
+    /// health of a worker.
+    fn health_threshold(&self, success: bool) -> usize {
+        if success {
+            self.healthy_threshold
+        } else {
+            self.unhealthy_threshold
+        }
+    }
+}
+
+/// Returns the error of a failed health check of the provided worker.
+fn health_check_error(target: &Backend, err: impl std::fmt::Display) -> Box<Error> {
+    Error::explain(
+        ErrorType::ConnectError,
+        format!("Health check of worker {} failed: {err}", target.addr),
+    )
+}
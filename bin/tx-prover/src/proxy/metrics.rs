@@ -0,0 +1,120 @@
+use once_cell::sync::Lazy;
+use pingora::lb::Backend;
+use prometheus::{
+    exponential_buckets, register_histogram_vec, register_int_counter, register_int_counter_vec,
+    register_int_gauge_vec, HistogramVec, IntCounter, IntCounterVec, IntGaugeVec,
+};
+
+// PROXY METRICS
+// ================================================================================================
+
+/// Number of requests received by the proxy.
+pub static REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!("tx_prover_proxy_requests_total", "Number of requests received")
+        .expect("metric should be registered once")
+});
+
+/// Number of requests rejected by the rate limiter of the proxy.
+pub static RATE_LIMITED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_rate_limited_requests_total",
+        "Number of requests rejected by the rate limiter"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of requests rejected because the queues of all workers were full.
+pub static REJECTED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_rejected_requests_total",
+        "Number of requests rejected because the queues of all workers were full"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of retries of requests whose connection to a worker failed.
+pub static RETRIES: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_retries_total",
+        "Number of retries of requests whose connection to a worker failed"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of requests rejected because they did not carry a known API key.
+pub static UNAUTHORIZED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_unauthorized_requests_total",
+        "Number of requests rejected because they did not carry a known API key"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of proof requests rejected because the daily proof quota of their API key was used up.
+pub static QUOTA_EXCEEDED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_quota_exceeded_requests_total",
+        "Number of proof requests rejected because the daily proof quota was used up"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of proof requests admitted for each API key.
+pub static CLIENT_PROOF_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "tx_prover_proxy_client_proof_requests_total",
+        "Number of proof requests admitted for each API key",
+        &["client"]
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of proof requests served from the proof cache of the proxy.
+pub static CACHED_PROOFS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "tx_prover_proxy_cached_proofs_total",
+        "Number of proof requests served from the proof cache"
+    )
+    .expect("metric should be registered once")
+});
+
+/// Number of requests which failed.
+pub static FAILED_REQUESTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!("tx_prover_proxy_failed_requests_total", "Number of failed requests")
+        .expect("metric should be registered once")
+});
+
+/// Number of requests in the queue of each worker, including the one being processed.
+pub static QUEUE_DEPTH: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "tx_prover_proxy_queue_depth",
+        "Number of requests in the queue of each worker",
+        &["worker"]
+    )
+    .expect("metric should be registered once")
+});
+
+/// Time taken by each worker to process the requests forwarded to it.
+pub static REQUEST_LATENCY: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "tx_prover_proxy_request_latency_seconds",
+        "Time taken by each worker to process a request",
+        &["worker"],
+        exponential_buckets(0.25, 2.0, 12).expect("buckets should be valid")
+    )
+    .expect("metric should be registered once")
+});
+
+// HELPERS
+// ================================================================================================
+
+/// Sets the number of requests in the queue of the provided worker.
+pub fn set_queue_depth(worker: &Backend, depth: usize) {
+    QUEUE_DEPTH.with_label_values(&[&worker.addr.to_string()]).set(depth as i64);
+}
+
+/// Stops exporting the queue depth of the provided worker, once its queue is removed.
+pub fn remove_queue_depth(worker: &Backend) {
+    // The queue depth is not exported if the worker never received a request
+    let _ = QUEUE_DEPTH.remove_label_values(&[&worker.addr.to_string()]);
+}
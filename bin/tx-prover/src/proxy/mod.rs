@@ -1,9 +1,15 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use miden_tx_prover::{API_KEY_HEADER, JOB_ID_HEADER, WITNESS_COMMITMENT_HEADER};
 use once_cell::sync::Lazy;
 use pingora::{
-    http::ResponseHeader,
+    http::{HMap, ResponseHeader},
     lb::Backend,
     prelude::{LoadBalancer as PingoraLoadBalancer, *},
     upstreams::peer::{Peer, ALPN},
@@ -12,29 +18,65 @@ use pingora_core::{upstreams::peer::HttpPeer, Result};
 use pingora_limits::rate::Rate;
 use pingora_proxy::{ProxyHttp, Session};
 use tokio::sync::RwLock;
-use tracing::error;
+use tracing::{error, info, warn};
+
+use self::{
+    api_keys::{ApiKeys, ProofReservation},
+    metrics::{
+        remove_queue_depth, set_queue_depth, CACHED_PROOFS, CLIENT_PROOF_REQUESTS, FAILED_REQUESTS,
+        QUOTA_EXCEEDED_REQUESTS, RATE_LIMITED_REQUESTS, REJECTED_REQUESTS, REQUESTS,
+        REQUEST_LATENCY, RETRIES, UNAUTHORIZED_REQUESTS,
+    },
+    selection::{update_recent_latency, LoadBalancingStrategy},
+};
+use crate::{
+    cache::{CacheLookup, PendingProof, ProofCache},
+    commands::ProxyConfig,
+};
 
-use crate::commands::ProxyConfig;
+pub mod admin;
+pub mod api_keys;
+pub mod discovery;
+pub mod health_check;
+pub mod metrics;
+pub mod selection;
 
-/// Load balancer that uses a round robin strategy
+/// Load balancer that selects workers with the configured [LoadBalancingStrategy]
+///
+/// Workers which fail their health checks or whose queue is full are skipped.
 pub struct LoadBalancer {
     lb: Arc<PingoraLoadBalancer<RoundRobin>>,
+    strategy: LoadBalancingStrategy,
     timeout_secs: Duration,
     connection_timeout_secs: Duration,
     max_queue_items: usize,
     max_retries_per_request: usize,
     max_req_per_sec: isize,
+    job_retention: Duration,
+    api_keys: Arc<ApiKeys>,
+    proof_cache: Arc<ProofCache<Bytes>>,
 }
 
 impl LoadBalancer {
-    pub fn new(workers: PingoraLoadBalancer<RoundRobin>, config: &ProxyConfig) -> Self {
+    pub fn new(
+        workers: Arc<PingoraLoadBalancer<RoundRobin>>,
+        api_keys: Arc<ApiKeys>,
+        config: &ProxyConfig,
+    ) -> Self {
         Self {
-            lb: Arc::new(workers),
+            lb: workers,
+            strategy: config.load_balancing_strategy,
             timeout_secs: Duration::from_secs(config.timeout_secs),
             connection_timeout_secs: Duration::from_secs(config.connection_timeout_secs),
             max_queue_items: config.max_queue_items,
             max_retries_per_request: config.max_retries_per_request,
             max_req_per_sec: config.max_req_per_sec,
+            job_retention: Duration::from_secs(config.job_retention_secs),
+            api_keys,
+            proof_cache: Arc::new(ProofCache::new(
+                config.proof_cache_size,
+                Duration::from_secs(config.proof_cache_ttl_secs),
+            )),
         }
     }
 
@@ -53,8 +95,44 @@ impl LoadBalancer {
         Ok(true)
     }
 
+    /// Create a 429 response for a client which used up its daily proof quota
+    pub async fn create_quota_exceeded_response(
+        session: &mut Session,
+        max_proofs_per_day: u64,
+    ) -> Result<bool> {
+        let mut header = ResponseHeader::build(429, None)?;
+        header.insert_header("X-Quota-Limit", max_proofs_per_day.to_string())?;
+        header.insert_header("X-Quota-Remaining", "0")?;
+        session.set_keepalive(None);
+        session.write_response_header(Box::new(header), true).await?;
+        Ok(true)
+    }
+
+    /// Create a 401 response for a request without a known API key
+    pub async fn create_unauthorized_response(session: &mut Session) -> Result<bool> {
+        let header = ResponseHeader::build(401, None)?;
+        session.set_keepalive(None);
+        session.write_response_header(Box::new(header), true).await?;
+        Ok(true)
+    }
+
+    /// Create a successful gRPC response with the provided response body of a cached proof
+    pub async fn create_cached_proof_response(session: &mut Session, body: Bytes) -> Result<bool> {
+        let mut header = ResponseHeader::build(200, None)?;
+        header.insert_header("content-type", "application/grpc")?;
+        session.write_response_header(Box::new(header), false).await?;
+        session.write_response_body(Some(body), false).await?;
+
+        let mut trailers = HMap::new();
+        trailers.insert("grpc-status", "0".parse().expect("gRPC status should be a valid header"));
+        session.write_response_trailers(trailers).await?;
+        Ok(true)
+    }
+
     /// Remove the request ID from the corresponding worker queue
-    pub async fn remove_request_from_queue(request_id: &str) {
+    ///
+    /// The queue of a worker which was removed from the proxy is dropped once it is empty.
+    pub async fn remove_request_from_queue(&self, request_id: &str) {
         let mut ctx_guard = QUEUES.write().await;
 
         // Get the worker by checking each queue
@@ -69,6 +147,12 @@ impl LoadBalancer {
             if !worker_queue.is_empty() {
                 worker_queue.remove(0);
             }
+            if worker_queue.is_empty() && !self.lb.backends().get_backend().contains(&worker) {
+                ctx_guard.remove(&worker);
+                remove_queue_depth(&worker);
+            } else {
+                set_queue_depth(&worker, worker_queue.len());
+            }
         }
     }
 
@@ -80,77 +164,392 @@ impl LoadBalancer {
             .to_str()
             .map_err(|_| Error::new(ErrorType::InternalError))
     }
+
+    /// Get the proving job ID from the session headers
+    pub fn get_job_id(session: &Session) -> Result<&str> {
+        session
+            .get_header(JOB_ID_HEADER)
+            .ok_or(Error::explain(ErrorType::HTTPStatus(400), "Job ID not found"))?
+            .to_str()
+            .map_err(|_| Error::explain(ErrorType::HTTPStatus(400), "Invalid job ID"))
+    }
+
+    /// Get the commitment to the proof requested by a gRPC request, if the request can be served
+    /// from the proof cache
+    ///
+    /// Only direct proof requests made over HTTP/2 which carry the commitment header are cached,
+    /// since the proxy does not read the request body. The commitment is verified by the worker.
+    pub fn get_witness_commitment(session: &Session) -> Option<String> {
+        let is_grpc = session
+            .get_header("content-type")
+            .is_some_and(|content_type| content_type == "application/grpc");
+        if session.req_header().uri.path() != PROVE_TRANSACTION_PATH
+            || !is_grpc
+            || !session.is_http2()
+        {
+            return None;
+        }
+
+        session
+            .get_header(WITNESS_COMMITMENT_HEADER)
+            .and_then(|commitment| commitment.to_str().ok())
+            .map(str::to_string)
+    }
+
+    /// Records the worker a proving job was sent to, and removes the expired jobs
+    pub async fn register_job(&self, job_id: &str, worker: Backend) {
+        let mut jobs = JOBS.write().await;
+        jobs.retain(|_, job| !job.is_expired(self.job_retention));
+        jobs.insert(
+            job_id.to_string(),
+            ProxyJob {
+                worker,
+                last_access: Instant::now(),
+                finished_at: None,
+                proof_reservation: None,
+            },
+        );
+    }
+
+    /// Keeps the proof reserved by a proving job submission with the job, once a worker accepted
+    /// the job
+    pub async fn keep_job_reservation(job_id: &str, proof_reservation: ProofReservation) {
+        if let Some(job) = JOBS.write().await.get_mut(job_id) {
+            job.proof_reservation = Some(proof_reservation);
+        }
+    }
+
+    /// Records that the result of a proving job was returned by its worker, i.e., that the job
+    /// finished, and takes the proof reserved by the job, if it was neither used nor released yet
+    pub async fn finish_job(job_id: &str) -> Option<ProofReservation> {
+        let mut jobs = JOBS.write().await;
+        let job = jobs.get_mut(job_id)?;
+        job.finished_at.get_or_insert_with(Instant::now);
+        job.proof_reservation.take()
+    }
+
+    /// Get the worker a proving job was sent to, and record the access to the job
+    pub async fn get_job_worker(&self, job_id: &str) -> Result<Backend> {
+        JOBS.write()
+            .await
+            .get_mut(job_id)
+            .filter(|job| !job.is_expired(self.job_retention))
+            .map(|job| {
+                job.last_access = Instant::now();
+                job.worker.clone()
+            })
+            .ok_or(Error::explain(ErrorType::HTTPStatus(404), "Job not found"))
+    }
+
+    /// Records that a worker failed to process a request, which updates its recent latency with
+    /// a penalty
+    ///
+    /// The penalty is the request timeout, or the time the worker took to fail if it is longer,
+    /// so that failing workers are selected last by the latency-based strategy.
+    pub async fn record_failure(&self, worker: &Backend, elapsed: Duration) {
+        Self::record_latency(worker, elapsed.max(self.timeout_secs)).await;
+    }
+
+    /// Records the time a worker took to process a request, which updates its recent latency
+    pub async fn record_latency(worker: &Backend, latency: Duration) {
+        let mut latencies = LATENCIES.write().await;
+        let recent_latency = update_recent_latency(latencies.get(worker).copied(), latency);
+        latencies.insert(worker.clone(), recent_latency);
+        REQUEST_LATENCY
+            .with_label_values(&[&worker.addr.to_string()])
+            .observe(latency.as_secs_f64());
+    }
+
+    /// Select a worker with the configured strategy, add the request to its queue and wait until
+    /// the request gets to the front of the queue
+    ///
+    /// Returns a 503 error if the queues of all the healthy workers are full.
+    async fn enqueue_request(&self, request_id: &str) -> Result<Backend> {
+        // Select the worker and enqueue the request ID in its queue
+        // We use a new scope to release the lock after the operation
+        let worker = {
+            let mut ctx_guard = QUEUES.write().await;
+            let latencies = LATENCIES.read().await;
+            let worker = self
+                .strategy
+                .select(&self.lb, &ctx_guard, &latencies, self.max_queue_items)
+                .ok_or_else(|| self.no_worker_available())?;
+
+            let worker_queue = ctx_guard.entry(worker.clone()).or_default();
+            worker_queue.push(request_id.to_string());
+            set_queue_depth(&worker, worker_queue.len());
+            worker
+        };
+
+        // Wait for the request to be at the front of the queue
+        loop {
+            // We use a new scope for each iteration to release the lock
+            {
+                let ctx_guard = QUEUES.read().await;
+                if let Some(worker_queue) = ctx_guard.get(&worker) {
+                    if worker_queue[0] == request_id {
+                        break;
+                    }
+                } else {
+                    return Err(Error::new_str("Worker not found"));
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        Ok(worker)
+    }
+
+    /// Returns the error of a request for which no worker could be selected
+    fn no_worker_available(&self) -> Box<Error> {
+        let backends = self.lb.backends();
+        if backends.get_backend().iter().any(|worker| backends.ready(worker)) {
+            REJECTED_REQUESTS.inc();
+            Error::explain(ErrorType::HTTPStatus(503), "All worker queues are full")
+        } else {
+            Error::new_str("Worker not found")
+        }
+    }
 }
 
+/// Path of the gRPC method which proves a transaction
+const PROVE_TRANSACTION_PATH: &str = "/api.Api/ProveTransaction";
+
+/// Path of the gRPC method which submits a proving job
+const SUBMIT_PROVING_JOB_PATH: &str = "/api.Api/SubmitProvingJob";
+
+/// Path of the gRPC method which retrieves the result of a proving job
+const GET_JOB_RESULT_PATH: &str = "/api.Api/GetJobResult";
+
+/// Paths of the gRPC methods which request a proof, and reserve a proof from the daily proof
+/// quotas
+///
+/// Proofs requested with `ProveTransaction` count towards the quota once the request succeeds,
+/// and proofs of proving jobs once the result of the job is retrieved.
+const PROOF_REQUEST_PATHS: [&str; 2] = [PROVE_TRANSACTION_PATH, SUBMIT_PROVING_JOB_PATH];
+
+/// Paths of the gRPC methods which query a proving job
+const JOB_QUERY_PATHS: [&str; 2] = ["/api.Api/GetJobStatus", GET_JOB_RESULT_PATH];
+
+/// gRPC status codes returned by `GetJobResult` when the job will never produce a proof, i.e.,
+/// `NOT_FOUND` and `ABORTED`
+const FAILED_JOB_GRPC_STATUSES: [&str; 2] = ["5", "10"];
+
 /// Rate limiter
 static RATE_LIMITER: Lazy<Rate> = Lazy::new(|| Rate::new(Duration::from_secs(1)));
 
 /// Shared state. It is a map of workers to a vector of request IDs
+///
+/// Workers are added to the map when they are added at runtime or receive a request, and removed
+/// once they are removed from the proxy and their queue is empty.
 static QUEUES: Lazy<RwLock<HashMap<Backend, Vec<String>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+/// Shared state. It is a map of workers to their recent latency
+static LATENCIES: Lazy<RwLock<HashMap<Backend, Duration>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Shared state. It is a map of proving job IDs to the workers the jobs were sent to
+static JOBS: Lazy<RwLock<HashMap<String, ProxyJob>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// A proving job submitted through the proxy
+///
+/// The proof reserved by the submission is kept with the job once a worker accepts it, and counts
+/// towards the daily quota of the API key when the result of the job is retrieved. It is released
+/// if the job fails or expires before.
+///
+/// The proxy does not know when the worker finishes the job. Until the result of the job is
+/// returned, the job is kept for the job retention time after it was last submitted or queried,
+/// and afterwards for the job retention time after its result was first returned, as the worker
+/// does after the job finished.
+pub struct ProxyJob {
+    worker: Backend,
+    last_access: Instant,
+    finished_at: Option<Instant>,
+    proof_reservation: Option<ProofReservation>,
+}
+
+impl ProxyJob {
+    /// Returns true if the job expired, given the job retention time
+    fn is_expired(&self, retention: Duration) -> bool {
+        self.finished_at.unwrap_or(self.last_access).elapsed() >= retention
+    }
+}
+
 /// Custom context for the request/response lifecycle
-/// We use this context to keep track of the number of tries for a request.
+/// We use this context to keep track of the number of tries for a request, of whether the
+/// request is in a worker queue, of the worker the request was forwarded to and when, of the
+/// name of the API key of the client, if any, of the proof reserved from its daily quota, of the
+/// response of a proof request which is to be cached, and of the outcome of the request.
 pub struct TriesCounter {
     tries: usize,
+    queued: bool,
+    forwarded: Option<(Backend, Instant)>,
+    client: Option<String>,
+    proof_reservation: Option<ProofReservation>,
+    pending_proof: Option<PendingProof<Bytes>>,
+    response_body: Vec<u8>,
+    proof_succeeded: bool,
+    job_failed: bool,
 }
 
 /// Implements load-balancing of incoming requests across a pool of workers.
 ///
 /// At the backend-level, a request lifecycle works as follows:
 /// - When a new requests arrives, [LoadBalancer::request_filter()] method is called. In this method
-///   we apply IP-based rate-limiting to the request and assign a unique ID to it.
+///   we authenticate the client with its API key, if API keys are configured, apply rate-limiting
+///   to the request, reserve a proof from the daily quota of the API key and assign a unique ID to
+///   it. Proving job submissions are also assigned a unique job ID. Proof requests whose proof is
+///   cached are answered here, and identical proof requests wait for the one in flight.
 /// - Next, the [Self::upstream_peer()] method is called. We use it to figure out which worker will
-///   process the request. Inside `upstream_peer()`, we pick a worker in a round-robin fashion and
-///   add the request to the queue of requests for that worker. Once the request gets to the front
-///   of the queue, we forward it to the worker. This step is also in charge of assinging the
-///   timeouts and enabling HTTP/2. Finally, we establish a connection with the worker.
+///   process the request. Inside `upstream_peer()`, we pick a worker with the configured load
+///   balancing strategy, among the healthy workers whose queue is not full, and add the request to
+///   the queue of requests for that worker. If the queues of all workers are full, we return a 503
+///   error. Once the request gets to the front of the queue, we forward it to the worker. Proving
+///   job submissions are queued in the same way and the worker which accepts a job is recorded,
+///   while status and result queries skip the queue and are sent to the worker the job was
+///   submitted to. This step is also in charge of assinging the timeouts and enabling HTTP/2.
+///   Finally, we establish a connection with the worker.
 /// - Before sending the request to the upstream server and if the connection succeed, the
 ///   [Self::upstream_request_filter()] method is called. In this method, we ensure that the correct
 ///   headers are forwarded for gRPC requests.
 /// - If the connection fails, the [Self::fail_to_connect()] method is called. In this method, we
 ///   retry the request [self.max_retries_per_request] times.
+/// - While the response of the worker is received, the [Self::upstream_response_filter()],
+///   [Self::upstream_response_body_filter()] and [Self::upstream_response_trailer_filter()] methods
+///   record the outcome of the request and the response of proof requests which are to be cached.
 /// - Once the worker processes the request (either successfully or with a failure),
-///   [Self::logging()] method is called. In this method, we remove the request from the worker's
-///   queue, allowing the worker to process the next request.
+///   [Self::logging()] method is called. In this method, we cache the response of a successful
+///   proof request, count it towards the daily quota of the API key, or keep the reservation of
+///   an accepted proving job until its result is retrieved, remove the request from the
+///   worker's queue, if it was queued, allowing the worker to process the next request, and update
+///   the recent latency of the worker.
 #[async_trait]
 impl ProxyHttp for LoadBalancer {
     type CTX = TriesCounter;
     fn new_ctx(&self) -> Self::CTX {
-        TriesCounter { tries: 0 }
+        TriesCounter {
+            tries: 0,
+            queued: false,
+            forwarded: None,
+            client: None,
+            proof_reservation: None,
+            pending_proof: None,
+            response_body: Vec::new(),
+            proof_succeeded: false,
+            job_failed: false,
+        }
     }
 
     /// Decide whether to filter the request or not.
     ///
-    /// Here we apply IP-based rate-limiting to the request. We assign a unique ID to the request
-    /// and check if the current window requests exceed the maximum allowed requests per second.
+    /// If API keys are configured, we authenticate the client with the API key in the request
+    /// headers and return a 401 response if the key is missing or unknown. Then, we apply
+    /// rate-limiting to the request, per API key or per IP address for clients without an API
+    /// key. We assign a unique ID to the request and check if the current window requests exceed
+    /// the maximum allowed requests per second. Then, proof requests made with an API key reserve
+    /// a proof from its daily quota, which is only used once the proof succeeds or, for proving
+    /// jobs, once the result of the job is retrieved. Finally, we serve
+    /// the proof of a proof request from the proof cache, waiting for an identical request in
+    /// flight if there is one.
     ///
-    /// If the request is rate-limited, we return a 429 response. Otherwise, we return false.
-    async fn request_filter(&self, session: &mut Session, _ctx: &mut Self::CTX) -> Result<bool>
+    /// If the request is rate-limited or the quota is used up, we return a 429 response. If the
+    /// proof is cached, we return it. Otherwise, we return false.
+    async fn request_filter(&self, session: &mut Session, ctx: &mut Self::CTX) -> Result<bool>
     where
         Self::CTX: Send + Sync,
     {
-        let client_addr = session.client_addr();
-        let user_id = client_addr.map(|addr| addr.to_string());
+        REQUESTS.inc();
+
+        // Authenticate the client with its API key, if API keys are configured
+        let api_key = if self.api_keys.is_enabled() {
+            let api_key = session
+                .get_header(API_KEY_HEADER)
+                .and_then(|key| key.to_str().ok())
+                .and_then(|key| self.api_keys.get(key));
+            match api_key {
+                Some(api_key) => {
+                    ctx.client = Some(api_key.name.clone());
+                    Some(api_key)
+                },
+                None => {
+                    UNAUTHORIZED_REQUESTS.inc();
+                    warn!(client_addr = ?session.client_addr(), "Missing or unknown API key");
+                    return Self::create_unauthorized_response(session).await;
+                },
+            }
+        } else {
+            None
+        };
+
+        // Rate limit clients by API key, or by IP address if they do not use an API key
+        let (user_id, max_req_per_sec) = match api_key {
+            Some(api_key) => (Some(format!("key:{}", api_key.name)), api_key.max_req_per_sec),
+            None => (session.client_addr().map(|addr| addr.to_string()), self.max_req_per_sec),
+        };
 
         // Request ID is a random number
         let request_id = rand::random::<u64>().to_string();
         session.req_header_mut().insert_header("X-Request-ID", request_id)?;
 
+        // Job ID is a random number, which replaces any job ID set by the client
+        if session.req_header().uri.path() == SUBMIT_PROVING_JOB_PATH {
+            let job_id = rand::random::<u64>().to_string();
+            session.req_header_mut().insert_header(JOB_ID_HEADER, job_id)?;
+        }
+
         // Retrieve the current window requests
         let curr_window_requests = RATE_LIMITER.observe(&user_id, 1);
 
-        if curr_window_requests > self.max_req_per_sec {
-            return Self::create_too_many_requests_response(session, self.max_req_per_sec).await;
+        if curr_window_requests > max_req_per_sec {
+            RATE_LIMITED_REQUESTS.inc();
+            warn!(client = ctx.client.as_deref(), "Request rate limited");
+            return Self::create_too_many_requests_response(session, max_req_per_sec).await;
         };
+
+        // Reserve a proof from the daily quota of the API key for proof requests
+        if let Some(api_key) = api_key {
+            if PROOF_REQUEST_PATHS.contains(&session.req_header().uri.path()) {
+                let Some(reservation) = self.api_keys.reserve_proof(api_key) else {
+                    QUOTA_EXCEEDED_REQUESTS.inc();
+                    warn!(client = ctx.client.as_deref(), "Daily proof quota exceeded");
+                    return Self::create_quota_exceeded_response(
+                        session,
+                        api_key.max_proofs_per_day,
+                    )
+                    .await;
+                };
+                ctx.proof_reservation = Some(reservation);
+                CLIENT_PROOF_REQUESTS.with_label_values(&[&api_key.name]).inc();
+            }
+        }
+
+        // Serve the proof from the cache, or wait for an identical request in flight
+        if let Some(commitment) = Self::get_witness_commitment(session) {
+            match self.proof_cache.lookup(&commitment).await {
+                CacheLookup::Hit(body) => {
+                    info!(client = ctx.client.as_deref(), "Serving cached proof");
+                    CACHED_PROOFS.inc();
+                    // Cached proofs do not count towards the daily quota
+                    ctx.proof_reservation = None;
+                    return Self::create_cached_proof_response(session, body).await;
+                },
+                CacheLookup::Miss(pending_proof) => ctx.pending_proof = Some(pending_proof),
+            }
+        }
+
         Ok(false)
     }
 
     /// Returns [HttpPeer] corresponding to the worker that will handle the current request.
     ///
-    /// Here we select the next worker from the pool in a round-robin fashion. We then add the
-    /// request to the worker's queue and wait until it gets to the front of it. Then, we construct
-    /// and return the [HttpPeer]. The peer is configured with timeouts, and HTTP/2.
+    /// Here we select the next worker from the pool with the configured load balancing strategy.
+    /// We then add the request to the worker's queue and wait until it gets to the front of it.
+    /// If the queues of all workers are full, a 503 error is returned instead. Proving job
+    /// submissions are queued in the same way and recorded in the job table, while status and
+    /// result queries skip the queue and are sent to the worker which received the job.
+    /// Then, we construct and return the [HttpPeer]. The peer is configured with timeouts, and
+    /// HTTP/2.
     ///
     /// Note that the request is not removed from the queue here. It will be returned later in
     /// [Self::logging()] once the worker processes the it.
@@ -159,45 +558,30 @@ impl ProxyHttp for LoadBalancer {
         session: &mut Session,
         ctx: &mut Self::CTX,
     ) -> Result<Box<HttpPeer>> {
-        if ctx.tries > 0 {
-            Self::remove_request_from_queue(Self::get_request_id(session)?).await;
+        if ctx.queued {
+            self.remove_request_from_queue(Self::get_request_id(session)?).await;
+            ctx.queued = false;
         }
 
-        // Select the worker in a round-robin fashion
-        let worker = self.lb.select(b"", 256).ok_or(Error::new_str("Worker not found"))?;
-
-        // Read request ID from headers
-        let request_id = Self::get_request_id(session)?;
-
-        // Enqueue the request ID in the worker queue
-        // We use a new scope to release the lock after the operation
-        {
-            let mut ctx_guard = QUEUES.write().await;
-            let worker_queue = ctx_guard.entry(worker.clone()).or_insert_with(Vec::new);
-
-            // Limit queue length
-            if worker_queue.len() >= self.max_queue_items {
-                return Err(Error::new_str("Too many requests in the queue"));
-            }
-
-            worker_queue.push(request_id.to_string());
+        // The request is retried, so the worker it was forwarded to failed to process it
+        if let Some((worker, forwarded_at)) = ctx.forwarded.take() {
+            self.record_failure(&worker, forwarded_at.elapsed()).await;
         }
 
-        // Wait for the request to be at the front of the queue
-        loop {
-            // We use a new scope for each iteration to release the lock
-            {
-                let ctx_guard = QUEUES.read().await;
-                if let Some(worker_queue) = ctx_guard.get(&worker) {
-                    if worker_queue[0] == request_id {
-                        break;
-                    }
-                } else {
-                    return Err(Error::new_str("Worker not found"));
-                }
+        let path = session.req_header().uri.path();
+        let worker = if JOB_QUERY_PATHS.contains(&path) {
+            self.get_job_worker(Self::get_job_id(session)?).await?
+        } else {
+            let worker = self.enqueue_request(Self::get_request_id(session)?).await?;
+            ctx.queued = true;
+            if path == SUBMIT_PROVING_JOB_PATH {
+                // Submissions return before the job is proven, so their latency is not recorded
+                self.register_job(Self::get_job_id(session)?, worker.clone()).await;
+            } else {
+                ctx.forwarded = Some((worker.clone(), Instant::now()));
             }
-            tokio::time::sleep(Duration::from_millis(100)).await;
-        }
+            worker
+        };
 
         // Set SNI
         let mut http_peer = HttpPeer::new(worker, false, "".to_string());
@@ -220,7 +604,8 @@ impl ProxyHttp for LoadBalancer {
 
     /// Applies the necessary filters to the request before sending it to the upstream server.
     ///
-    /// Here we ensure that the correct headers are forwarded for gRPC requests.
+    /// Here we ensure that the correct headers are forwarded for gRPC requests, and that the API
+    /// key of the client is not forwarded to the worker.
     ///
     /// This method is called right after [Self::upstream_peer()] returns a [HttpPeer] and a
     /// connection is established with the worker.
@@ -233,6 +618,8 @@ impl ProxyHttp for LoadBalancer {
     where
         Self::CTX: Send + Sync,
     {
+        upstream_request.remove_header(API_KEY_HEADER);
+
         // Check if it's a gRPC request
         if let Some(content_type) = upstream_request.headers.get("content-type") {
             if content_type == "application/grpc" {
@@ -244,6 +631,50 @@ impl ProxyHttp for LoadBalancer {
         Ok(())
     }
 
+    /// Records the response body of a proof request which is to be cached.
+    fn upstream_response_body_filter(
+        &self,
+        _session: &mut Session,
+        body: &mut Option<Bytes>,
+        _end_of_stream: bool,
+        ctx: &mut Self::CTX,
+    ) {
+        if let (Some(_), Some(body)) = (&ctx.pending_proof, body) {
+            ctx.response_body.extend_from_slice(body);
+        }
+    }
+
+    /// Records whether a proving job failed, which gRPC reports in the response headers of
+    /// responses without a body.
+    fn upstream_response_filter(
+        &self,
+        _session: &mut Session,
+        upstream_response: &mut ResponseHeader,
+        ctx: &mut Self::CTX,
+    ) {
+        ctx.job_failed = upstream_response
+            .headers
+            .get("grpc-status")
+            .is_some_and(|status| FAILED_JOB_GRPC_STATUSES.iter().any(|failed| status == failed));
+    }
+
+    /// Records whether a request succeeded, which gRPC reports in the response trailers.
+    ///
+    /// This is used to cache successful proofs, to count them towards the daily quotas and to
+    /// update the recent latency of the worker.
+    fn upstream_response_trailer_filter(
+        &self,
+        _session: &mut Session,
+        upstream_trailers: &mut HMap,
+        ctx: &mut Self::CTX,
+    ) -> Result<()> {
+        let status = upstream_trailers.get("grpc-status");
+        ctx.proof_succeeded = status.is_some_and(|status| status == "0");
+        ctx.job_failed |= status
+            .is_some_and(|status| FAILED_JOB_GRPC_STATUSES.iter().any(|failed| status == failed));
+        Ok(())
+    }
+
     /// Retry the request if the connection fails.
     fn fail_to_connect(
         &self,
@@ -256,21 +687,73 @@ impl ProxyHttp for LoadBalancer {
             return e;
         }
         ctx.tries += 1;
+        RETRIES.inc();
         e.set_retry(true);
         e
     }
 
-    /// Logs the request lifecycle in case that an error happened and removes the request from the
-    /// worker queue.
+    /// Logs the request lifecycle in case that an error happened, caches the response of a
+    /// successful proof request and counts it towards the daily quota of the API key, updates the
+    /// recent latency of the worker and removes the request from the worker queue.
+    ///
+    /// Proofs of proving jobs count towards the quota when the result of the job is retrieved, and
+    /// are released when the result query reports that the job failed.
     ///
     /// This method is the last one in the request lifecycle, no matter if the request was
     /// processed or not.
-    async fn logging(&self, session: &mut Session, e: Option<&Error>, _ctx: &mut Self::CTX)
+    async fn logging(&self, session: &mut Session, e: Option<&Error>, ctx: &mut Self::CTX)
     where
         Self::CTX: Send + Sync,
     {
         if let Some(e) = e {
-            error!("Error: {:?}", e);
+            error!(client = ctx.client.as_deref(), "Error: {:?}", e);
+            FAILED_REQUESTS.inc();
+        }
+
+        // Cache the proof, or let one of the identical requests waiting for it prove the
+        // transaction
+        if let Some(pending_proof) = ctx.pending_proof.take() {
+            if e.is_none() && ctx.proof_succeeded {
+                pending_proof.complete(Bytes::from(std::mem::take(&mut ctx.response_body)));
+            }
+        }
+
+        // Count successful proofs towards the daily quota of the API key, and release the
+        // reservation of the other requests. The reservation of an accepted proving job is kept
+        // with the job until its result is retrieved.
+        let path = session.req_header().uri.path();
+        let succeeded = e.is_none() && ctx.proof_succeeded;
+        if let Some(proof_reservation) = ctx.proof_reservation.take() {
+            if path == SUBMIT_PROVING_JOB_PATH {
+                if let (true, Ok(job_id)) = (succeeded, Self::get_job_id(session)) {
+                    Self::keep_job_reservation(job_id, proof_reservation).await;
+                }
+            } else if succeeded {
+                proof_reservation.use_quota();
+            }
+        }
+        if path == GET_JOB_RESULT_PATH && (succeeded || (e.is_none() && ctx.job_failed)) {
+            if let Ok(job_id) = Self::get_job_id(session) {
+                if let Some(proof_reservation) = Self::finish_job(job_id).await {
+                    if succeeded {
+                        proof_reservation.use_quota();
+                    }
+                }
+            }
+        }
+
+        if !ctx.queued {
+            return;
+        }
+
+        // Update the recent latency of the worker which processed the request, with a penalty if
+        // the request failed
+        if let Some((worker, forwarded_at)) = &ctx.forwarded {
+            if e.is_none() && ctx.proof_succeeded {
+                Self::record_latency(worker, forwarded_at.elapsed()).await;
+            } else {
+                self.record_failure(worker, forwarded_at.elapsed()).await;
+            }
         }
 
         // Get the request ID from the session
@@ -279,6 +762,6 @@ impl ProxyHttp for LoadBalancer {
         // Remove the completed request from the worker queue
         // Maybe we can replace this with a read lock and using write only in the moment of the
         // deletion.
-        Self::remove_request_from_queue(request_id).await;
+        self.remove_request_from_queue(request_id).await;
     }
 }
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, time::Duration};
+
+use pingora::{
+    lb::{Backend, LoadBalancer as PingoraLoadBalancer},
+    prelude::RoundRobin,
+};
+use serde::{Deserialize, Serialize};
+
+/// Weight of the latest latency of a worker in its recent latency.
+const LATENCY_SMOOTHING_FACTOR: f64 = 0.3;
+
+// LOAD BALANCING STRATEGY
+// ================================================================================================
+
+/// Strategy used by the proxy to select the worker which receives a request.
+///
+/// Whatever the strategy, only healthy workers whose queue is not full are selected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LoadBalancingStrategy {
+    /// Selects the workers in turn.
+    #[default]
+    RoundRobin,
+    /// Selects the worker with the fewest requests in its queue.
+    LeastQueueDepth,
+    /// Selects the worker which processed its recent requests the fastest. Failed requests count
+    /// as taking at least the request timeout, so that failing workers are selected last. Workers
+    /// which have not processed any request yet are selected first.
+    LeastRecentLatency,
+}
+
+impl LoadBalancingStrategy {
+    /// Returns the worker which should receive the next request, or `None` if no healthy worker
+    /// has room in its queue.
+    ///
+    /// - `queues` maps the workers to the IDs of the requests in their queue.
+    /// - `latencies` maps the workers to their recent latency.
+    pub fn select(
+        &self,
+        workers: &PingoraLoadBalancer<RoundRobin>,
+        queues: &HashMap<Backend, Vec<String>>,
+        latencies: &HashMap<Backend, Duration>,
+        max_queue_items: usize,
+    ) -> Option<Backend> {
+        let queue_depth = |worker: &Backend| queues.get(worker).map_or(0, Vec::len);
+        let has_room = |worker: &Backend| queue_depth(worker) < max_queue_items;
+
+        let available_workers = || {
+            let backends = workers.backends();
+            backends
+                .get_backend()
+                .iter()
+                .filter(|worker| backends.ready(worker) && has_room(worker))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            Self::RoundRobin => {
+                workers.select_with(b"", 256, |worker, healthy| healthy && has_room(worker))
+            },
+            Self::LeastQueueDepth => available_workers().into_iter().min_by_key(queue_depth),
+            Self::LeastRecentLatency => available_workers()
+                .into_iter()
+                .min_by_key(|worker| latencies.get(worker).copied().unwrap_or_default()),
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the recent latency of a worker after it processed a request in `latency`.
+///
+/// The recent latency is an exponential moving average of the latencies of the worker, so that
+/// older requests have a decreasing weight in it.
+pub fn update_recent_latency(recent_latency: Option<Duration>, latency: Duration) -> Duration {
+    match recent_latency {
+        Some(recent_latency) => {
+            recent_latency.mul_f64(1.0 - LATENCY_SMOOTHING_FACTOR)
+                + latency.mul_f64(LATENCY_SMOOTHING_FACTOR)
+        },
+        None => latency,
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use pingora::{
+        lb::{Backend, LoadBalancer as PingoraLoadBalancer},
+        prelude::RoundRobin,
+    };
+
+    use super::{update_recent_latency, LoadBalancingStrategy};
+    use crate::commands::ProxyConfig;
+
+    #[tokio::test]
+    async fn load_balancing_strategies_select_workers() {
+        let worker_1 = Backend::new("127.0.0.1:50057").unwrap();
+        let worker_2 = Backend::new("127.0.0.1:50058").unwrap();
+        let workers: PingoraLoadBalancer<RoundRobin> =
+            PingoraLoadBalancer::try_from_iter(["127.0.0.1:50057", "127.0.0.1:50058"]).unwrap();
+
+        let queues = HashMap::from([
+            (worker_1.clone(), vec!["1".to_string(), "2".to_string()]),
+            (worker_2.clone(), vec!["3".to_string()]),
+        ]);
+        let mut latencies = HashMap::from([
+            (worker_1.clone(), Duration::from_secs(1)),
+            (worker_2.clone(), Duration::from_secs(5)),
+        ]);
+
+        // The worker with the fewest queued requests is selected
+        let strategy = LoadBalancingStrategy::LeastQueueDepth;
+        assert_eq!(strategy.select(&workers, &queues, &latencies, 10), Some(worker_2.clone()));
+
+        // The worker with the lowest recent latency is selected, if its queue is not full
+        let strategy = LoadBalancingStrategy::LeastRecentLatency;
+        assert_eq!(strategy.select(&workers, &queues, &latencies, 10), Some(worker_1.clone()));
+        assert_eq!(strategy.select(&workers, &queues, &latencies, 2), Some(worker_2.clone()));
+
+        // No worker is selected when all queues are full
+        let strategy = LoadBalancingStrategy::RoundRobin;
+        assert_eq!(strategy.select(&workers, &queues, &latencies, 1), None);
+
+        // Older latencies have a decreasing weight in the recent latency
+        let recent_latency = update_recent_latency(Some(Duration::from_secs(10)), Duration::ZERO);
+        assert_eq!(recent_latency, Duration::from_secs(7));
+
+        // A worker whose request failed is penalized with the request timeout, and is no longer
+        // selected before the slower worker
+        let timeout = Duration::from_secs(ProxyConfig::default().timeout_secs);
+        let recent_latency = update_recent_latency(latencies.get(&worker_1).copied(), timeout);
+        latencies.insert(worker_1.clone(), recent_latency);
+        let strategy = LoadBalancingStrategy::LeastRecentLatency;
+        assert_eq!(strategy.select(&workers, &queues, &latencies, 10), Some(worker_2.clone()));
+
+        // Strategies are configured in kebab case
+        let config = toml::to_string(&ProxyConfig {
+            load_balancing_strategy: LoadBalancingStrategy::LeastRecentLatency,
+            ..ProxyConfig::default()
+        })
+        .unwrap();
+        assert!(config.contains("load_balancing_strategy = \"least-recent-latency\""));
+    }
+}
@@ -20,6 +20,18 @@ pub(crate) fn load_config_from_file() -> Result<ProxyConfig, String> {
         .map_err(|err| format!("Failed to load {} config file: {err}", config_path.display()))
 }
 
+/// Writes the provided config to the config file in the current directory, replacing its content
+pub(crate) fn save_config_to_file(config: &ProxyConfig) -> Result<(), String> {
+    let mut current_dir = std::env::current_dir().map_err(|err| err.to_string())?;
+    current_dir.push(PROVER_SERVICE_CONFIG_FILE_NAME);
+
+    let config_as_toml_string =
+        toml::to_string_pretty(config).map_err(|err| format!("Error formatting config: {err}"))?;
+
+    std::fs::write(&current_dir, config_as_toml_string)
+        .map_err(|err| format!("Failed to write {} config file: {err}", current_dir.display()))
+}
+
 pub(crate) fn setup_tracing() {
     // Set a default log level if `RUST_LOG` is not set
     if std::env::var("RUST_LOG").is_err() {
@@ -12,6 +12,8 @@ use miden_objects::{
 pub enum TransactionKernelError {
     AccountDeltaError(AccountDeltaError),
     FailedToAddAssetToNote(NoteError),
+    FailedToLoadAccountData(String),
+    InvalidAccountEventId(u64),
     InvalidNoteInputs {
         expected: Digest,
         got: Digest,
@@ -46,6 +48,12 @@ impl fmt::Display for TransactionKernelError {
             TransactionKernelError::FailedToAddAssetToNote(err) => {
                 write!(f, "Failed to add asset to note: {err}")
             },
+            TransactionKernelError::FailedToLoadAccountData(err) => {
+                write!(f, "Failed to load account data on demand: {err}")
+            },
+            TransactionKernelError::InvalidAccountEventId(event_id) => {
+                write!(f, "Account event ID {event_id} is not within the range reserved for account events")
+            },
             TransactionKernelError::InvalidNoteInputs { expected, got, data } => {
                 write!(
                     f,
@@ -8,6 +8,12 @@ use super::{TransactionEventParsingError, TransactionTraceParsingError};
 /// Value of the top 16 bits of a transaction kernel event ID.
 pub const EVENT_ID_PREFIX: u32 = 2;
 
+/// Value of the top 16 bits of a user-defined account event ID.
+///
+/// Account events are emitted by the account code via the `miden::tx::emit_event` procedure. The
+/// lower 16 bits of the event ID can be freely chosen by the account components.
+pub const ACCOUNT_EVENT_ID_PREFIX: u32 = 3;
+
 // TRANSACTION EVENT
 // ================================================================================================
 
@@ -34,6 +40,8 @@ const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
 const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
 const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
 
+const ACCOUNT_EMIT_EVENT: u32 = 0x2_000f; // 131087
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -66,6 +74,8 @@ pub enum TransactionEvent {
 
     NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
     NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+
+    AccountEmitEvent = ACCOUNT_EMIT_EVENT,
 }
 
 impl fmt::Display for TransactionEvent {
@@ -112,6 +122,8 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
             NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
 
+            ACCOUNT_EMIT_EVENT => Ok(TransactionEvent::AccountEmitEvent),
+
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
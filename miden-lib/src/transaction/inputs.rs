@@ -1,8 +1,10 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    accounts::{Account, StorageSlot},
-    transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
+    accounts::{PartialAccount, StorageSlot},
+    transaction::{
+        ChainMmr, InputAccount, InputNote, TransactionArgs, TransactionInputs, TransactionScript,
+    },
     vm::AdviceInputs,
     Digest, Felt, FieldElement, Word, EMPTY_WORD, WORD_SIZE, ZERO,
 };
@@ -94,8 +96,8 @@ fn build_advice_stack(
     // Note: keep in sync with the process_account_data kernel procedure
     let account = tx_inputs.account();
     inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
-    inputs.extend_stack(account.vault().commitment());
-    inputs.extend_stack(account.storage().commitment());
+    inputs.extend_stack(account.vault_root());
+    inputs.extend_stack(account.storage_commitment());
     inputs.extend_stack(account.code().commitment());
 
     // push the number of input notes onto the stack
@@ -149,11 +151,21 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
 /// - The node |-> (key, value), for all leaf nodes of the asset vault SMT.
 /// - [account_id, 0, 0, 0] |-> account_seed, when account seed is provided.
 /// - If present, the Merkle leaves associated with the account storage maps.
+///
+/// For partial accounts, only the Merkle nodes and leaves covered by the storage map and vault
+/// proofs of the account are inserted.
 fn add_account_to_advice_inputs(
-    account: &Account,
+    account: &InputAccount,
     account_seed: Option<Word>,
     inputs: &mut AdviceInputs,
 ) {
+    let account = match account {
+        InputAccount::Full(account) => account,
+        InputAccount::Partial(account) => {
+            return add_partial_account_to_advice_inputs(account, account_seed, inputs)
+        },
+    };
+
     // --- account storage ----------------------------------------------------
     let storage = account.storage();
 
@@ -195,6 +207,46 @@ fn add_account_to_advice_inputs(
     }
 }
 
+/// Inserts the data of a partial account into the provided advice inputs.
+///
+/// This is the same as [add_account_to_advice_inputs()], except that only the Merkle paths and
+/// leaves of the storage map entries and vault assets opened by the account's proofs are inserted.
+fn add_partial_account_to_advice_inputs(
+    account: &PartialAccount,
+    account_seed: Option<Word>,
+    inputs: &mut AdviceInputs,
+) {
+    // --- account storage and vault ------------------------------------------
+    for proof in account.storage_map_proofs().iter().chain(account.vault_proofs()) {
+        let leaf = proof.leaf();
+        inputs.extend_merkle_store(
+            proof
+                .path()
+                .inner_nodes(leaf.index().value(), leaf.hash())
+                .expect("leaf index must be valid for the proof path"),
+        );
+        inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+    }
+
+    // extend advice map with storage commitment |-> length, storage slots and types vector
+    let storage_header = account.storage_header();
+    inputs.extend_map([(storage_header.commitment(), storage_header.as_elements())]);
+
+    // --- account code -------------------------------------------------------
+    let code = account.code();
+
+    // extend the advice map with the account code data
+    inputs.extend_map([(code.commitment(), code.as_elements())]);
+
+    // --- account seed -------------------------------------------------------
+    if let Some(account_seed) = account_seed {
+        inputs.extend_map(vec![(
+            [account.id().into(), ZERO, ZERO, ZERO].into(),
+            account_seed.to_vec(),
+        )]);
+    }
+}
+
 // INPUT NOTE INJECTOR
 // ------------------------------------------------------------------------------------------------
 
@@ -6,7 +6,8 @@ use miden_objects::{
     accounts::AccountId,
     assembly::{Assembler, DefaultSourceManager, KernelLibrary},
     transaction::{
-        OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
+        AccountEvent, OutputNote, OutputNotes, TransactionArgs, TransactionInputs,
+        TransactionOutputs,
     },
     utils::{group_slice_elements, serde::Deserializable},
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
@@ -20,7 +21,7 @@ use super::MidenLib;
 pub mod memory;
 
 mod events;
-pub use events::{TransactionEvent, TransactionTrace};
+pub use events::{TransactionEvent, TransactionTrace, ACCOUNT_EVENT_ID_PREFIX};
 
 mod inputs;
 
@@ -260,10 +261,14 @@ impl TransactionKernel {
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
+    ///
+    /// The account events emitted during transaction execution are not committed to by the output
+    /// stack and are included into the returned [TransactionOutputs] as provided.
     pub fn from_transaction_parts(
         stack: &StackOutputs,
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
+        account_events: Vec<AccountEvent>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
         let (final_acct_hash, output_notes_hash, expiration_block_num) =
             Self::parse_output_stack(stack)?;
@@ -290,6 +295,7 @@ impl TransactionKernel {
             account,
             output_notes,
             expiration_block_num,
+            account_events,
         })
     }
 }
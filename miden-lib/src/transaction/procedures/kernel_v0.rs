@@ -6,7 +6,7 @@ use miden_objects::{digest, Digest, Felt};
 // ================================================================================================
 
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 32] = [
+pub const KERNEL0_PROCEDURES: [Digest; 33] = [
     // account_vault_add_asset
     digest!(0xa9fe41a58d609bc6, 0xca49c09c352213a4, 0x30f55868815fc5, 0x84f2f2540b3d335e),
     // account_vault_get_balance
@@ -71,4 +71,6 @@ pub const KERNEL0_PROCEDURES: [Digest; 32] = [
     digest!(0xfd51bff9ff681633, 0x8075745cbe89f84c, 0xebecbce5a2c97df9, 0xad195ad3e589976f),
     // get_expiration_delta
     digest!(0x60d571bf3cbe4ca0, 0x1969d046d6e5e007, 0x5f97e0173f503c60, 0x8064d7deb54c2f13),
+    // emit_account_event
+    digest!(0x8e1b6774d1675a7, 0xf39ad4023f179424, 0xd02cc4baf91ab603, 0x11647d3de08ef7ea),
 ];
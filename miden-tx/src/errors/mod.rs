@@ -2,12 +2,17 @@ use alloc::string::String;
 use core::fmt::{self, Display};
 
 use miden_objects::{
-    accounts::AccountId, notes::NoteId, AccountError, Felt, ProvenTransactionError,
-    TransactionInputError, TransactionOutputError,
+    accounts::AccountId,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+    AccountError, Digest, Felt, NoteError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, TransactionScriptError,
 };
 use miden_verifier::VerificationError;
 use vm_processor::ExecutionError;
 
+use crate::executor::TransactionMismatchReport;
+
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
 
@@ -23,6 +28,11 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InconsistentForeignAccountId {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    InvalidForeignAccountInputs(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     TransactionHostCreationFailed(TransactionHostError),
 }
@@ -36,6 +46,24 @@ impl fmt::Display for TransactionExecutorError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionExecutorError {}
 
+// TRANSACTION CROSS-CHECK ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionCrossCheckError {
+    Mismatch(TransactionMismatchReport),
+    ReExecutionFailed(TransactionExecutorError),
+}
+
+impl fmt::Display for TransactionCrossCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionCrossCheckError {}
+
 // TRANSACTION PROVER ERROR
 // ================================================================================================
 
@@ -95,6 +123,89 @@ impl fmt::Display for TransactionVerifierError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionVerifierError {}
 
+// STATEFUL TRANSACTION VERIFIER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatefulTransactionVerifierError {
+    AccountStateMismatch {
+        account_id: AccountId,
+        current_state_hash: Digest,
+        init_state_hash: Digest,
+    },
+    InvalidNoteInclusionProof(NoteId),
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: u32,
+    },
+    ReferenceBlockNotFound(Digest),
+    TransactionExpired {
+        expiration_block_num: u32,
+        chain_tip: u32,
+    },
+    TransactionVerificationFailed(TransactionVerifierError),
+    UnauthenticatedNoteNotFound(NoteId),
+}
+
+impl fmt::Display for StatefulTransactionVerifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StatefulTransactionVerifierError {}
+
+// TRANSACTION RECEIPT ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionReceiptError {
+    AccountIdMismatch {
+        receipt_account_id: AccountId,
+        transaction_account_id: AccountId,
+    },
+    CodeCommitmentMismatch,
+    FinalStateHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InitialStateHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InvalidVaultUpdate(String),
+    MissingStorageMapProof {
+        slot_index: u8,
+        key: Digest,
+    },
+    MissingVaultProof(Digest),
+    NewAccountNotSupported,
+    NonceMismatch {
+        expected: Felt,
+        actual: Felt,
+    },
+    StorageCommitmentMismatch,
+    StorageHeaderMismatch,
+    StorageSlotNotMap(u8),
+    StorageSlotNotValue(u8),
+    StorageSlotOutOfBounds(u8),
+    TransactionIdMismatch {
+        receipt_tx_id: TransactionId,
+        transaction_tx_id: TransactionId,
+    },
+    VaultRootMismatch,
+}
+
+impl fmt::Display for TransactionReceiptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionReceiptError {}
+
 // TRANSACTION HOST ERROR
 // ================================================================================================
 
@@ -134,6 +245,27 @@ impl fmt::Display for DataStoreError {
 #[cfg(feature = "std")]
 impl std::error::Error for DataStoreError {}
 
+// TRANSACTION REQUEST ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionRequestError {
+    DuplicateInputNote(NoteId),
+    InconsistentFaucetId { expected: AccountId, actual: AccountId },
+    MintFromNonFaucetAccount(AccountId),
+    NoteCreationFailed(NoteError),
+    TransactionScriptCompilationFailed(TransactionScriptError),
+}
+
+impl fmt::Display for TransactionRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionRequestError {}
+
 // AUTHENTICATION ERROR
 // ================================================================================================
 
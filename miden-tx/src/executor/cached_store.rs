@@ -0,0 +1,320 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_lib::utils::sync::RwLock;
+use miden_objects::{
+    accounts::{Account, AccountId},
+    notes::NoteId,
+    transaction::{ChainMmr, ForeignAccountInputs, InputNote, InputNotes, TransactionInputs},
+    BlockHeader, Word,
+};
+use winter_maybe_async::*;
+
+use super::DataStore;
+use crate::DataStoreError;
+
+// DATA STORE BACKEND TRAIT
+// ================================================================================================
+
+/// The [DataStoreBackend] trait defines a fine-grained interface for fetching the individual
+/// components of [TransactionInputs].
+///
+/// Unlike [DataStore], which returns all transaction inputs at once, a backend allows a
+/// [CachingDataStore] to fetch only the data which is not already cached.
+#[maybe_async_trait]
+pub trait DataStoreBackend {
+    /// Returns the account with the specified ID together with its seed. The seed must be
+    /// provided only for new accounts.
+    ///
+    /// # Errors
+    /// Returns an error if the account could not be found in the backend.
+    #[maybe_async]
+    fn get_account(&self, account_id: AccountId)
+        -> Result<(Account, Option<Word>), DataStoreError>;
+
+    /// Returns the header of the block with the specified number.
+    ///
+    /// # Errors
+    /// Returns an error if the block could not be found in the backend.
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError>;
+
+    /// Returns the chain MMR for the chain ending right before `block_ref` (i.e., with chain
+    /// length equal to `block_ref`) which contains authentication paths for all of the specified
+    /// blocks.
+    ///
+    /// # Errors
+    /// Returns an error if any of the blocks could not be found in the backend.
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, blocks: &[u32]) -> Result<ChainMmr, DataStoreError>;
+
+    /// Returns the input note with the specified ID. For notes which have been recorded in the
+    /// chain by `block_ref`, the note must be returned together with its inclusion proof.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note could not be found in the backend.
+    /// - The note has already been consumed.
+    #[maybe_async]
+    fn get_input_note(&self, note_id: NoteId, block_ref: u32) -> Result<InputNote, DataStoreError>;
+
+    /// Returns the inputs of the foreign account with the specified ID as of block `block_ref`.
+    ///
+    /// # Errors
+    /// Returns an error if the account or the block could not be found in the backend.
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError>;
+}
+
+// CACHING DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which assembles transaction inputs from a [DataStoreBackend] and caches the
+/// chain data and input notes across calls.
+///
+/// The following data is cached:
+/// - Block headers, keyed by block number. Since block headers never change, these entries are
+///   never invalidated.
+/// - The chain MMR for the current reference block.
+/// - Input notes together with their inclusion proofs.
+///
+/// The chain MMR and the input notes are invalidated whenever a transaction is requested against
+/// a different reference block than the previous one. Accounts, including foreign accounts, are
+/// always fetched from the backend as their state changes with every transaction.
+pub struct CachingDataStore<B> {
+    backend: B,
+    cache: RwLock<DataStoreCache>,
+}
+
+impl<B> CachingDataStore<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CachingDataStore] instantiated with the specified backend and an empty
+    /// cache.
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            cache: RwLock::new(DataStoreCache::default()),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the underlying backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns the hit/miss metrics of this cache.
+    pub fn metrics(&self) -> CacheMetrics {
+        self.cache.read().metrics
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all entries from the cache. The metrics are preserved.
+    pub fn clear(&self) {
+        let mut cache = self.cache.write();
+        cache.block_ref = None;
+        cache.chain_mmr = None;
+        cache.notes.clear();
+        cache.block_headers.clear();
+    }
+}
+
+#[maybe_async_trait]
+impl<B: DataStoreBackend> DataStore for CachingDataStore<B> {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        self.cache.write().set_block_ref(block_ref);
+
+        // the account state changes with every transaction, so it is never cached
+        let (account, account_seed) = maybe_await!(self.backend.get_account(account_id))?;
+
+        // fetch input notes
+        let mut input_notes = Vec::with_capacity(notes.len());
+        for note_id in notes {
+            let cached_note = self.cache.write().get_note(block_ref, note_id);
+            let note = match cached_note {
+                Some(note) => note,
+                None => {
+                    let note = maybe_await!(self.backend.get_input_note(*note_id, block_ref))?;
+                    self.cache.write().insert_note(block_ref, *note_id, note.clone());
+                    note
+                },
+            };
+            input_notes.push(note);
+        }
+
+        // collect the blocks in which the authenticated input notes were created
+        let mut note_blocks: Vec<u32> = input_notes
+            .iter()
+            .filter_map(|note| note.location().map(|location| location.block_num()))
+            .filter(|block_num| *block_num != block_ref)
+            .collect();
+        note_blocks.sort_unstable();
+        note_blocks.dedup();
+
+        // fetch the reference block header
+        let cached_header = self.cache.write().get_block_header(block_ref);
+        let block_header = match cached_header {
+            Some(header) => header,
+            None => {
+                let header = maybe_await!(self.backend.get_block_header(block_ref))?;
+                self.cache.write().block_headers.insert(block_ref, header);
+                header
+            },
+        };
+
+        // fetch the chain MMR; the cached MMR can be re-used only if it tracks all note blocks
+        let cached_mmr = self.cache.write().get_chain_mmr(block_ref, &note_blocks);
+        let chain_mmr = match cached_mmr {
+            Some(chain_mmr) => chain_mmr,
+            None => {
+                let chain_mmr = maybe_await!(self.backend.get_chain_mmr(block_ref, &note_blocks))?;
+                let mut cache = self.cache.write();
+                for block_num in note_blocks.iter() {
+                    if let Some(header) = chain_mmr.get_block(*block_num) {
+                        cache.block_headers.insert(*block_num, *header);
+                    }
+                }
+                cache.insert_chain_mmr(block_ref, chain_mmr.clone());
+                chain_mmr
+            },
+        };
+
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        maybe_await!(self.backend.get_foreign_account_inputs(account_id, block_ref))
+    }
+}
+
+// CACHE METRICS
+// ================================================================================================
+
+/// Hit and miss counters of a [CachingDataStore].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheMetrics {
+    pub block_header_hits: u64,
+    pub block_header_misses: u64,
+    pub chain_mmr_hits: u64,
+    pub chain_mmr_misses: u64,
+    pub note_hits: u64,
+    pub note_misses: u64,
+}
+
+impl CacheMetrics {
+    /// Returns the total number of cache hits.
+    pub fn hits(&self) -> u64 {
+        self.block_header_hits + self.chain_mmr_hits + self.note_hits
+    }
+
+    /// Returns the total number of cache misses.
+    pub fn misses(&self) -> u64 {
+        self.block_header_misses + self.chain_mmr_misses + self.note_misses
+    }
+}
+
+// DATA STORE CACHE
+// ================================================================================================
+
+/// Cached data of a [CachingDataStore].
+#[derive(Default)]
+struct DataStoreCache {
+    block_ref: Option<u32>,
+    chain_mmr: Option<ChainMmr>,
+    notes: BTreeMap<NoteId, InputNote>,
+    block_headers: BTreeMap<u32, BlockHeader>,
+    metrics: CacheMetrics,
+}
+
+impl DataStoreCache {
+    /// Sets the current reference block, invalidating the chain MMR and the input notes if the
+    /// reference block has changed.
+    fn set_block_ref(&mut self, block_ref: u32) {
+        if self.block_ref != Some(block_ref) {
+            self.block_ref = Some(block_ref);
+            self.chain_mmr = None;
+            self.notes.clear();
+        }
+    }
+
+    /// Returns the cached note with the specified ID and updates the metrics.
+    ///
+    /// Nothing is returned if the cached notes belong to a reference block other than `block_ref`,
+    /// which happens when a concurrent request changed the reference block.
+    fn get_note(&mut self, block_ref: u32, note_id: &NoteId) -> Option<InputNote> {
+        let note = self.notes.get(note_id).filter(|_| self.block_ref == Some(block_ref)).cloned();
+        match note {
+            Some(_) => self.metrics.note_hits += 1,
+            None => self.metrics.note_misses += 1,
+        }
+        note
+    }
+
+    /// Returns the cached header of the specified block and updates the metrics.
+    fn get_block_header(&mut self, block_num: u32) -> Option<BlockHeader> {
+        let header = self.block_headers.get(&block_num).copied();
+        match header {
+            Some(_) => self.metrics.block_header_hits += 1,
+            None => self.metrics.block_header_misses += 1,
+        }
+        header
+    }
+
+    /// Returns the cached chain MMR if it was fetched for `block_ref` and contains all of the
+    /// specified blocks, and updates the metrics.
+    fn get_chain_mmr(&mut self, block_ref: u32, blocks: &[u32]) -> Option<ChainMmr> {
+        let chain_mmr = self
+            .chain_mmr
+            .as_ref()
+            .filter(|_| self.block_ref == Some(block_ref))
+            .filter(|chain_mmr| blocks.iter().all(|block_num| chain_mmr.contains_block(*block_num)))
+            .cloned();
+        match chain_mmr {
+            Some(_) => self.metrics.chain_mmr_hits += 1,
+            None => self.metrics.chain_mmr_misses += 1,
+        }
+        chain_mmr
+    }
+
+    /// Caches the provided note unless the reference block has changed since the note was
+    /// requested, in which case the note would be served for the wrong reference block.
+    fn insert_note(&mut self, block_ref: u32, note_id: NoteId, note: InputNote) {
+        if self.block_ref == Some(block_ref) {
+            self.notes.insert(note_id, note);
+        }
+    }
+
+    /// Caches the provided chain MMR unless the reference block has changed since the MMR was
+    /// requested, in which case the MMR would be served for the wrong reference block.
+    fn insert_chain_mmr(&mut self, block_ref: u32, chain_mmr: ChainMmr) {
+        if self.block_ref == Some(block_ref) {
+            self.chain_mmr = Some(chain_mmr);
+        }
+    }
+}
@@ -1,7 +1,12 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
 
-use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionInputs};
+use miden_objects::{
+    accounts::AccountId,
+    crypto::merkle::SmtProof,
+    notes::NoteId,
+    transaction::{ForeignAccountInputs, TransactionInputs},
+};
 use winter_maybe_async::*;
 
 use crate::DataStoreError;
@@ -35,4 +40,70 @@ pub trait DataStore {
         block_ref: u32,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the data required to invoke procedures of the foreign account with the specified
+    /// ID from within a transaction executed against the block with the specified number.
+    ///
+    /// The returned inputs must contain the state of the account as of block_ref, together with
+    /// the Merkle path of the account in the account tree of that block.
+    ///
+    /// The default implementation does not provide any foreign account, so data stores which do
+    /// not support foreign procedure invocation do not need to implement this method.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The block with the specified number could not be found in the data store.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        _block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        Err(DataStoreError::AccountNotFound(account_id))
+    }
+}
+
+// LAZY DATA STORE TRAIT
+// ================================================================================================
+
+/// The [LazyDataStore] trait extends a [DataStore] with the ability to load account data on demand
+/// during transaction execution.
+///
+/// This is required for executing transactions against partial accounts (see
+/// [PartialAccount](miden_objects::accounts::PartialAccount)): when the transaction kernel
+/// accesses a storage map entry or a vault asset which was not included in the partial account,
+/// the transaction host requests the corresponding leaf from the data store.
+///
+/// All returned proofs must open the specified leaf against the state of the account as of the
+/// start of the transaction. Since requests are made while the VM is executing, the methods of
+/// this trait are always synchronous.
+pub trait LazyDataStore {
+    /// Returns the inclusion proof for the leaf at the specified index of the storage map in the
+    /// specified storage slot of the account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The storage slot with the specified index is not a storage map.
+    /// - The data store encountered some internal error
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        slot_index: u8,
+        leaf_index: u64,
+    ) -> Result<SmtProof, DataStoreError>;
+
+    /// Returns the inclusion proof for the leaf at the specified index of the account's vault.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The data store encountered some internal error
+    fn get_vault_witness(
+        &self,
+        account_id: AccountId,
+        leaf_index: u64,
+    ) -> Result<SmtProof, DataStoreError>;
 }
@@ -7,7 +7,8 @@ use miden_objects::{
     notes::NoteId,
     transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
     vm::StackOutputs,
-    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+    TransactionInputError, MAX_NUM_FOREIGN_ACCOUNTS, MAX_TX_EXECUTION_CYCLES,
+    MIN_TX_EXECUTION_CYCLES, ZERO,
 };
 use vm_processor::{ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
@@ -16,11 +17,19 @@ use super::{TransactionExecutorError, TransactionHost};
 use crate::auth::TransactionAuthenticator;
 
 mod data_store;
-pub use data_store::DataStore;
+pub use data_store::{DataStore, LazyDataStore};
+
+mod cached_store;
+pub use cached_store::{CacheMetrics, CachingDataStore, DataStoreBackend};
 
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod reexecutor;
+pub use reexecutor::{
+    Mismatch, OutputNoteMismatch, TransactionMismatchReport, TransactionReExecutor,
+};
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -35,6 +44,7 @@ pub use mast_store::TransactionMastStore;
 /// [TransactionAuthenticator], allowing it to be used with different backend implementations.
 pub struct TransactionExecutor {
     data_store: Arc<dyn DataStore>,
+    lazy_data_store: Option<Arc<dyn LazyDataStore>>,
     mast_store: Arc<TransactionMastStore>,
     authenticator: Option<Arc<dyn TransactionAuthenticator>>,
     /// Holds the code of all accounts loaded into this transaction executor via the
@@ -57,6 +67,7 @@ impl TransactionExecutor {
 
         Self {
             data_store,
+            lazy_data_store: None,
             mast_store: Arc::new(TransactionMastStore::new()),
             authenticator,
             exec_options: ExecutionOptions::new(
@@ -97,6 +108,16 @@ impl TransactionExecutor {
         self
     }
 
+    /// Sets the [LazyDataStore] used to load account data on demand during transaction execution.
+    ///
+    /// This is required for executing transactions against partial accounts: the storage map
+    /// entries and vault assets which are accessed by the transaction but are not included in the
+    /// partial account are requested from the lazy data store.
+    pub fn with_lazy_data_store(mut self, lazy_data_store: Arc<dyn LazyDataStore>) -> Self {
+        self.lazy_data_store = Some(lazy_data_store);
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -128,21 +149,64 @@ impl TransactionExecutor {
     /// and compile the transaction into an executable program. Then, it executes the transaction
     /// program and creates an [ExecutedTransaction] object.
     ///
+    /// The inputs of all foreign accounts specified in the transaction arguments are also fetched
+    /// from the [DataStore], validated against the account root of the reference block, and
+    /// loaded into the advice inputs and the [TransactionMastStore].
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If the inputs of any of the foreign accounts are invalid.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
         account_id: AccountId,
         block_ref: u32,
         notes: &[NoteId],
-        tx_args: TransactionArgs,
+        mut tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let tx_inputs =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        // fetch the foreign account inputs and load them into the advice inputs and MAST store
+        let num_foreign_accounts = tx_args.foreign_accounts().len();
+        if num_foreign_accounts > MAX_NUM_FOREIGN_ACCOUNTS as usize {
+            return Err(TransactionExecutorError::InvalidForeignAccountInputs(
+                TransactionInputError::TooManyForeignAccounts {
+                    max: MAX_NUM_FOREIGN_ACCOUNTS as usize,
+                    actual: num_foreign_accounts,
+                },
+            ));
+        }
+
+        let mut account_codes = self.account_codes.clone();
+        let foreign_account_ids: Vec<AccountId> =
+            tx_args.foreign_accounts().iter().copied().collect();
+        for foreign_account_id in foreign_account_ids {
+            let foreign_inputs = maybe_await!(self
+                .data_store
+                .get_foreign_account_inputs(foreign_account_id, block_ref))
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+            if foreign_inputs.account_id() != foreign_account_id {
+                return Err(TransactionExecutorError::InconsistentForeignAccountId {
+                    expected: foreign_account_id,
+                    actual: foreign_inputs.account_id(),
+                });
+            }
+
+            foreign_inputs
+                .verify(tx_inputs.block_header().account_root())
+                .map_err(TransactionExecutorError::InvalidForeignAccountInputs)?;
+
+            tx_args
+                .add_foreign_account_inputs(&foreign_inputs)
+                .map_err(TransactionExecutorError::InvalidForeignAccountInputs)?;
+            self.mast_store.load_account_code(foreign_inputs.partial_account().code());
+            account_codes.insert(foreign_inputs.partial_account().code().clone());
+        }
+
         let (stack_inputs, advice_inputs) =
             TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
@@ -155,9 +219,12 @@ impl TransactionExecutor {
             advice_recorder,
             self.mast_store.clone(),
             self.authenticator.clone(),
-            self.account_codes.iter().map(|code| code.commitment()).collect(),
+            account_codes.iter().map(|code| code.commitment()).collect(),
         )
         .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+        if let Some(lazy_data_store) = &self.lazy_data_store {
+            host = host.with_lazy_data_store(lazy_data_store.clone());
+        }
 
         // execute the transaction kernel
         let result = vm_processor::execute(
@@ -169,8 +236,7 @@ impl TransactionExecutor {
         .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
 
         // Attempt to retrieve used account codes based on the advice map
-        let account_codes = self
-            .account_codes
+        let account_codes = account_codes
             .iter()
             .filter_map(|code| {
                 tx_args
@@ -201,14 +267,25 @@ fn build_executed_transaction(
     host: TransactionHost<RecAdviceProvider>,
     account_codes: Vec<AccountCode>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-    let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
-        host.into_parts();
+    let (
+        advice_recorder,
+        account_delta,
+        output_notes,
+        account_events,
+        generated_signatures,
+        tx_progress,
+        lazy_advice,
+    ) = host.into_parts();
 
     let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
 
-    let tx_outputs =
-        TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-            .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+    let tx_outputs = TransactionKernel::from_transaction_parts(
+        &stack_outputs,
+        &map.into(),
+        output_notes,
+        account_events,
+    )
+    .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
 
     let final_account = &tx_outputs.account;
 
@@ -237,8 +314,10 @@ fn build_executed_transaction(
         });
     }
 
-    // introduce generated signatures into the witness inputs
+    // introduce generated signatures and the account data loaded on demand into the witness
+    // inputs
     advice_witness.extend_map(generated_signatures);
+    advice_witness.extend(lazy_advice);
 
     Ok(ExecutedTransaction::new(
         tx_inputs,
@@ -0,0 +1,241 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{AccountDelta, AccountHeader},
+    assembly::Library,
+    transaction::{ExecutedTransaction, OutputNote, OutputNotes, TransactionWitness},
+    utils::serde::Serializable,
+    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES,
+};
+use vm_processor::{ExecutionOptions, MemAdviceProvider};
+
+use super::TransactionMastStore;
+use crate::{errors::TransactionCrossCheckError, TransactionExecutorError, TransactionHost};
+
+// TRANSACTION RE-EXECUTOR
+// ================================================================================================
+
+/// The transaction re-executor independently re-executes transactions and cross-checks their
+/// results against the results claimed by the party which originally executed them.
+///
+/// Re-execution is stateless: all data needed to execute the transaction program is taken from
+/// the [TransactionWitness], and a fresh [TransactionHost] without an authenticator is used. Thus,
+/// all signatures required by the transaction must already be present in the advice witness.
+pub struct TransactionReExecutor {
+    mast_store: Arc<TransactionMastStore>,
+    exec_options: ExecutionOptions,
+}
+
+impl TransactionReExecutor {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [TransactionReExecutor] instance.
+    pub fn new() -> Self {
+        Self {
+            mast_store: Arc::new(TransactionMastStore::new()),
+            exec_options: ExecutionOptions::new(
+                Some(MAX_TX_EXECUTION_CYCLES),
+                MIN_TX_EXECUTION_CYCLES,
+                false,
+                false,
+            )
+            .expect("Must not fail while max cycles is more than min trace length"),
+        }
+    }
+
+    /// Loads the provided library code into the internal MAST forest store.
+    ///
+    /// TODO: this is a work-around to support accounts which were complied with user-defined
+    /// libraries. Once Miden Assembler supports library vendoring, this should go away.
+    pub fn load_library(&mut self, library: &Library) {
+        self.mast_store.insert(library.mast_forest().clone());
+    }
+
+    // CROSS-CHECKING
+    // --------------------------------------------------------------------------------------------
+
+    /// Re-executes the provided transaction from its witness and checks that the resulting final
+    /// account header, account delta and output notes are identical to the ones in the provided
+    /// [ExecutedTransaction].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction could not be re-executed.
+    /// - Any of the re-execution results differ from the claimed ones.
+    pub fn cross_check(
+        &self,
+        executed_tx: &ExecutedTransaction,
+    ) -> Result<(), TransactionCrossCheckError> {
+        let tx_witness: TransactionWitness = executed_tx.clone().into();
+
+        self.cross_check_witness(
+            tx_witness,
+            executed_tx.final_account(),
+            executed_tx.account_delta(),
+            executed_tx.output_notes(),
+        )
+    }
+
+    /// Re-executes the transaction described by the provided witness and checks that the
+    /// resulting final account header, account delta and output notes are identical to the
+    /// claimed ones.
+    ///
+    /// The results are compared using their serialized representations.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction could not be re-executed.
+    /// - Any of the re-execution results differ from the claimed ones.
+    pub fn cross_check_witness(
+        &self,
+        tx_witness: TransactionWitness,
+        final_account: &AccountHeader,
+        account_delta: &AccountDelta,
+        output_notes: &OutputNotes,
+    ) -> Result<(), TransactionCrossCheckError> {
+        let (actual_account, actual_delta, actual_notes) = self
+            .reexecute(tx_witness)
+            .map_err(TransactionCrossCheckError::ReExecutionFailed)?;
+
+        let mut report = TransactionMismatchReport::default();
+
+        if final_account.to_bytes() != actual_account.to_bytes() {
+            report.final_account = Some(Mismatch {
+                claimed: final_account.clone(),
+                actual: actual_account,
+            });
+        }
+
+        if account_delta.to_bytes() != actual_delta.to_bytes() {
+            report.account_delta = Some(Mismatch {
+                claimed: account_delta.clone(),
+                actual: actual_delta,
+            });
+        }
+
+        // pair the claimed and actual notes by index, until both lists are exhausted
+        let (mut claimed_notes, mut actual_notes) = (output_notes.iter(), actual_notes.iter());
+        let note_pairs =
+            core::iter::from_fn(|| match (claimed_notes.next(), actual_notes.next()) {
+                (None, None) => None,
+                pair => Some(pair),
+            });
+        for (index, (claimed, actual)) in note_pairs.enumerate() {
+            if claimed.map(|note| note.to_bytes()) != actual.map(|note| note.to_bytes()) {
+                report.output_notes.push(OutputNoteMismatch {
+                    index,
+                    claimed: claimed.cloned(),
+                    actual: actual.cloned(),
+                });
+            }
+        }
+
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(TransactionCrossCheckError::Mismatch(report))
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the transaction program using the data in the provided witness and returns the
+    /// final account header, account delta and output notes of the transaction.
+    fn reexecute(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<(AccountHeader, AccountDelta, OutputNotes), TransactionExecutorError> {
+        let TransactionWitness {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            account_codes,
+        } = tx_witness;
+
+        for account_code in &account_codes {
+            self.mast_store.load_account_code(account_code);
+        }
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            None,
+            account_codes.iter().map(|code| code.commitment()).collect(),
+        )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+
+        let result = vm_processor::execute(
+            &TransactionKernel::main(),
+            stack_inputs,
+            &mut host,
+            self.exec_options,
+        )
+        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+
+        let (advice_provider, account_delta, output_notes, account_events, ..) = host.into_parts();
+        let (_, map, _) = advice_provider.into_parts();
+        let tx_outputs = TransactionKernel::from_transaction_parts(
+            result.stack_outputs(),
+            &map.into(),
+            output_notes,
+            account_events,
+        )
+        .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+
+        Ok((tx_outputs.account, account_delta, tx_outputs.output_notes))
+    }
+}
+
+impl Default for TransactionReExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// MISMATCH REPORT
+// ================================================================================================
+
+/// A pair of differing values: the one claimed by the original executor and the one obtained by
+/// re-executing the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<T> {
+    pub claimed: T,
+    pub actual: T,
+}
+
+/// Describes an output note which differs between the claimed and the re-executed transaction.
+///
+/// If one of the transactions did not create a note at the given index, the corresponding field
+/// is set to `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputNoteMismatch {
+    pub index: usize,
+    pub claimed: Option<OutputNote>,
+    pub actual: Option<OutputNote>,
+}
+
+/// A structured report of the differences between the claimed results of a transaction and the
+/// results obtained by re-executing it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionMismatchReport {
+    pub final_account: Option<Mismatch<AccountHeader>>,
+    pub account_delta: Option<Mismatch<AccountDelta>>,
+    pub output_notes: Vec<OutputNoteMismatch>,
+}
+
+impl TransactionMismatchReport {
+    /// Returns true if the report does not contain any differences.
+    pub fn is_empty(&self) -> bool {
+        self.final_account.is_none() && self.account_delta.is_none() && self.output_notes.is_empty()
+    }
+}
@@ -8,17 +8,21 @@ use alloc::{
 use miden_lib::{
     errors::tx_kernel_errors::TX_KERNEL_ERRORS,
     transaction::{
-        memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
-        TransactionEvent, TransactionKernelError, TransactionTrace,
+        memory::{
+            CURRENT_INPUT_NOTE_PTR, INPUT_VAULT_ROOT_PTR, NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR,
+            NATIVE_ACCT_VAULT_ROOT_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR, OUTPUT_VAULT_ROOT_PTR,
+        },
+        TransactionEvent, TransactionKernelError, TransactionTrace, ACCOUNT_EVENT_ID_PREFIX,
     },
 };
 use miden_objects::{
-    accounts::{AccountDelta, AccountHeader},
+    accounts::{AccountDelta, AccountHeader, AccountId, StorageSlotType},
     assets::Asset,
+    crypto::merkle::{SmtProof, SMT_DEPTH},
     notes::NoteId,
-    transaction::{OutputNote, TransactionMeasurements},
-    vm::RowIndex,
-    Digest, Hasher,
+    transaction::{AccountEvent, OutputNote, TransactionMeasurements},
+    vm::{AdviceInputs, RowIndex},
+    Digest, Hasher, Word,
 };
 use vm_processor::{
     AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId, ExecutionError, Felt,
@@ -38,7 +42,9 @@ mod tx_progress;
 pub use tx_progress::TransactionProgress;
 
 use crate::{
-    auth::TransactionAuthenticator, errors::TransactionHostError, executor::TransactionMastStore,
+    auth::TransactionAuthenticator,
+    errors::TransactionHostError,
+    executor::{LazyDataStore, TransactionMastStore},
 };
 
 // TRANSACTION HOST
@@ -70,6 +76,11 @@ pub struct TransactionHost<A> {
     /// map.
     output_notes: BTreeMap<usize, OutputNoteBuilder>,
 
+    /// The list of user-defined events emitted by the account code while executing a transaction.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler.
+    account_events: Vec<AccountEvent>,
+
     /// Serves signature generation requests from the transaction runtime for signatures which are
     /// not present in the `generated_signatures` field.
     authenticator: Option<Arc<dyn TransactionAuthenticator>>,
@@ -90,6 +101,20 @@ pub struct TransactionHost<A> {
     ///
     /// This map is initialized at construction time from the [KERNEL_ERRORS] array.
     error_messages: BTreeMap<u32, &'static str>,
+
+    /// The ID of the account against which the transaction is executed.
+    account_id: AccountId,
+
+    /// Serves requests for the storage map entries and vault assets of the account which are not
+    /// present in the advice provider.
+    lazy_data_store: Option<Arc<dyn LazyDataStore>>,
+
+    /// Contains the Merkle nodes and leaves loaded from the `lazy_data_store` during transaction
+    /// execution.
+    ///
+    /// This data is not part of the initial advice inputs, and thus, must be added to the advice
+    /// witness of the transaction.
+    lazy_advice: AdviceInputs,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -115,23 +140,37 @@ impl<A: AdviceProvider> TransactionHost<A> {
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
+            account_events: Vec::new(),
             authenticator,
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
             error_messages: kernel_assertion_errors,
+            account_id: account.id(),
+            lazy_data_store: None,
+            lazy_advice: AdviceInputs::default(),
         })
     }
 
-    /// Consumes `self` and returns the advice provider, account delta, output notes, generated
-    /// signatures, and transaction progress.
+    /// Sets the [LazyDataStore] used to load the storage map entries and vault assets of the
+    /// account which are not present in the advice provider.
+    pub fn with_lazy_data_store(mut self, lazy_data_store: Arc<dyn LazyDataStore>) -> Self {
+        self.lazy_data_store = Some(lazy_data_store);
+        self
+    }
+
+    /// Consumes `self` and returns the advice provider, account delta, output notes, account
+    /// events, generated signatures, transaction progress, and the advice data loaded on demand.
+    #[allow(clippy::type_complexity)]
     pub fn into_parts(
         self,
     ) -> (
         A,
         AccountDelta,
         Vec<OutputNote>,
+        Vec<AccountEvent>,
         BTreeMap<Digest, Vec<Felt>>,
         TransactionProgress,
+        AdviceInputs,
     ) {
         let output_notes = self.output_notes.into_values().map(|builder| builder.build()).collect();
 
@@ -139,8 +178,10 @@ impl<A: AdviceProvider> TransactionHost<A> {
             self.adv_provider,
             self.account_delta.into_delta(),
             output_notes,
+            self.account_events,
             self.generated_signatures,
             self.tx_progress,
+            self.lazy_advice,
         )
     }
 
@@ -227,6 +268,34 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Extracts the user-defined event emitted by the account code from the process state and
+    /// records it in the list of account events.
+    ///
+    /// Expected stack state: [event_id, EVENT_DATA, ...]
+    pub fn on_account_emit_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        let event_id = process.get_stack_item(0);
+        let event_id = u32::try_from(event_id.as_int())
+            .map_err(|_| TransactionKernelError::InvalidAccountEventId(event_id.as_int()))?;
+
+        if event_id >> 16 != ACCOUNT_EVENT_ID_PREFIX {
+            return Err(TransactionKernelError::InvalidAccountEventId(event_id.into()));
+        }
+
+        let event_data = [
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ];
+
+        self.account_events.push(AccountEvent::new(event_id, event_data));
+
+        Ok(())
+    }
+
     // ACCOUNT STORAGE UPDATE HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -412,6 +481,79 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(HostResponse::None)
     }
 
+    /// Loads the specified node of the account's vault or of one of the account's storage maps
+    /// from the lazy data store, if the node is not present in the advice provider.
+    ///
+    /// The tree is identified by comparing the provided root against the current roots of the
+    /// native account's vault and storage maps. Since the input and output vaults of the
+    /// transaction are derived from the account vault, their roots are treated as vault roots as
+    /// well. If the root does not belong to any of these trees, or no lazy data store is assigned
+    /// to this host, this is a no-op.
+    ///
+    /// The loaded leaf and its Merkle path are inserted into the advice provider and recorded in
+    /// the `lazy_advice` field of this host.
+    fn on_tree_node_requested<S: ProcessState>(
+        &mut self,
+        process: &S,
+        root: Word,
+        depth: Felt,
+        index: Felt,
+    ) -> Result<(), ExecutionError> {
+        let Some(lazy_data_store) = self.lazy_data_store.clone() else {
+            return Ok(());
+        };
+
+        if depth.as_int() != SMT_DEPTH as u64
+            || self.adv_provider.get_tree_node(root, &depth, &index).is_ok()
+        {
+            return Ok(());
+        }
+
+        let leaf_index = index.as_int();
+        let proof = if Self::is_vault_root(process, root) {
+            lazy_data_store.get_vault_witness(self.account_id, leaf_index)
+        } else {
+            match Self::get_storage_map_slot_index(process, root)? {
+                Some(slot_index) => {
+                    lazy_data_store.get_storage_map_witness(self.account_id, slot_index, leaf_index)
+                },
+                None => return Ok(()),
+            }
+        }
+        .map_err(|err| {
+            ExecutionError::EventError(
+                TransactionKernelError::FailedToLoadAccountData(err.to_string()).to_string(),
+            )
+        })?;
+
+        self.insert_smt_proof(&proof)
+    }
+
+    /// Inserts the leaf and the Merkle path of the provided proof into the advice provider and
+    /// records them in the `lazy_advice` field of this host.
+    fn insert_smt_proof(&mut self, proof: &SmtProof) -> Result<(), ExecutionError> {
+        let leaf = proof.leaf();
+        let inner_nodes: Vec<_> = proof
+            .path()
+            .inner_nodes(leaf.index().value(), leaf.hash())
+            .map_err(|err| {
+                ExecutionError::EventError(
+                    TransactionKernelError::FailedToLoadAccountData(err.to_string()).to_string(),
+                )
+            })?
+            .collect();
+
+        for node in inner_nodes.iter() {
+            self.adv_provider.merge_roots(node.left.into(), node.right.into())?;
+        }
+        self.adv_provider.insert_into_map(leaf.hash().into(), leaf.to_elements())?;
+
+        self.lazy_advice.extend_merkle_store(inner_nodes.into_iter());
+        self.lazy_advice.extend_map([(leaf.hash(), leaf.to_elements())]);
+
+        Ok(())
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -454,6 +596,36 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
         Ok(num_storage_slots_word[0].as_int())
     }
+    /// Returns true if the specified root is the current root of the native account's vault, or
+    /// of the input or output vault of the transaction.
+    fn is_vault_root<S: ProcessState>(process: &S, root: Word) -> bool {
+        [NATIVE_ACCT_VAULT_ROOT_PTR, INPUT_VAULT_ROOT_PTR, OUTPUT_VAULT_ROOT_PTR]
+            .into_iter()
+            .any(|vault_root_ptr| {
+                process.get_mem_value(process.ctx(), vault_root_ptr) == Some(root)
+            })
+    }
+
+    /// Returns the index of the native account's storage map slot with the specified root, or
+    /// None if none of the account's storage maps has this root.
+    fn get_storage_map_slot_index<S: ProcessState>(
+        process: &S,
+        root: Word,
+    ) -> Result<Option<u8>, ExecutionError> {
+        let num_storage_slots = Self::get_num_storage_slots(process)
+            .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+
+        for slot_index in 0..num_storage_slots {
+            let slot_ptr = NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR + 2 * slot_index as u32;
+            let slot_value = process.get_mem_value(process.ctx(), slot_ptr);
+            let slot_type = process.get_mem_value(process.ctx(), slot_ptr + 1);
+            if slot_value == Some(root) && slot_type == Some(StorageSlotType::Map.as_word()) {
+                return Ok(Some(slot_index as u8));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 // HOST IMPLEMENTATION FOR TRANSACTION HOST
@@ -475,6 +647,24 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
     ) -> Result<HostResponse, ExecutionError> {
         match injector {
             AdviceInjector::SigToStack { .. } => self.on_signature_requested(process),
+            AdviceInjector::MerkleNodeToStack => {
+                let depth = process.get_stack_item(0);
+                let index = process.get_stack_item(1);
+                let root = [
+                    process.get_stack_item(5),
+                    process.get_stack_item(4),
+                    process.get_stack_item(3),
+                    process.get_stack_item(2),
+                ];
+                self.on_tree_node_requested(process, root, depth, index)?;
+                self.adv_provider.set_advice(process, &injector)
+            },
+            AdviceInjector::SmtPeek => {
+                let key = process.get_stack_word(0);
+                let root = process.get_stack_word(1);
+                self.on_tree_node_requested(process, root, Felt::from(SMT_DEPTH), key[3])?;
+                self.adv_provider.set_advice(process, &injector)
+            },
             injector => self.adv_provider.set_advice(process, &injector),
         }
     }
@@ -532,6 +722,8 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
 
             TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
             TransactionEvent::NoteAfterAddAsset => Ok(()),
+
+            TransactionEvent::AccountEmitEvent => self.on_account_emit_event(process),
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
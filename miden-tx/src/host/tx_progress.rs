@@ -1,5 +1,13 @@
 pub use alloc::vec::Vec;
 
+use miden_objects::{
+    utils::{
+        read_bounded_vec,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
+    Digest,
+};
+
 use super::{NoteId, RowIndex, TransactionMeasurements};
 
 // TRANSACTION PROGRESS
@@ -7,7 +15,7 @@ use super::{NoteId, RowIndex, TransactionMeasurements};
 
 /// Contains the information about the number of cycles for each of the transaction execution
 /// stages.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct TransactionProgress {
     prologue: CycleInterval,
     notes_processing: CycleInterval,
@@ -86,6 +94,40 @@ impl TransactionProgress {
     }
 }
 
+impl Serializable for TransactionProgress {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.prologue.write_into(target);
+        self.notes_processing.write_into(target);
+        target.write_usize(self.note_execution.len());
+        for (note_id, interval) in self.note_execution.iter() {
+            note_id.write_into(target);
+            interval.write_into(target);
+        }
+        self.tx_script_processing.write_into(target);
+        self.epilogue.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionProgress {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prologue = CycleInterval::read_from(source)?;
+        let notes_processing = CycleInterval::read_from(source)?;
+        // each entry holds a note ID and a cycle interval whose bounds take at least a byte each
+        let note_execution: Vec<(NoteId, CycleInterval)> =
+            read_bounded_vec(source, Digest::SERIALIZED_SIZE + 2)?;
+        let tx_script_processing = CycleInterval::read_from(source)?;
+        let epilogue = CycleInterval::read_from(source)?;
+
+        Ok(Self {
+            prologue,
+            notes_processing,
+            note_execution,
+            tx_script_processing,
+            epilogue,
+        })
+    }
+}
+
 impl From<TransactionProgress> for TransactionMeasurements {
     fn from(tx_progress: TransactionProgress) -> Self {
         let prologue = tx_progress.prologue().len();
@@ -113,7 +155,7 @@ impl From<TransactionProgress> for TransactionMeasurements {
 }
 
 /// Stores the cycles corresponding to the start and the end of an interval.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct CycleInterval {
     start: Option<RowIndex>,
     end: Option<RowIndex>,
@@ -144,3 +186,19 @@ impl CycleInterval {
         0
     }
 }
+
+impl Serializable for CycleInterval {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.start.map(u32::from).write_into(target);
+        self.end.map(u32::from).write_into(target);
+    }
+}
+
+impl Deserializable for CycleInterval {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let start = Option::<u32>::read_from(source)?.map(RowIndex::from);
+        let end = Option::<u32>::read_from(source)?.map(RowIndex::from);
+
+        Ok(Self { start, end })
+    }
+}
@@ -9,21 +9,31 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+pub use executor::{
+    CacheMetrics, CachingDataStore, DataStore, DataStoreBackend, LazyDataStore, Mismatch,
+    OutputNoteMismatch, TransactionExecutor, TransactionMastStore, TransactionMismatchReport,
+    TransactionReExecutor,
+};
 
 pub mod host;
 pub use host::{TransactionHost, TransactionProgress};
 
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
+pub use prover::{
+    prune_witness, HashFunction, LocalTransactionProver, ProvingOptions, TransactionProver,
+};
+
+mod request;
+pub use request::{TransactionRequest, TransactionRequestBuilder};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{verify_receipt, ChainView, StatefulTransactionVerifier, TransactionVerifier};
 
 mod errors;
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, DataStoreError, StatefulTransactionVerifierError,
+    TransactionCrossCheckError, TransactionExecutorError, TransactionProverError,
+    TransactionReceiptError, TransactionRequestError, TransactionVerifierError,
 };
 
 pub mod auth;
@@ -6,16 +6,22 @@ use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     accounts::delta::AccountUpdateDetails,
     assembly::Library,
-    transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
+    transaction::{
+        OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionMeasurements,
+        TransactionWitness,
+    },
 };
 use miden_prover::prove;
-pub use miden_prover::ProvingOptions;
+pub use miden_prover::{HashFunction, ProvingOptions};
 use vm_processor::MemAdviceProvider;
 use winter_maybe_async::*;
 
 use super::{TransactionHost, TransactionProverError};
 use crate::executor::TransactionMastStore;
 
+mod pruning;
+pub use pruning::prune_witness;
+
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
 
@@ -56,6 +62,11 @@ impl LocalTransactionProver {
         }
     }
 
+    /// Returns the options used by this prover to generate proofs.
+    pub fn proof_options(&self) -> &ProvingOptions {
+        &self.proof_options
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -63,24 +74,20 @@ impl LocalTransactionProver {
     pub fn load_library(&mut self, library: &Library) {
         self.mast_store.insert(library.mast_forest().clone());
     }
-}
 
-impl Default for LocalTransactionProver {
-    fn default() -> Self {
-        Self {
-            mast_store: Arc::new(TransactionMastStore::new()),
-            proof_options: Default::default(),
-        }
-    }
-}
-
-#[maybe_async_trait]
-impl TransactionProver for LocalTransactionProver {
+    /// Proves the provided transaction and returns a [ProvenTransaction] together with the number
+    /// of cycles spent in each stage of its execution.
+    ///
+    /// Unlike the trace length of the proof, the total number of cycles in the measurements is not
+    /// padded to the next power of two.
+    ///
+    /// # Errors
+    /// Returns an error in the same cases as [TransactionProver::prove()].
     #[maybe_async]
-    fn prove(
+    pub fn prove_with_measurements(
         &self,
         tx_witness: TransactionWitness,
-    ) -> Result<ProvenTransaction, TransactionProverError> {
+    ) -> Result<(ProvenTransaction, TransactionMeasurements), TransactionProverError> {
         let TransactionWitness {
             tx_inputs,
             tx_args,
@@ -123,12 +130,23 @@ impl TransactionProver for LocalTransactionProver {
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
         // extract transaction outputs and process transaction data
-        let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
-            host.into_parts();
+        let (
+            advice_provider,
+            account_delta,
+            output_notes,
+            account_events,
+            _signatures,
+            tx_progress,
+            _lazy_advice,
+        ) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
-        let tx_outputs =
-            TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-                .map_err(TransactionProverError::InvalidTransactionOutput)?;
+        let tx_outputs = TransactionKernel::from_transaction_parts(
+            &stack_outputs,
+            &map.into(),
+            output_notes,
+            account_events,
+        )
+        .map_err(TransactionProverError::InvalidTransactionOutput)?;
 
         // erase private note information (convert private full notes to just headers)
         let output_notes: Vec<_> = tx_outputs.output_notes.iter().map(OutputNote::shrink).collect();
@@ -144,17 +162,19 @@ impl TransactionProver for LocalTransactionProver {
         .add_input_notes(input_notes)
         .add_output_notes(output_notes);
 
-        let builder = match account.is_public() {
+        let builder = match account.id().is_public() {
             true => {
-                let account_update_details = if account.is_new() {
-                    let mut account = account.clone();
-                    account
-                        .apply_delta(&account_delta)
-                        .map_err(TransactionProverError::InvalidAccountDelta)?;
-
-                    AccountUpdateDetails::New(account)
-                } else {
-                    AccountUpdateDetails::Delta(account_delta)
+                // partial accounts are never new, so new accounts are always available in full
+                let account_update_details = match account.full_account() {
+                    Some(account) if account.is_new() => {
+                        let mut account = account.clone();
+                        account
+                            .apply_delta(&account_delta)
+                            .map_err(TransactionProverError::InvalidAccountDelta)?;
+
+                        AccountUpdateDetails::New(account)
+                    },
+                    _ => AccountUpdateDetails::Delta(account_delta),
                 };
 
                 builder.account_update_details(account_update_details)
@@ -162,6 +182,28 @@ impl TransactionProver for LocalTransactionProver {
             false => builder,
         };
 
-        builder.build().map_err(TransactionProverError::ProvenTransactionError)
+        let proven_tx = builder.build().map_err(TransactionProverError::ProvenTransactionError)?;
+
+        Ok((proven_tx, tx_progress.into()))
+    }
+}
+
+impl Default for LocalTransactionProver {
+    fn default() -> Self {
+        Self {
+            mast_store: Arc::new(TransactionMastStore::new()),
+            proof_options: Default::default(),
+        }
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionProver for LocalTransactionProver {
+    #[maybe_async]
+    fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        maybe_await!(self.prove_with_measurements(tx_witness)).map(|(proven_tx, _)| proven_tx)
     }
 }
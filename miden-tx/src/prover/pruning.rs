@@ -0,0 +1,88 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::PartialAccount,
+    transaction::{InputAccount, TransactionInputs, TransactionWitness},
+    vm::AdviceInputs,
+    Digest,
+};
+use vm_processor::MemAdviceProvider;
+
+// WITNESS PRUNING
+// ================================================================================================
+
+/// Returns a copy of the provided [TransactionWitness] which contains only the data required to
+/// prove the transaction.
+///
+/// The advice witness of an executed transaction records all advice data requested by the VM
+/// during execution. Thus, the witness can be pruned as follows:
+/// - The account is replaced by a [PartialAccount] without storage map and vault proofs. The
+///   storage map entries and vault assets read by the transaction are already part of the advice
+///   witness. New accounts are kept in full since the prover needs the full initial state of new
+///   public accounts.
+/// - The advice stack, and the advice map entries and Merkle store nodes which the prover derives
+///   from the transaction inputs and arguments, are removed from the advice witness.
+///
+/// The pruned witness results in the same proven transaction as the original one.
+pub fn prune_witness(tx_witness: TransactionWitness) -> TransactionWitness {
+    let TransactionWitness {
+        tx_inputs,
+        tx_args,
+        advice_witness,
+        account_codes,
+    } = tx_witness;
+
+    let tx_inputs = prune_account(tx_inputs);
+
+    // compute the advice data which the prover derives from the pruned inputs
+    let (_, derived_advice) = TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+    let (_, derived_map, derived_store) = MemAdviceProvider::from(derived_advice).into_parts();
+    let derived_nodes: BTreeSet<Digest> =
+        derived_store.inner_nodes().map(|node| node.value).collect();
+
+    // keep only the recorded advice data which cannot be derived by the prover
+    let (_, map, store) = MemAdviceProvider::from(advice_witness).into_parts();
+    let map = map.into_iter().filter(|(key, values)| derived_map.get(key) != Some(values));
+    let nodes: Vec<_> = store
+        .inner_nodes()
+        .filter(|node| !derived_nodes.contains(&node.value))
+        .collect();
+
+    let mut advice_witness = AdviceInputs::default().with_map(map);
+    advice_witness.extend_merkle_store(nodes.into_iter());
+
+    TransactionWitness {
+        tx_inputs,
+        tx_args,
+        advice_witness,
+        account_codes,
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Replaces the account of the provided transaction inputs with a [PartialAccount] which contains
+/// only the account header, storage header and code, unless the account is new.
+fn prune_account(tx_inputs: TransactionInputs) -> TransactionInputs {
+    if tx_inputs.account().is_new() {
+        return tx_inputs;
+    }
+
+    let (account, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+    let account = match account {
+        InputAccount::Full(account) => PartialAccount::from(&account),
+        InputAccount::Partial(account) => PartialAccount::new(
+            account.header().clone(),
+            account.storage_header().clone(),
+            account.code().clone(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .expect("parts of a valid partial account should be consistent"),
+    };
+
+    TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+        .expect("pruning the account should not invalidate the transaction inputs")
+}
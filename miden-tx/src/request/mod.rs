@@ -0,0 +1,445 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_lib::{
+    notes::{create_p2id_note, create_p2idr_note, create_swap_note},
+    transaction::TransactionKernel,
+};
+use miden_objects::{
+    accounts::{AccountId, AccountType},
+    assets::{Asset, FungibleAsset},
+    crypto::rand::FeltRng,
+    notes::{Note, NoteDetails, NoteId, NoteType},
+    transaction::{TransactionArgs, TransactionScript},
+    Felt, Word, ZERO,
+};
+use vm_processor::AdviceMap;
+
+use crate::errors::TransactionRequestError;
+
+// TRANSACTION REQUEST
+// ================================================================================================
+
+/// A high-level description of a transaction against a single account.
+///
+/// A transaction request is built via [TransactionRequestBuilder] from a list of typed operations
+/// (e.g., pay-to-id, swap, mint), and contains everything needed to execute the transaction:
+/// - the IDs of the notes to be consumed together with their note args,
+/// - the transaction script which creates the requested output notes and authenticates the
+///   transaction,
+/// - the output notes the transaction is expected to create, and
+/// - the notes which are expected to be created by other accounts in the future as a result of this
+///   transaction (e.g., the payback notes of SWAP notes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequest {
+    account_id: AccountId,
+    input_note_ids: Vec<NoteId>,
+    note_args: BTreeMap<NoteId, Word>,
+    expected_output_notes: Vec<Note>,
+    expected_future_notes: Vec<NoteDetails>,
+    tx_script: TransactionScript,
+}
+
+impl TransactionRequest {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionRequestBuilder] for a transaction against the specified account.
+    pub fn builder(account_id: AccountId) -> TransactionRequestBuilder {
+        TransactionRequestBuilder::new(account_id)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction is to be executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the IDs of the notes to be consumed by the transaction, in the order of
+    /// consumption.
+    pub fn input_note_ids(&self) -> &[NoteId] {
+        &self.input_note_ids
+    }
+
+    /// Returns the note args for the consumed notes.
+    pub fn note_args(&self) -> &BTreeMap<NoteId, Word> {
+        &self.note_args
+    }
+
+    /// Returns the notes which the transaction is expected to create.
+    pub fn expected_output_notes(&self) -> &[Note] {
+        &self.expected_output_notes
+    }
+
+    /// Returns the details of the notes which are expected to be created by other accounts as a
+    /// result of this transaction.
+    pub fn expected_future_notes(&self) -> &[NoteDetails] {
+        &self.expected_future_notes
+    }
+
+    /// Returns the transaction script generated for this request.
+    pub fn tx_script(&self) -> &TransactionScript {
+        &self.tx_script
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [TransactionArgs] for executing this request.
+    ///
+    /// The advice inputs of the returned arguments are populated with the details of all expected
+    /// output notes.
+    pub fn to_transaction_args(&self) -> TransactionArgs {
+        let mut tx_args = TransactionArgs::new(
+            Some(self.tx_script.clone()),
+            Some(self.note_args.clone()),
+            AdviceMap::default(),
+        );
+        tx_args.extend_expected_output_notes(self.expected_output_notes.iter().cloned());
+
+        tx_args
+    }
+}
+
+impl From<TransactionRequest> for TransactionArgs {
+    fn from(request: TransactionRequest) -> Self {
+        request.to_transaction_args()
+    }
+}
+
+// TRANSACTION REQUEST BUILDER
+// ================================================================================================
+
+/// A builder of [TransactionRequest]s.
+///
+/// Output notes are created with the standard note scripts from `miden-lib`. All notes created by
+/// the builder have `aux` set to ZERO; the `rng` passed to the individual operations is used to
+/// draw the serial numbers of the notes.
+///
+/// The generated transaction script expects the account to expose the procedures of the basic
+/// wallet (for pay-to-id, pay-to-id-reclaimable and swap operations), or of the basic fungible
+/// faucet (for mint operations), as well as the basic RPO Falcon512 authentication procedure.
+#[derive(Clone, Debug)]
+pub struct TransactionRequestBuilder {
+    account_id: AccountId,
+    input_notes: Vec<NoteId>,
+    note_args: BTreeMap<NoteId, Word>,
+    sent_notes: Vec<Note>,
+    minted_notes: Vec<Note>,
+    expected_future_notes: Vec<NoteDetails>,
+}
+
+impl TransactionRequestBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionRequestBuilder] for a transaction against the specified account.
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            input_notes: Vec::new(),
+            note_args: BTreeMap::new(),
+            sent_notes: Vec::new(),
+            minted_notes: Vec::new(),
+            expected_future_notes: Vec::new(),
+        }
+    }
+
+    // OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the specified notes to the list of notes consumed by the transaction.
+    pub fn consume_notes(mut self, note_ids: impl IntoIterator<Item = NoteId>) -> Self {
+        self.input_notes.extend(note_ids);
+        self
+    }
+
+    /// Adds the specified note to the list of notes consumed by the transaction, and sets the
+    /// note args to be passed to its script.
+    pub fn consume_note_with_args(mut self, note_id: NoteId, args: Word) -> Self {
+        self.input_notes.push(note_id);
+        self.note_args.insert(note_id, args);
+        self
+    }
+
+    /// Adds a P2ID note sending the specified assets from the account to the `target` account.
+    ///
+    /// # Errors
+    /// Returns an error if the note could not be created.
+    pub fn pay_to_id<R: FeltRng>(
+        mut self,
+        target: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        rng: &mut R,
+    ) -> Result<Self, TransactionRequestError> {
+        let note = create_p2id_note(self.account_id, target, assets, note_type, ZERO, rng)
+            .map_err(TransactionRequestError::NoteCreationFailed)?;
+        self.sent_notes.push(note);
+        Ok(self)
+    }
+
+    /// Adds a P2IDR note sending the specified assets from the account to the `target` account.
+    /// The assets can be reclaimed by the account once the chain reaches `recall_height`.
+    ///
+    /// # Errors
+    /// Returns an error if the note could not be created.
+    pub fn pay_to_id_reclaimable<R: FeltRng>(
+        mut self,
+        target: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        recall_height: u32,
+        rng: &mut R,
+    ) -> Result<Self, TransactionRequestError> {
+        let note =
+            create_p2idr_note(self.account_id, target, assets, note_type, ZERO, recall_height, rng)
+                .map_err(TransactionRequestError::NoteCreationFailed)?;
+        self.sent_notes.push(note);
+        Ok(self)
+    }
+
+    /// Adds a SWAP note offering `offered_asset` in exchange for `requested_asset`.
+    ///
+    /// The details of the payback note are recorded as an expected future note of the request.
+    ///
+    /// # Errors
+    /// Returns an error if the note could not be created.
+    pub fn swap<R: FeltRng>(
+        mut self,
+        offered_asset: Asset,
+        requested_asset: Asset,
+        note_type: NoteType,
+        rng: &mut R,
+    ) -> Result<Self, TransactionRequestError> {
+        let (note, payback_note) =
+            create_swap_note(self.account_id, offered_asset, requested_asset, note_type, ZERO, rng)
+                .map_err(TransactionRequestError::NoteCreationFailed)?;
+        self.sent_notes.push(note);
+        self.expected_future_notes.push(payback_note);
+        Ok(self)
+    }
+
+    /// Adds a P2ID note carrying the newly minted `asset` to the `target` account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account is not a fungible faucet.
+    /// - The asset was not issued by the account.
+    /// - The note could not be created.
+    pub fn mint<R: FeltRng>(
+        mut self,
+        target: AccountId,
+        asset: FungibleAsset,
+        note_type: NoteType,
+        rng: &mut R,
+    ) -> Result<Self, TransactionRequestError> {
+        if self.account_id.account_type() != AccountType::FungibleFaucet {
+            return Err(TransactionRequestError::MintFromNonFaucetAccount(self.account_id));
+        }
+        if asset.faucet_id() != self.account_id {
+            return Err(TransactionRequestError::InconsistentFaucetId {
+                expected: self.account_id,
+                actual: asset.faucet_id(),
+            });
+        }
+
+        let note =
+            create_p2id_note(self.account_id, target, vec![asset.into()], note_type, ZERO, rng)
+                .map_err(TransactionRequestError::NoteCreationFailed)?;
+        self.minted_notes.push(note);
+        Ok(self)
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the [TransactionRequest] and generates its transaction script.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same note is consumed more than once.
+    /// - The generated transaction script fails to compile.
+    pub fn build(self) -> Result<TransactionRequest, TransactionRequestError> {
+        let mut seen_notes = BTreeSet::new();
+        for note_id in self.input_notes.iter() {
+            if !seen_notes.insert(*note_id) {
+                return Err(TransactionRequestError::DuplicateInputNote(*note_id));
+            }
+        }
+
+        let script_src = self.build_script_source();
+        let tx_script = TransactionScript::compile(script_src, [], TransactionKernel::assembler())
+            .map_err(TransactionRequestError::TransactionScriptCompilationFailed)?;
+
+        let mut expected_output_notes = self.minted_notes;
+        expected_output_notes.extend(self.sent_notes);
+
+        Ok(TransactionRequest {
+            account_id: self.account_id,
+            input_note_ids: self.input_notes,
+            note_args: self.note_args,
+            expected_output_notes,
+            expected_future_notes: self.expected_future_notes,
+            tx_script,
+        })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MASM source of the transaction script which creates the requested output notes
+    /// and authenticates the transaction.
+    ///
+    /// Minted notes are created first, followed by the sent notes in the order they were added.
+    fn build_script_source(&self) -> String {
+        let mut src = String::from("begin\n");
+
+        for note in self.minted_notes.iter() {
+            let amount = match note.assets().iter().next() {
+                Some(Asset::Fungible(asset)) => asset.amount(),
+                _ => unreachable!("minted notes contain exactly one fungible asset"),
+            };
+
+            // => [amount, tag, aux, note_type, execution_hint, RECIPIENT, PAD(6)]
+            let _ = writeln!(src, "    padw push.0.0");
+            push_note_parameters(&mut src, note);
+            let _ = writeln!(src, "    push.{amount}");
+            let _ =
+                writeln!(src, "    call.::miden::contracts::faucets::basic_fungible::distribute");
+            // => [note_idx, PAD(15)]
+            let _ = writeln!(src, "    dropw dropw dropw dropw\n");
+        }
+
+        for note in self.sent_notes.iter() {
+            // => [tag, aux, note_type, execution_hint, RECIPIENT, PAD(8)]
+            let _ = writeln!(src, "    padw padw");
+            push_note_parameters(&mut src, note);
+            let _ = writeln!(src, "    call.::miden::contracts::wallets::basic::create_note");
+            // => [note_idx, PAD(15)]
+            let _ = writeln!(src, "    swapw dropw");
+            // => [note_idx, PAD(11)]
+
+            for asset in note.assets().iter() {
+                let _ = writeln!(src, "    push.{}", word_to_masm(&Word::from(*asset)));
+                let _ = writeln!(
+                    src,
+                    "    call.::miden::contracts::wallets::basic::move_asset_to_note"
+                );
+                // => [ASSET, note_idx, PAD(11)]
+                let _ = writeln!(src, "    dropw");
+            }
+            let _ = writeln!(src, "    dropw dropw dropw\n");
+        }
+
+        let _ = writeln!(src, "    call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512");
+        src.push_str("end\n");
+
+        src
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends the instructions pushing `[tag, aux, note_type, execution_hint, RECIPIENT]` of the
+/// provided note onto the stack.
+fn push_note_parameters(src: &mut String, note: &Note) {
+    let metadata = note.metadata();
+    let _ = writeln!(src, "    push.{}", word_to_masm(&note.recipient().digest().into()));
+    let _ = writeln!(src, "    push.{}", Felt::from(metadata.execution_hint()));
+    let _ = writeln!(src, "    push.{}", metadata.note_type() as u8);
+    let _ = writeln!(src, "    push.{}", metadata.aux());
+    let _ = writeln!(src, "    push.{}", u32::from(metadata.tag()));
+}
+
+/// Formats the provided word as a MASM `push` argument.
+fn word_to_masm(word: &Word) -> String {
+    word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+            },
+            AccountId,
+        },
+        assets::FungibleAsset,
+        crypto::rand::RpoRandomCoin,
+        notes::{NoteId, NoteType},
+        Digest, ZERO,
+    };
+
+    use super::TransactionRequest;
+    use crate::errors::TransactionRequestError;
+
+    #[test]
+    fn mint_requires_matching_faucet() {
+        let mut rng = RpoRandomCoin::new([ZERO; 4]);
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let wallet_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let target_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap();
+        let result = TransactionRequest::builder(wallet_id).mint(
+            target_id,
+            asset,
+            NoteType::Public,
+            &mut rng,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            TransactionRequestError::MintFromNonFaucetAccount(wallet_id)
+        );
+
+        let result = TransactionRequest::builder(other_faucet_id).mint(
+            target_id,
+            asset,
+            NoteType::Public,
+            &mut rng,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            TransactionRequestError::InconsistentFaucetId {
+                expected: other_faucet_id,
+                actual: faucet_id
+            }
+        );
+
+        let request = TransactionRequest::builder(faucet_id)
+            .mint(target_id, asset, NoteType::Public, &mut rng)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.expected_output_notes().len(), 1);
+        assert_eq!(request.expected_output_notes()[0].metadata().sender(), faucet_id);
+    }
+
+    #[test]
+    fn duplicate_input_notes_are_rejected() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let note_id = NoteId::new(Digest::default(), Digest::default());
+
+        let result = TransactionRequest::builder(account_id)
+            .consume_notes([note_id])
+            .consume_note_with_args(note_id, [ZERO; 4])
+            .build();
+        assert_eq!(result.unwrap_err(), TransactionRequestError::DuplicateInputNote(note_id));
+    }
+}
@@ -12,24 +12,24 @@ use miden_objects::{
         AuthSecretKey,
     },
     assets::{Asset, FungibleAsset, TokenSymbol},
-    block::{compute_tx_hash, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree, NoteBatch},
+    block::{
+        compute_tx_hash, AccountTree, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree,
+        NoteBatch, NullifierTree,
+    },
     crypto::{
         dsa::rpo_falcon512::SecretKey,
-        merkle::{Mmr, MmrError, PartialMmr, Smt},
+        merkle::{Mmr, MmrError, PartialMmr},
     },
     notes::{Note, NoteId, NoteInclusionProof, NoteType, Nullifier},
     transaction::{
         ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
         TransactionId, TransactionInputs,
     },
-    AccountError, BlockHeader, FieldElement, NoteError, ACCOUNT_TREE_DEPTH,
+    AccountError, BlockHeader, FieldElement, NoteError,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::{
-    crypto::{RpoRandomCoin, SimpleSmt},
-    Digest, Felt, Word, ZERO,
-};
+use vm_processor::{crypto::RpoRandomCoin, Digest, Felt, Word};
 
 use super::TransactionContextBuilder;
 use crate::auth::BasicAuthenticator;
@@ -191,10 +191,10 @@ pub struct MockChain {
     blocks: Vec<Block>,
 
     /// Tree containing the latest `Nullifier`'s tree.
-    nullifiers: Smt,
+    nullifiers: NullifierTree,
 
     /// Tree containing the latest hash of each account.
-    accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    accounts: AccountTree,
 
     /// Objects that have not yet been finalized.
     ///
@@ -227,8 +227,8 @@ impl MockChain {
         Self {
             chain: Mmr::default(),
             blocks: vec![],
-            nullifiers: Smt::default(),
-            accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
+            nullifiers: NullifierTree::new(),
+            accounts: AccountTree::new(),
             pending_objects: PendingObjects::new(),
             available_notes: BTreeMap::new(),
             available_accounts: BTreeMap::new(),
@@ -237,7 +237,11 @@ impl MockChain {
     }
 
     pub fn add_executed_transaction(&mut self, transaction: ExecutedTransaction) {
-        let mut account = transaction.initial_account().clone();
+        let mut account = transaction
+            .initial_account()
+            .full_account()
+            .expect("mock chain requires transactions against full accounts")
+            .clone();
         account.apply_delta(transaction.account_delta()).unwrap();
 
         // disregard private accounts, so it's easier to retrieve data
@@ -465,6 +469,9 @@ impl MockChain {
     ///
     /// This will also make all the objects currently pending available for use.
     /// If `block_num` is `Some(number)`, `number` will be used as the new block's number
+    ///
+    /// # Panics
+    /// Panics if nullifiers are pending when the genesis block is sealed.
     pub fn seal_block(&mut self, block_num: Option<u32>) -> Block {
         let next_block_num = self.blocks.last().map_or(0, |b| b.header().block_num() + 1);
         let block_num: u32 = if let Some(input_block_num) = block_num {
@@ -477,7 +484,8 @@ impl MockChain {
         };
 
         for update in self.pending_objects.updated_accounts.iter() {
-            self.accounts.insert(update.account_id().into(), *update.new_state_hash());
+            self.accounts
+                .apply_updates([(update.account_id(), Digest::from(*update.new_state_hash()))]);
 
             if let Some(mock_account) = self.available_accounts.get(&update.account_id()) {
                 let account = match update.details() {
@@ -499,9 +507,9 @@ impl MockChain {
         // - resetting the nullifier tree once defined at the protocol level.
         // - inserting only nullifier from transactions included in the batches, once the batch
         // kernel has been implemented.
-        for nullifier in self.pending_objects.created_nullifiers.iter() {
-            self.nullifiers.insert(nullifier.inner(), [block_num.into(), ZERO, ZERO, ZERO]);
-        }
+        self.nullifiers
+            .apply_updates(self.pending_objects.created_nullifiers.iter().copied(), block_num)
+            .expect("nullifiers should not be created in the genesis block");
         let notes_tree = self.pending_objects.build_note_tree();
 
         let version = 0;
@@ -602,7 +610,7 @@ impl MockChain {
     }
 
     /// Get a reference to the nullifier tree.
-    pub fn nullifiers(&self) -> &Smt {
+    pub fn nullifiers(&self) -> &NullifierTree {
         &self.nullifiers
     }
 
@@ -612,7 +620,7 @@ impl MockChain {
     }
 
     /// Get the reference to the accounts hash tree.
-    pub fn accounts(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
+    pub fn accounts(&self) -> &AccountTree {
         &self.accounts
     }
 }
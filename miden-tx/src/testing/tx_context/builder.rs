@@ -11,7 +11,7 @@ use miden_objects::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
         },
-        Account, AccountId,
+        Account, AccountId, PartialAccount,
     },
     assembly::Assembler,
     assets::{Asset, FungibleAsset, NonFungibleAsset},
@@ -25,7 +25,9 @@ use miden_objects::{
         prepare_word,
         storage::prepare_assets,
     },
-    transaction::{OutputNote, TransactionArgs, TransactionScript},
+    transaction::{
+        ForeignAccountInputs, OutputNote, TransactionArgs, TransactionInputs, TransactionScript,
+    },
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
@@ -47,6 +49,8 @@ pub struct TransactionContextBuilder {
     note_args: BTreeMap<NoteId, Word>,
     rng: ChaCha20Rng,
     mock_chain: Option<MockChain>,
+    foreign_accounts: Vec<Account>,
+    partial_account: bool,
 }
 
 impl TransactionContextBuilder {
@@ -64,6 +68,8 @@ impl TransactionContextBuilder {
             advice_inputs: Default::default(),
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
+            partial_account: false,
         }
     }
 
@@ -90,6 +96,8 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
+            partial_account: false,
         }
     }
 
@@ -115,6 +123,8 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
+            partial_account: false,
         }
     }
 
@@ -140,6 +150,8 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
+            partial_account: false,
         }
     }
 
@@ -163,6 +175,22 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Adds the provided accounts to the set of foreign accounts available to the transaction.
+    ///
+    /// If a mock chain is provided via [Self::mock_chain()], the accounts must be present in its
+    /// account tree. Otherwise, the accounts are added to the mock chain created by the builder.
+    pub fn foreign_accounts(mut self, foreign_accounts: Vec<Account>) -> Self {
+        self.foreign_accounts.extend(foreign_accounts);
+        self
+    }
+
+    /// Provides the account to the transaction as a [PartialAccount] without any storage map or
+    /// vault proofs, so that all storage map entries and vault assets are loaded on demand.
+    pub fn partial_account(mut self) -> Self {
+        self.partial_account = true;
+        self
+    }
+
     pub fn tx_script(mut self, tx_script: TransactionScript) -> Self {
         self.tx_script = Some(tx_script);
         self
@@ -563,7 +591,10 @@ impl TransactionContextBuilder {
         let mut mock_chain = if let Some(mock_chain) = self.mock_chain {
             mock_chain
         } else {
-            MockChainBuilder::default().notes(self.input_notes.clone()).build()
+            MockChainBuilder::default()
+                .accounts(self.foreign_accounts.clone())
+                .notes(self.input_notes.clone())
+                .build()
         };
 
         for _ in 0..4 {
@@ -580,15 +611,37 @@ impl TransactionContextBuilder {
         let input_note_ids: Vec<NoteId> =
             mock_chain.available_notes().iter().map(|n| n.id()).collect();
 
-        let tx_inputs = mock_chain.get_transaction_inputs(
+        let mut tx_inputs = mock_chain.get_transaction_inputs(
             self.account.clone(),
             self.account_seed,
             &input_note_ids,
         );
+        if self.partial_account {
+            let (_, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+            tx_inputs = TransactionInputs::new(
+                PartialAccount::from(&self.account),
+                account_seed,
+                block_header,
+                block_chain,
+                input_notes,
+            )
+            .expect("invalid partial account inputs");
+        }
 
         tx_args.extend_expected_output_notes(self.expected_output_notes.clone());
 
+        let foreign_account_inputs: BTreeMap<AccountId, ForeignAccountInputs> = self
+            .foreign_accounts
+            .iter()
+            .map(|account| {
+                let account_proof = mock_chain.accounts().open(account.id()).path().clone();
+                (account.id(), ForeignAccountInputs::from_account(account, account_proof))
+            })
+            .collect();
+        let tx_args = tx_args.with_foreign_accounts(foreign_account_inputs.keys().copied());
+
         TransactionContext {
+            account: self.account,
             mock_chain,
             expected_output_notes: self.expected_output_notes,
             tx_args,
@@ -596,6 +649,7 @@ impl TransactionContextBuilder {
             authenticator: self.authenticator,
             advice_inputs: self.advice_inputs,
             assembler: self.assembler,
+            foreign_account_inputs,
         }
     }
 
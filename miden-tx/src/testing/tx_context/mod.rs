@@ -1,13 +1,18 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
-use alloc::{rc::Rc, sync::Arc, vec::Vec};
+use alloc::{collections::BTreeMap, rc::Rc, sync::Arc, vec::Vec};
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    accounts::{Account, AccountId},
+    accounts::{Account, AccountId, StorageSlot},
     assembly::Assembler,
+    crypto::merkle::SmtProof,
     notes::{Note, NoteId},
-    transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs, TransactionInputs},
+    transaction::{
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes,
+        TransactionArgs, TransactionInputs,
+    },
+    BlockHeader, Digest, Felt, Word, ZERO,
 };
 use vm_processor::{AdviceInputs, ExecutionError, Process};
 use winter_maybe_async::*;
@@ -18,8 +23,8 @@ use super::{
     MockHost,
 };
 use crate::{
-    auth::TransactionAuthenticator, DataStore, DataStoreError, TransactionExecutor,
-    TransactionExecutorError, TransactionMastStore,
+    auth::TransactionAuthenticator, DataStore, DataStoreBackend, DataStoreError, LazyDataStore,
+    TransactionExecutor, TransactionExecutorError, TransactionMastStore,
 };
 
 mod builder;
@@ -31,9 +36,10 @@ pub use builder::TransactionContextBuilder;
 #[derive(Clone)]
 /// Represents all needed data for executing a transaction, or arbitrary code.
 ///
-/// It implements [DataStore], so transactions may be executed with
-/// [TransactionExecutor](crate::TransactionExecutor)
+/// It implements [DataStore], [DataStoreBackend] and [LazyDataStore], so transactions may be
+/// executed with [TransactionExecutor](crate::TransactionExecutor)
 pub struct TransactionContext {
+    account: Account,
     mock_chain: MockChain,
     expected_output_notes: Vec<Note>,
     tx_args: TransactionArgs,
@@ -41,6 +47,7 @@ pub struct TransactionContext {
     advice_inputs: AdviceInputs,
     authenticator: Option<MockAuthenticator>,
     assembler: Assembler,
+    foreign_account_inputs: BTreeMap<AccountId, ForeignAccountInputs>,
 }
 
 impl TransactionContext {
@@ -86,14 +93,16 @@ impl TransactionContext {
         let authenticator = self
             .authenticator
             .map(|auth| Arc::new(auth) as Arc<dyn TransactionAuthenticator>);
-        let tx_executor = TransactionExecutor::new(Arc::new(mock_data_store), authenticator);
+        let mock_data_store = Arc::new(mock_data_store);
+        let tx_executor = TransactionExecutor::new(mock_data_store.clone(), authenticator)
+            .with_lazy_data_store(mock_data_store);
         let notes: Vec<NoteId> = self.tx_inputs.input_notes().into_iter().map(|n| n.id()).collect();
 
         maybe_await!(tx_executor.execute_transaction(account_id, block_num, &notes, self.tx_args))
     }
 
     pub fn account(&self) -> &Account {
-        self.tx_inputs.account()
+        &self.account
     }
 
     pub fn expected_output_notes(&self) -> &[Note] {
@@ -139,4 +148,114 @@ impl DataStore for TransactionContext {
 
         Ok(self.tx_inputs.clone())
     }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        self.foreign_account_inputs
+            .get(&account_id)
+            .cloned()
+            .ok_or(DataStoreError::AccountNotFound(account_id))
+    }
+}
+
+impl LazyDataStore for TransactionContext {
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        slot_index: u8,
+        leaf_index: u64,
+    ) -> Result<SmtProof, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+
+        match self.account.storage().slots().get(slot_index as usize) {
+            Some(StorageSlot::Map(map)) => Ok(map.open(&leaf_key(leaf_index))),
+            _ => Err(DataStoreError::InternalError(format!(
+                "storage slot {slot_index} is not a storage map"
+            ))),
+        }
+    }
+
+    fn get_vault_witness(
+        &self,
+        account_id: AccountId,
+        leaf_index: u64,
+    ) -> Result<SmtProof, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+
+        Ok(self.account.vault().asset_tree().open(&leaf_key(leaf_index)))
+    }
+}
+
+/// Returns a key which maps to the SMT leaf with the specified index.
+fn leaf_key(leaf_index: u64) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, Felt::new(leaf_index)])
+}
+
+#[maybe_async_trait]
+impl DataStoreBackend for TransactionContext {
+    #[maybe_async]
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        assert_eq!(account_id, self.tx_inputs.account().id());
+
+        Ok((self.account.clone(), self.tx_inputs.account_seed()))
+    }
+
+    #[maybe_async]
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        if block_num == self.tx_inputs.block_header().block_num() {
+            return Ok(*self.tx_inputs.block_header());
+        }
+
+        self.tx_inputs
+            .block_chain()
+            .get_block(block_num)
+            .copied()
+            .ok_or(DataStoreError::BlockNotFound(block_num))
+    }
+
+    #[maybe_async]
+    fn get_chain_mmr(&self, block_ref: u32, blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        let chain_mmr = self.tx_inputs.block_chain();
+        match blocks.iter().find(|block_num| !chain_mmr.contains_block(**block_num)) {
+            Some(block_num) => Err(DataStoreError::BlockNotFound(*block_num)),
+            None => Ok(chain_mmr.clone()),
+        }
+    }
+
+    #[maybe_async]
+    fn get_input_note(&self, note_id: NoteId, block_ref: u32) -> Result<InputNote, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        self.tx_inputs
+            .input_notes()
+            .iter()
+            .find(|note| note.id() == note_id)
+            .cloned()
+            .ok_or(DataStoreError::NoteNotFound(note_id))
+    }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        self.foreign_account_inputs
+            .get(&account_id)
+            .cloned()
+            .ok_or(DataStoreError::AccountNotFound(account_id))
+    }
 }
@@ -28,6 +28,7 @@ use miden_objects::{
         AccountStorage, AccountType, StorageSlot,
     },
     assets::{AssetVault, NonFungibleAsset},
+    crypto::merkle::MerkleStore,
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
         NoteType,
@@ -933,6 +934,17 @@ fn get_mock_advice_inputs(foreign_account: &Account, mock_chain: &MockChain) ->
     let foreign_storage_root = foreign_account.storage().commitment();
     let foreign_code_root = foreign_account.code().commitment();
 
+    // the merkle store only needs the path from the account tree root to the foreign account leaf
+    let account_witness = mock_chain.accounts().open(foreign_account.id());
+    let mut merkle_store = MerkleStore::new();
+    merkle_store
+        .add_merkle_path(
+            u64::from(foreign_account.id()),
+            account_witness.state_hash().expect("foreign account should be on chain"),
+            account_witness.path().clone(),
+        )
+        .unwrap();
+
     AdviceInputs::default()
         .with_map([
             // ACCOUNT_ID |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_ROOT, CODE_ROOT]
@@ -951,7 +963,7 @@ fn get_mock_advice_inputs(foreign_account: &Account, mock_chain: &MockChain) ->
             // CODE_ROOT |-> [[ACCOUNT_PROCEDURE_DATA]]
             (foreign_code_root, foreign_account.code().as_elements()),
         ])
-        .with_merkle_store(mock_chain.accounts().into())
+        .with_merkle_store(merkle_store)
 }
 
 fn foreign_account_data_memory_assertions(foreign_account: &Account, process: &Process<MockHost>) {
@@ -9,7 +9,9 @@ use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    errors::tx_kernel_errors::ERR_ACCOUNT_EVENT_ID_OUT_OF_RANGE, transaction::TransactionKernel,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
@@ -17,37 +19,51 @@ use miden_objects::{
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         },
-        AccountBuilder, AccountCode, AccountComponent, AccountStorage, AccountType, StorageSlot,
+        Account, AccountBuilder, AccountCode, AccountComponent, AccountHeader, AccountId,
+        AccountStorage, AccountType, StorageSlot,
     },
     assembly::DefaultSourceManager,
     assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    block::{AccountTree, NullifierTree},
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType, Nullifier,
     },
     testing::{
         account_component::AccountMockComponent,
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
         notes::DEFAULT_NOTE_CODE,
         prepare_word,
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
+    },
+    transaction::{
+        AccountEvent, ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, OutputNotes,
+        ProvenTransaction, ProvenTransactionBuilder, TransactionArgs, TransactionMeasurements,
+        TransactionScript, TransactionWitness,
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
-    Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+    BlockHeader, Felt, TransactionInputError, Word, MIN_PROOF_SECURITY_LEVEL,
 };
 use miden_prover::ProvingOptions;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
-    utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    utils::{ByteWriter, Deserializable, Serializable},
+    Digest, ExecutionError, MemAdviceProvider, ONE,
 };
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    prune_witness, LocalTransactionProver, TransactionExecutor, TransactionHost,
+    TransactionProgress, TransactionProver, TransactionVerifier,
+};
+use crate::{
+    testing::{
+        mock_chain::{Auth, MockChainBuilder},
+        TransactionContext, TransactionContextBuilder,
+    },
+    CachingDataStore, DataStore, DataStoreBackend, DataStoreError, OutputNoteMismatch,
+    TransactionCrossCheckError, TransactionExecutorError, TransactionMastStore,
+    TransactionReExecutor,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
@@ -107,12 +123,14 @@ fn transaction_executor_witness() {
     )
     .unwrap();
 
-    let (advice_provider, _, output_notes, _signatures, _tx_progress) = host.into_parts();
+    let (advice_provider, _, output_notes, account_events, _signatures, _tx_progress, _) =
+        host.into_parts();
     let (_, map, _) = advice_provider.into_parts();
     let tx_outputs = TransactionKernel::from_transaction_parts(
         result.stack_outputs(),
         &map.into(),
         output_notes,
+        account_events,
     )
     .unwrap();
 
@@ -120,6 +138,264 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_reexecutor_cross_check() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert!(executed_transaction.output_notes().num_notes() > 0);
+
+    // an honestly executed transaction should pass the cross-check
+    let reexecutor = TransactionReExecutor::new();
+    reexecutor.cross_check(&executed_transaction).unwrap();
+
+    // tamper with the final account nonce and drop the last output note
+    let (account_delta, mut tx_outputs, tx_witness, tx_measurements) =
+        executed_transaction.clone().into_parts();
+    let final_account = tx_outputs.account.clone();
+    tx_outputs.account = AccountHeader::new(
+        final_account.id(),
+        final_account.nonce() + ONE,
+        final_account.vault_root(),
+        final_account.storage_commitment(),
+        final_account.code_commitment(),
+    );
+    let mut output_notes = tx_outputs.output_notes.iter().cloned().collect::<Vec<_>>();
+    let dropped_note = output_notes.pop().unwrap();
+    tx_outputs.output_notes = OutputNotes::new(output_notes).unwrap();
+
+    let tampered_transaction = ExecutedTransaction::new(
+        tx_witness.tx_inputs,
+        tx_outputs,
+        tx_witness.account_codes,
+        account_delta,
+        tx_witness.tx_args,
+        tx_witness.advice_witness,
+        tx_measurements,
+    );
+
+    let report = match reexecutor.cross_check(&tampered_transaction) {
+        Err(TransactionCrossCheckError::Mismatch(report)) => report,
+        other => panic!("expected a mismatch report, got {other:?}"),
+    };
+
+    let account_mismatch = report.final_account.unwrap();
+    assert_eq!(&account_mismatch.actual, executed_transaction.final_account());
+    assert!(report.account_delta.is_none());
+    assert_eq!(
+        report.output_notes,
+        vec![OutputNoteMismatch {
+            index: executed_transaction.output_notes().num_notes() - 1,
+            claimed: None,
+            actual: Some(dropped_note),
+        }]
+    );
+}
+
+#[test]
+fn executed_transaction_serde() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    let bytes = executed_transaction.to_bytes();
+    let deserialized = ExecutedTransaction::read_from_bytes(&bytes).unwrap();
+
+    assert_eq!(deserialized.id(), executed_transaction.id());
+    assert_eq!(deserialized.final_account(), executed_transaction.final_account());
+    assert_eq!(deserialized.account_delta(), executed_transaction.account_delta());
+    assert_eq!(deserialized.output_notes(), executed_transaction.output_notes());
+    assert_eq!(deserialized.advice_witness(), executed_transaction.advice_witness());
+    assert_eq!(deserialized.measurements(), executed_transaction.measurements());
+    assert_eq!(deserialized.to_bytes(), bytes);
+
+    let measurements = executed_transaction.measurements();
+    assert_eq!(
+        &TransactionMeasurements::read_from_bytes(&measurements.to_bytes()).unwrap(),
+        measurements
+    );
+}
+
+#[test]
+fn transaction_progress_serde() {
+    let note_id = NoteId::new(Digest::default(), Digest::default());
+
+    let mut tx_progress = TransactionProgress::default();
+    tx_progress.start_prologue(0.into());
+    tx_progress.end_prologue(100.into());
+    tx_progress.start_notes_processing(100.into());
+    tx_progress.start_note_execution(110.into(), note_id);
+    tx_progress.end_note_execution(350.into());
+    tx_progress.end_notes_processing(360.into());
+    tx_progress.start_epilogue(400.into());
+
+    let deserialized = TransactionProgress::read_from_bytes(&tx_progress.to_bytes()).unwrap();
+    assert_eq!(deserialized, tx_progress);
+    assert_eq!(TransactionMeasurements::from(deserialized), tx_progress.into());
+
+    // a crafted number of note execution entries must not trigger a huge allocation
+    let empty_progress = TransactionProgress::default().to_bytes();
+    let mut bytes = empty_progress[..4].to_vec();
+    bytes.write_usize(usize::MAX);
+    bytes.extend_from_slice(&empty_progress[5..]);
+    assert!(TransactionProgress::read_from_bytes(&bytes).is_err());
+
+    let empty_measurements =
+        TransactionMeasurements::from(TransactionProgress::default()).to_bytes();
+    let mut bytes = empty_measurements[..2].to_vec();
+    bytes.write_usize(usize::MAX);
+    bytes.extend_from_slice(&empty_measurements[3..]);
+    assert!(TransactionMeasurements::read_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn caching_data_store() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    // a context for the same account and notes against a later reference block
+    let later_tx_context = TransactionContextBuilder::new(tx_context.account().clone())
+        .mock_chain(tx_context.mock_chain().clone())
+        .build();
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let later_block_ref = later_tx_context.tx_inputs().block_header().block_num();
+    assert!(later_block_ref > block_ref);
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    let num_notes = note_ids.len() as u64;
+    let tx_args = tx_context.tx_args().clone();
+    let later_tx_inputs = later_tx_context.tx_inputs().clone();
+
+    let backend = ReferenceBlockBackend(BTreeMap::from([
+        (block_ref, tx_context),
+        (later_block_ref, later_tx_context),
+    ]));
+    let data_store = Arc::new(CachingDataStore::new(backend));
+    let executor = TransactionExecutor::new(data_store.clone(), None);
+
+    // the first execution populates the cache
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    let metrics = data_store.metrics();
+    assert_eq!(metrics.hits(), 0);
+    assert_eq!(metrics.note_misses, num_notes);
+    assert_eq!(metrics.block_header_misses, 1);
+    assert_eq!(metrics.chain_mmr_misses, 1);
+
+    // the second execution against the same reference block is served from the cache
+    let inputs = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    assert_eq!(&inputs, executed_transaction.tx_inputs());
+    let metrics = data_store.metrics();
+    assert_eq!(metrics.note_hits, num_notes);
+    assert_eq!(metrics.block_header_hits, 1);
+    assert_eq!(metrics.chain_mmr_hits, 1);
+    assert_eq!(metrics.misses(), num_notes + 2);
+
+    // changing the reference block invalidates the cached notes and chain MMR
+    let inputs = data_store
+        .get_transaction_inputs(account_id, later_block_ref, &note_ids)
+        .unwrap();
+    assert_eq!(inputs, later_tx_inputs);
+    let metrics = data_store.metrics();
+    assert_eq!(metrics.hits(), num_notes + 2);
+    assert_eq!(metrics.note_misses, 2 * num_notes);
+    assert_eq!(metrics.block_header_misses, 2);
+    assert_eq!(metrics.chain_mmr_misses, 2);
+
+    // block headers do not depend on the reference block, so they stay cached
+    data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let metrics = data_store.metrics();
+    assert_eq!(metrics.block_header_hits, 2);
+    assert_eq!(metrics.note_misses, 3 * num_notes);
+    assert_eq!(metrics.chain_mmr_misses, 3);
+
+    // clearing the cache forces the data to be fetched again
+    data_store.clear();
+    data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    let metrics = data_store.metrics();
+    assert_eq!(metrics.misses(), 4 * num_notes + 7);
+}
+
+/// Data store backend which serves transaction contexts built against different reference blocks.
+struct ReferenceBlockBackend(BTreeMap<u32, TransactionContext>);
+
+impl ReferenceBlockBackend {
+    fn context(&self, block_ref: u32) -> Result<&TransactionContext, DataStoreError> {
+        self.0.get(&block_ref).ok_or(DataStoreError::BlockNotFound(block_ref))
+    }
+}
+
+impl DataStoreBackend for ReferenceBlockBackend {
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        let tx_context = self.0.values().next().expect("backend has no transaction contexts");
+        DataStoreBackend::get_account(tx_context, account_id)
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        DataStoreBackend::get_block_header(self.context(block_num)?, block_num)
+    }
+
+    fn get_chain_mmr(&self, block_ref: u32, blocks: &[u32]) -> Result<ChainMmr, DataStoreError> {
+        DataStoreBackend::get_chain_mmr(self.context(block_ref)?, block_ref, blocks)
+    }
+
+    fn get_input_note(&self, note_id: NoteId, block_ref: u32) -> Result<InputNote, DataStoreError> {
+        DataStoreBackend::get_input_note(self.context(block_ref)?, note_id, block_ref)
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        DataStoreBackend::get_foreign_account_inputs(
+            self.context(block_ref)?,
+            account_id,
+            block_ref,
+        )
+    }
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -845,6 +1121,63 @@ fn prove_witness_and_verify() {
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
+#[test]
+fn prove_and_verify_batch() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executed_transaction = tx_context.execute().unwrap();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+
+    // a transaction with a dummy proof must fail without affecting the other transactions
+    let invalid_transaction = ProvenTransactionBuilder::mock(
+        proven_transaction.account_id(),
+        proven_transaction.account_update().init_state_hash(),
+        proven_transaction.account_update().final_state_hash(),
+        proven_transaction.block_ref(),
+        proven_transaction.expiration_block_num(),
+    )
+    .account_update_details(proven_transaction.account_update().details().clone())
+    .build()
+    .unwrap();
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let results = verifier.verify_batch(&[
+        proven_transaction.clone(),
+        invalid_transaction,
+        proven_transaction,
+    ]);
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn prove_pruned_witness_and_verify() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executed_transaction = tx_context.execute().unwrap();
+    let executed_transaction_id = executed_transaction.id();
+
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    let pruned_witness = prune_witness(tx_witness.clone());
+    assert!(pruned_witness.tx_inputs.account().full_account().is_none());
+    assert!(pruned_witness.advice_witness.stack().is_empty());
+    assert!(pruned_witness.to_bytes().len() < tx_witness.to_bytes().len());
+
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(pruned_witness).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -1003,3 +1336,289 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account nonce should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(4));
 }
+
+/// Tests that user-defined events emitted by the account code are captured by the transaction host
+/// and included into the executed transaction in the order of emission.
+#[test]
+fn executed_transaction_account_events() {
+    const ACCOUNT_COMPONENT_CODE: &str = "
+      use.miden::tx
+
+      export.emit_transfer_events
+        push.1.2.3.4 push.196609 exec.tx::emit_event # event ID 0x30001
+        push.5.6.7.8 push.196610 exec.tx::emit_event # event ID 0x30002
+      end
+
+      export.emit_invalid_event
+        push.1.2.3.4 push.131073 exec.tx::emit_event # event ID 0x20001
+      end";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let account_component_module = Module::parser(ModuleKind::Library)
+        .parse_str(
+            LibraryPath::new("account_component::events").unwrap(),
+            ACCOUNT_COMPONENT_CODE,
+            &source_manager,
+        )
+        .unwrap();
+    let account_component_lib = TransactionKernel::assembler()
+        .assemble_library([account_component_module])
+        .unwrap();
+
+    let account_component =
+        AccountComponent::new(account_component_lib.clone(), vec![StorageSlot::empty_value()])
+            .unwrap()
+            .with_supports_all_types();
+
+    let (native_account, seed) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(account_component)
+        .build()
+        .unwrap();
+
+    let tx_context =
+        TransactionContextBuilder::new(native_account).account_seed(Some(seed)).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let execute_script = |script_src: &str| {
+        let tx_script = TransactionScript::compile(
+            script_src,
+            [],
+            TransactionKernel::assembler().with_library(&account_component_lib).unwrap(),
+        )
+        .unwrap();
+        let tx_args = TransactionArgs::new(
+            Some(tx_script),
+            None,
+            tx_context.tx_args().advice_inputs().clone().map,
+        );
+
+        executor.execute_transaction(account_id, block_ref, &[], tx_args)
+    };
+
+    let executed_tx = execute_script(
+        "use.account_component::events
+        begin
+            call.events::emit_transfer_events
+        end",
+    )
+    .unwrap();
+
+    let expected_events = [
+        AccountEvent::new(0x30001, [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        AccountEvent::new(0x30002, [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+    ];
+    assert_eq!(executed_tx.account_events(), &expected_events);
+
+    // event IDs outside of the account event range must be rejected by the kernel
+    let result = execute_script(
+        "use.account_component::events
+        begin
+            call.events::emit_invalid_event
+        end",
+    );
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_ACCOUNT_EVENT_ID_OUT_OF_RANGE
+    ));
+}
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+#[test]
+fn transaction_executor_foreign_account_inputs() {
+    let (foreign_account, _) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![AccountStorage::mock_item_2().slot],
+            )
+            .unwrap(),
+        )
+        .nonce(ONE)
+        .build_testing()
+        .unwrap();
+    let foreign_account_id = foreign_account.id();
+
+    let tx_script_src = format!(
+        "
+        use.std::sys
+
+        use.miden::account
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw push.0.0
+            # => [pad(10)]
+
+            # push the key and the index of the desired storage map item
+            push.{map_key} push.0
+
+            # get the hash of the `get_map_item` account procedure
+            procref.account::get_map_item
+
+            # push the foreign account id
+            push.{foreign_account_id}
+            # => [foreign_account_id, FOREIGN_PROC_ROOT, storage_item_index, MAP_ITEM_KEY, pad(10)]
+
+            exec.tx::execute_foreign_procedure
+            # => [MAP_VALUE]
+
+            push.{map_value} assert_eqw
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        map_key = STORAGE_LEAVES_2[0].0,
+        map_value = prepare_word(&STORAGE_LEAVES_2[0].1),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = TransactionArgs::with_tx_script(tx_script.clone())
+        .with_foreign_accounts([foreign_account_id]);
+
+    // the foreign account inputs are fetched from the data store and loaded by the executor
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &[], tx_args.clone())
+        .unwrap();
+
+    // the foreign account data must be part of the transaction witness
+    TransactionReExecutor::new().cross_check(&executed_transaction).unwrap();
+
+    // foreign account inputs which are not authenticated by the reference block are rejected
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account])
+        .mock_chain(MockChainBuilder::default().build())
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let result = executor.execute_transaction(account_id, block_ref, &[], tx_args);
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionExecutorError::InvalidForeignAccountInputs(
+            TransactionInputError::ForeignAccountNotInAccountTree(foreign_account_id)
+        )
+    );
+}
+
+// PARTIAL ACCOUNT TESTS
+// ================================================================================================
+
+#[test]
+fn transaction_executor_partial_account() {
+    let updated_map_key = [Felt::new(14), Felt::new(15), Felt::new(16), Felt::new(17)];
+    let updated_map_value = [Felt::new(18), Felt::new(19), Felt::new(20), Felt::new(21)];
+
+    let tx_script_src = format!(
+        "
+        use.std::sys
+
+        use.test::account
+
+        begin
+            # read an existing entry of the storage map
+            push.{MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::get_map_item
+            push.{MAP_VALUE} assert_eqw
+
+            # insert a new entry into the storage map
+            push.{UPDATED_MAP_VALUE} push.{UPDATED_MAP_KEY} push.{STORAGE_INDEX_2}
+            call.account::set_map_item dropw dropw dropw
+
+            push.1 call.account::incr_nonce drop
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        MAP_KEY = STORAGE_LEAVES_2[0].0,
+        MAP_VALUE = prepare_word(&STORAGE_LEAVES_2[0].1),
+        UPDATED_MAP_KEY = prepare_word(&updated_map_key),
+        UPDATED_MAP_VALUE = prepare_word(&updated_map_value),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let full_tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .tx_script(tx_script.clone())
+        .build();
+    let partial_tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .tx_script(tx_script)
+        .partial_account()
+        .build();
+
+    // the storage map entries and vault assets of the partial account are loaded on demand, so the
+    // results must be the same as for the full account
+    let full_tx = full_tx_context.execute().unwrap();
+    let partial_tx = partial_tx_context.clone().execute().unwrap();
+
+    assert!(partial_tx.initial_account().full_account().is_none());
+    assert_eq!(partial_tx.final_account(), full_tx.final_account());
+    assert_eq!(partial_tx.account_delta(), full_tx.account_delta());
+    assert_eq!(partial_tx.output_notes(), full_tx.output_notes());
+
+    // the data loaded on demand must be part of the transaction witness
+    TransactionReExecutor::new().cross_check(&partial_tx).unwrap();
+
+    // without a lazy data store, the missing account data cannot be loaded
+    let account_id = partial_tx_context.account().id();
+    let block_ref = partial_tx_context.tx_inputs().block_header().block_num();
+    let notes: Vec<NoteId> = partial_tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect();
+    let tx_args = partial_tx_context.tx_args().clone();
+    let executor = TransactionExecutor::new(Arc::new(partial_tx_context), None);
+
+    let result = executor.execute_transaction(account_id, block_ref, &notes, tx_args);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(_))
+    ));
+}
+
+// MOCK CHAIN TESTS
+// ================================================================================================
+
+#[test]
+fn mock_chain_roots_match_typed_trees() {
+    let nullifier = Nullifier::from(Digest::from([ONE, ONE, ONE, ONE]));
+    let mut mock_chain = MockChainBuilder::default().build();
+    let account = mock_chain.add_existing_wallet(Auth::NoAuth, vec![]);
+    mock_chain.add_nullifier(nullifier);
+    let block = mock_chain.seal_block(Some(3));
+
+    let nullifier_tree = NullifierTree::with_entries([(nullifier, 3)]).unwrap();
+    assert_eq!(block.header().nullifier_root(), nullifier_tree.root());
+    assert_eq!(mock_chain.nullifiers().get_block_num(&nullifier), Some(3));
+
+    let account_tree = AccountTree::with_entries([(account.id(), account.hash())]).unwrap();
+    assert_eq!(block.header().account_root(), account_tree.root());
+}
@@ -1,9 +1,17 @@
+use alloc::vec::Vec;
+
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
 
+mod receipt;
+pub use receipt::verify_receipt;
+
+mod stateful;
+pub use stateful::{ChainView, StatefulTransactionVerifier};
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
@@ -31,6 +39,36 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_transaction(&transaction)
+    }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel and returns the
+    /// verification result of each transaction, in the same order as the transactions.
+    ///
+    /// The program info of the transaction kernel is shared across all verifications. When the
+    /// `concurrent` feature is enabled, the transactions are verified in parallel.
+    pub fn verify_batch(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Vec<Result<(), TransactionVerifierError>> {
+        #[cfg(feature = "concurrent")]
+        {
+            use rayon::prelude::*;
+            transactions.par_iter().map(|tx| self.verify_transaction(tx)).collect()
+        }
+
+        #[cfg(not(feature = "concurrent"))]
+        transactions.iter().map(|tx| self.verify_transaction(tx)).collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel.
+    fn verify_transaction(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -0,0 +1,484 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use core::cmp::Ordering;
+
+use miden_objects::{
+    accounts::{AccountHeader, AccountStorageHeader, NonFungibleDeltaAction, StorageSlotType},
+    assets::{Asset, FungibleAsset},
+    crypto::merkle::{LeafIndex, PartialMerkleTree, SmtLeaf, SmtProof, SMT_DEPTH},
+    transaction::{ProvenTransaction, TransactionReceipt},
+    Digest, Word, EMPTY_WORD, ZERO,
+};
+
+use crate::errors::TransactionReceiptError;
+
+// RECEIPT VERIFICATION
+// ================================================================================================
+
+/// Verifies that the provided [TransactionReceipt] correctly describes the changes which the
+/// provided [ProvenTransaction] made to its account.
+///
+/// The receipt is verified by applying its account delta to the disclosed initial state of the
+/// account, and checking that the resulting state matches the disclosed final state. The initial
+/// and final account headers are in turn checked against the account state hashes committed to by
+/// the transaction. Thus, a successfully verified receipt proves that the transaction updated the
+/// account exactly as described by the delta, assuming that the transaction proof itself has
+/// been verified.
+///
+/// Receipts of transactions which create an account are not supported: the initial state hash of
+/// such transactions is the empty digest, so the disclosed initial state of the account would not
+/// be bound to the transaction.
+///
+/// # Errors
+/// Returns an error if:
+/// - The receipt was issued for a different transaction or account.
+/// - The initial account header describes a new account, i.e., its nonce is zero.
+/// - The initial or final account headers do not match the account state hashes of the transaction.
+/// - The account code or nonce of the final account header is inconsistent with the delta.
+/// - The receipt does not contain an opening for a storage map entry or a vault asset updated by
+///   the delta.
+/// - Applying the delta to the disclosed initial state does not result in the disclosed final
+///   state.
+pub fn verify_receipt(
+    receipt: &TransactionReceipt,
+    transaction: &ProvenTransaction,
+) -> Result<(), TransactionReceiptError> {
+    if receipt.transaction_id() != transaction.id() {
+        return Err(TransactionReceiptError::TransactionIdMismatch {
+            receipt_tx_id: receipt.transaction_id(),
+            transaction_tx_id: transaction.id(),
+        });
+    }
+
+    if receipt.account_id() != transaction.account_id()
+        || receipt.final_header().id() != transaction.account_id()
+    {
+        return Err(TransactionReceiptError::AccountIdMismatch {
+            receipt_account_id: receipt.account_id(),
+            transaction_account_id: transaction.account_id(),
+        });
+    }
+
+    verify_state_hashes(receipt, transaction)?;
+
+    let initial_header = receipt.initial_header();
+    let final_header = receipt.final_header();
+    let delta = receipt.account_delta();
+
+    if initial_header.code_commitment() != final_header.code_commitment() {
+        return Err(TransactionReceiptError::CodeCommitmentMismatch);
+    }
+
+    let expected_nonce = delta.nonce().unwrap_or(initial_header.nonce());
+    if final_header.nonce() != expected_nonce {
+        return Err(TransactionReceiptError::NonceMismatch {
+            expected: expected_nonce,
+            actual: final_header.nonce(),
+        });
+    }
+
+    verify_storage(receipt, initial_header, final_header)?;
+    verify_vault(receipt, initial_header, final_header)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Checks that the initial and final account headers of the receipt hash to the initial and final
+/// account state hashes of the transaction.
+fn verify_state_hashes(
+    receipt: &TransactionReceipt,
+    transaction: &ProvenTransaction,
+) -> Result<(), TransactionReceiptError> {
+    let account_update = transaction.account_update();
+
+    // for new accounts, the initial account state hash is set to EMPTY_WORD, so the initial header
+    // would not be bound to the transaction
+    let initial_header = receipt.initial_header();
+    if initial_header.nonce() == ZERO {
+        return Err(TransactionReceiptError::NewAccountNotSupported);
+    }
+
+    let initial_hash = initial_header.hash();
+    if initial_hash != account_update.init_state_hash() {
+        return Err(TransactionReceiptError::InitialStateHashMismatch {
+            expected: account_update.init_state_hash(),
+            actual: initial_hash,
+        });
+    }
+
+    let final_hash = receipt.final_header().hash();
+    if final_hash != account_update.final_state_hash() {
+        return Err(TransactionReceiptError::FinalStateHashMismatch {
+            expected: account_update.final_state_hash(),
+            actual: final_hash,
+        });
+    }
+
+    Ok(())
+}
+
+/// Applies the storage delta of the receipt to the disclosed initial storage, and checks that the
+/// resulting storage commitment matches the one in the final account header.
+fn verify_storage(
+    receipt: &TransactionReceipt,
+    initial_header: &AccountHeader,
+    final_header: &AccountHeader,
+) -> Result<(), TransactionReceiptError> {
+    let storage_header = receipt.storage_header();
+    if storage_header.commitment() != initial_header.storage_commitment() {
+        return Err(TransactionReceiptError::StorageHeaderMismatch);
+    }
+
+    let mut slots: Vec<(StorageSlotType, Word)> = storage_header.slots().cloned().collect();
+    let storage_delta = receipt.account_delta().storage();
+
+    for (&slot_index, map_delta) in storage_delta.maps() {
+        let (slot_type, map_root) = slots
+            .get_mut(slot_index as usize)
+            .ok_or(TransactionReceiptError::StorageSlotOutOfBounds(slot_index))?;
+        if !matches!(slot_type, StorageSlotType::Map) {
+            return Err(TransactionReceiptError::StorageSlotNotMap(slot_index));
+        }
+
+        let mut map = PartialSmt::new(Digest::from(*map_root), receipt.storage_map_proofs());
+        for (&key, &value) in map_delta.leaves() {
+            map.insert(key, value)
+                .ok_or(TransactionReceiptError::MissingStorageMapProof { slot_index, key })?;
+        }
+        *map_root = map.root().into();
+    }
+
+    for (&slot_index, &value) in storage_delta.values() {
+        let (slot_type, slot_value) = slots
+            .get_mut(slot_index as usize)
+            .ok_or(TransactionReceiptError::StorageSlotOutOfBounds(slot_index))?;
+        if !matches!(slot_type, StorageSlotType::Value) {
+            return Err(TransactionReceiptError::StorageSlotNotValue(slot_index));
+        }
+        *slot_value = value;
+    }
+
+    if AccountStorageHeader::new(slots).commitment() != final_header.storage_commitment() {
+        return Err(TransactionReceiptError::StorageCommitmentMismatch);
+    }
+
+    Ok(())
+}
+
+/// Applies the vault delta of the receipt to the disclosed initial vault assets, and checks that
+/// the resulting vault root matches the one in the final account header.
+fn verify_vault(
+    receipt: &TransactionReceipt,
+    initial_header: &AccountHeader,
+    final_header: &AccountHeader,
+) -> Result<(), TransactionReceiptError> {
+    let vault_delta = receipt.account_delta().vault();
+    let mut vault = PartialSmt::new(initial_header.vault_root(), receipt.vault_proofs());
+
+    for (&faucet_id, &amount_delta) in vault_delta.fungible().iter() {
+        let vault_key = Digest::from([ZERO, ZERO, ZERO, faucet_id.into()]);
+        let current_value = vault
+            .get(&vault_key)
+            .ok_or(TransactionReceiptError::MissingVaultProof(vault_key))?;
+
+        // the amount of a fungible asset is stored in the first element of the asset word
+        let current_amount = current_value[0].as_int() as i128;
+        let new_amount = current_amount + amount_delta as i128;
+        let new_value = match new_amount.cmp(&0) {
+            Ordering::Less => {
+                return Err(TransactionReceiptError::InvalidVaultUpdate(format!(
+                    "amount of asset issued by faucet {faucet_id} would become negative"
+                )))
+            },
+            Ordering::Equal => EMPTY_WORD,
+            Ordering::Greater => {
+                let asset = FungibleAsset::new(faucet_id, new_amount as u64)
+                    .map_err(|err| TransactionReceiptError::InvalidVaultUpdate(err.to_string()))?;
+                Word::from(asset)
+            },
+        };
+        vault.insert(vault_key, new_value).expect("vault key should have been opened");
+    }
+
+    for (&asset, &action) in vault_delta.non_fungible().iter() {
+        let vault_key = Digest::from(asset.vault_key());
+        let current_value = vault
+            .get(&vault_key)
+            .ok_or(TransactionReceiptError::MissingVaultProof(vault_key))?;
+
+        let new_value = match action {
+            NonFungibleDeltaAction::Add if current_value == EMPTY_WORD => {
+                Word::from(Asset::NonFungible(asset))
+            },
+            NonFungibleDeltaAction::Remove if current_value == Word::from(asset) => EMPTY_WORD,
+            NonFungibleDeltaAction::Add => {
+                return Err(TransactionReceiptError::InvalidVaultUpdate(format!(
+                    "non-fungible asset {vault_key} cannot be added, it is already in the vault"
+                )))
+            },
+            NonFungibleDeltaAction::Remove => {
+                return Err(TransactionReceiptError::InvalidVaultUpdate(format!(
+                    "non-fungible asset {vault_key} cannot be removed, it is not in the vault"
+                )))
+            },
+        };
+        vault.insert(vault_key, new_value).expect("vault key should have been opened");
+    }
+
+    if vault.root() != final_header.vault_root() {
+        return Err(TransactionReceiptError::VaultRootMismatch);
+    }
+
+    Ok(())
+}
+
+// PARTIAL SPARSE MERKLE TREE
+// ================================================================================================
+
+/// A sparse Merkle tree which contains only the leaves opened by a set of [SmtProof]s.
+///
+/// Only the proofs against the expected root are used, so proofs for different trees can be
+/// provided together. Leaves which are not opened by any proof can be neither read nor updated.
+struct PartialSmt {
+    root: Digest,
+    tree: PartialMerkleTree,
+    leaves: BTreeMap<u64, SmtLeaf>,
+}
+
+impl PartialSmt {
+    /// Returns a new [PartialSmt] containing the leaves opened by the proofs against the provided
+    /// root.
+    fn new(root: Digest, proofs: &[SmtProof]) -> Self {
+        let mut tree = PartialMerkleTree::new();
+        let mut leaves = BTreeMap::new();
+        for proof in proofs.iter().filter(|proof| proof.compute_root() == root) {
+            let leaf_index = proof.leaf().index().value();
+            tree.add_path(leaf_index, proof.leaf().hash(), proof.path().clone())
+                .expect("paths against the same root should be consistent");
+            leaves.insert(leaf_index, proof.leaf().clone());
+        }
+
+        Self { root, tree, leaves }
+    }
+
+    /// Returns the root of this tree.
+    fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the value stored under the provided key, or `None` if the leaf of the key has not
+    /// been opened.
+    fn get(&self, key: &Digest) -> Option<Word> {
+        let leaf_index = LeafIndex::<SMT_DEPTH>::from(*key).value();
+        let leaf = self.leaves.get(&leaf_index)?;
+        let value = leaf.entries().into_iter().find(|(entry_key, _)| entry_key == key);
+        Some(value.map_or(EMPTY_WORD, |(_, value)| *value))
+    }
+
+    /// Sets the value stored under the provided key, or returns `None` if the leaf of the key has
+    /// not been opened. Setting a value to [EMPTY_WORD] removes the key from the tree.
+    fn insert(&mut self, key: Digest, value: Word) -> Option<()> {
+        let leaf_index = LeafIndex::<SMT_DEPTH>::from(key);
+        let leaf = self.leaves.get_mut(&leaf_index.value())?;
+
+        let mut entries: Vec<(Digest, Word)> = leaf
+            .entries()
+            .into_iter()
+            .filter(|(entry_key, _)| *entry_key != key)
+            .copied()
+            .collect();
+        if value != EMPTY_WORD {
+            entries.push((key, value));
+        }
+        // entries of a leaf are kept in the same order as in the full sparse Merkle tree
+        entries.sort_by(|(key_1, _), (key_2, _)| cmp_keys(key_1, key_2));
+
+        *leaf = SmtLeaf::new(entries, leaf_index).expect("entries should map to the same leaf");
+        self.tree
+            .update_leaf(leaf_index.value(), leaf.hash().into())
+            .expect("opened leaf should be in the tree");
+        self.root = self.tree.root();
+
+        Some(())
+    }
+}
+
+/// Compares two keys of a sparse Merkle tree leaf, starting from the most significant element.
+fn cmp_keys(key_1: &Digest, key_2: &Digest) -> Ordering {
+    key_1
+        .iter()
+        .rev()
+        .map(|element| element.as_int())
+        .cmp(key_2.iter().rev().map(|element| element.as_int()))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            },
+            Account, AccountDelta, AccountStorageDelta, AccountVaultDelta, StorageMapDelta,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset},
+        crypto::merkle::SmtProof,
+        testing::{
+            constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
+            storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
+            transaction::mock_account_id,
+        },
+        transaction::{ProvenTransaction, ProvenTransactionBuilder, TransactionReceipt},
+        utils::{Deserializable, Serializable},
+        Digest, Felt, ONE, ZERO,
+    };
+
+    use super::verify_receipt;
+    use crate::errors::TransactionReceiptError;
+
+    fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+        FungibleAsset::new(faucet_id.try_into().unwrap(), amount).unwrap().into()
+    }
+
+    /// Returns a delta which updates value and map slots, and adds and removes fungible and
+    /// non-fungible assets of the mock account.
+    fn mock_delta() -> AccountDelta {
+        let map_delta = StorageMapDelta::from_iters(
+            [STORAGE_LEAVES_2[1].0.into()],
+            [
+                (STORAGE_LEAVES_2[0].0.into(), [Felt::new(9); 4]),
+                ([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)], [Felt::new(7); 4]),
+            ],
+        );
+        let storage_delta = AccountStorageDelta::from_iters(
+            [],
+            [(STORAGE_INDEX_0, [Felt::new(5); 4])],
+            [(STORAGE_INDEX_2, map_delta)],
+        );
+
+        let vault_delta = AccountVaultDelta::from_iters(
+            [
+                fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 50),
+                fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, 20),
+            ],
+            [
+                fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, FUNGIBLE_ASSET_AMOUNT),
+                NonFungibleAsset::mock(
+                    ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                    &NON_FUNGIBLE_ASSET_DATA,
+                ),
+            ],
+        );
+
+        AccountDelta::new(storage_delta, vault_delta, Some(Felt::new(2))).unwrap()
+    }
+
+    fn mock_tx(init_state_hash: Digest, final_state_hash: Digest) -> ProvenTransaction {
+        ProvenTransactionBuilder::mock(
+            mock_account_id(),
+            init_state_hash,
+            final_state_hash,
+            Digest::default(),
+            10,
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn receipt_is_verified_against_proven_transaction() {
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ONE,
+            TransactionKernel::testing_assembler(),
+        );
+        let delta = mock_delta();
+        let mut final_account = account.clone();
+        final_account.apply_delta(&delta).unwrap();
+
+        let tx = mock_tx(account.hash(), final_account.hash());
+        let receipt = TransactionReceipt::new(tx.id(), &account, delta.clone()).unwrap();
+        verify_receipt(&receipt, &tx).unwrap();
+
+        // the receipt survives serialization
+        let receipt = TransactionReceipt::read_from_bytes(&receipt.to_bytes()).unwrap();
+        verify_receipt(&receipt, &tx).unwrap();
+
+        // a receipt issued for another transaction is rejected
+        let other_tx = mock_tx(account.hash(), account.hash());
+        assert!(matches!(
+            verify_receipt(&receipt, &other_tx),
+            Err(TransactionReceiptError::TransactionIdMismatch { .. })
+        ));
+
+        // a receipt describing a different update of the account is rejected
+        let other_delta = AccountDelta::new(
+            AccountStorageDelta::from_iters([STORAGE_INDEX_0], [], []),
+            AccountVaultDelta::default(),
+            Some(Felt::new(2)),
+        )
+        .unwrap();
+        let other_receipt =
+            TransactionReceipt::new(tx.id(), &account, other_delta.clone()).unwrap();
+        assert!(matches!(
+            verify_receipt(&other_receipt, &tx),
+            Err(TransactionReceiptError::FinalStateHashMismatch { .. })
+        ));
+
+        // a receipt which combines the correct account headers with a different delta is rejected
+        let forged_receipt = forge_receipt(&receipt, &other_delta, receipt.vault_proofs());
+        assert_eq!(
+            verify_receipt(&forged_receipt, &tx),
+            Err(TransactionReceiptError::StorageCommitmentMismatch)
+        );
+
+        // a receipt which does not open the updated vault assets is rejected
+        let forged_receipt = forge_receipt(&receipt, &delta, &[]);
+        assert!(matches!(
+            verify_receipt(&forged_receipt, &tx),
+            Err(TransactionReceiptError::MissingVaultProof(_))
+        ));
+
+        // a receipt which discloses an arbitrary initial state of a new account is rejected, since
+        // the initial state of new accounts is not committed to by the transaction
+        let forged_account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ZERO,
+            TransactionKernel::testing_assembler(),
+        );
+        let mut final_account = forged_account.clone();
+        final_account.apply_delta(&other_delta).unwrap();
+        let new_account_tx = mock_tx(Digest::default(), final_account.hash());
+        let forged_receipt =
+            TransactionReceipt::new(new_account_tx.id(), &forged_account, other_delta).unwrap();
+        assert_eq!(
+            verify_receipt(&forged_receipt, &new_account_tx),
+            Err(TransactionReceiptError::NewAccountNotSupported)
+        );
+    }
+
+    /// Returns a copy of the provided receipt with the delta and vault proofs replaced.
+    fn forge_receipt(
+        receipt: &TransactionReceipt,
+        delta: &AccountDelta,
+        vault_proofs: &[SmtProof],
+    ) -> TransactionReceipt {
+        let mut bytes = Vec::new();
+        receipt.transaction_id().write_into(&mut bytes);
+        receipt.initial_header().write_into(&mut bytes);
+        receipt.final_header().write_into(&mut bytes);
+        receipt.storage_header().write_into(&mut bytes);
+        delta.write_into(&mut bytes);
+        receipt.storage_map_proofs().to_vec().write_into(&mut bytes);
+        vault_proofs.to_vec().write_into(&mut bytes);
+        TransactionReceipt::read_from_bytes(&bytes).unwrap()
+    }
+}
@@ -0,0 +1,410 @@
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteHeader, NoteId, NoteInclusionProof, Nullifier},
+    transaction::{ChainMmr, ProvenTransaction},
+    BlockHeader, Digest,
+};
+
+use super::TransactionVerifier;
+use crate::errors::StatefulTransactionVerifierError;
+
+// CHAIN VIEW
+// ================================================================================================
+
+/// The [ChainView] trait defines the view of the chain state against which a
+/// [StatefulTransactionVerifier] checks proven transactions.
+pub trait ChainView {
+    /// Returns the header of the latest block in the chain.
+    fn latest_block_header(&self) -> BlockHeader;
+
+    /// Returns the chain MMR for the chain ending right before the latest block. The MMR must
+    /// contain the headers of all blocks which may be referenced by the verified transactions.
+    fn chain_mmr(&self) -> &ChainMmr;
+
+    /// Returns the current state hash of the specified account, or `None` if the account has not
+    /// been recorded in the chain yet.
+    fn get_account_state_hash(&self, account_id: AccountId) -> Option<Digest>;
+
+    /// Returns the number of the block in which the specified nullifier was recorded, or `None`
+    /// if the nullifier has not been spent.
+    fn get_nullifier_block_num(&self, nullifier: &Nullifier) -> Option<u32>;
+
+    /// Returns the inclusion proof of the specified note, or `None` if the note has not been
+    /// recorded in the chain.
+    fn get_note_inclusion_proof(&self, note_id: &NoteId) -> Option<NoteInclusionProof>;
+
+    /// Returns true if the specified note has been created by a transaction which is not yet
+    /// included in a block.
+    fn is_note_pending(&self, note_id: &NoteId) -> bool;
+}
+
+// STATEFUL TRANSACTION VERIFIER
+// ================================================================================================
+
+/// The [StatefulTransactionVerifier] verifies [ProvenTransaction]s against a view of the chain
+/// state in addition to verifying their proofs.
+///
+/// A transaction is considered valid if:
+/// - The block referenced by the transaction is the latest block or is present in the chain MMR.
+/// - None of the notes consumed by the transaction have been consumed before.
+/// - The current state of the account matches the initial state of the account in the transaction.
+///   Accounts which are not yet recorded in the chain are assumed to be new, and their initial
+///   state hash must be the empty digest.
+/// - The transaction has not expired as of the latest block.
+/// - All unauthenticated input notes either have a valid inclusion proof in the chain or are
+///   created by a pending transaction.
+/// - The transaction proof is valid.
+pub struct StatefulTransactionVerifier {
+    verifier: TransactionVerifier,
+}
+
+impl StatefulTransactionVerifier {
+    /// Returns a new [StatefulTransactionVerifier] instantiated with the specified security level.
+    pub fn new(proof_security_level: u32) -> Self {
+        Self {
+            verifier: TransactionVerifier::new(proof_security_level),
+        }
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the provided chain view and the
+    /// transaction kernel.
+    ///
+    /// The chain state is checked before the proof, so that invalid transactions are rejected
+    /// without incurring the cost of proof verification.
+    ///
+    /// # Errors
+    /// Returns an error if any of the checks listed in [StatefulTransactionVerifier] fails.
+    pub fn verify(
+        &self,
+        transaction: ProvenTransaction,
+        chain: &impl ChainView,
+    ) -> Result<(), StatefulTransactionVerifierError> {
+        self.verify_chain_state(&transaction, chain)?;
+
+        self.verifier
+            .verify(transaction)
+            .map_err(StatefulTransactionVerifierError::TransactionVerificationFailed)
+    }
+
+    /// Checks the provided [ProvenTransaction] against the provided chain view without verifying
+    /// the transaction proof.
+    ///
+    /// # Errors
+    /// Returns an error if any of the chain state checks listed in [StatefulTransactionVerifier]
+    /// fails.
+    pub fn verify_chain_state(
+        &self,
+        transaction: &ProvenTransaction,
+        chain: &impl ChainView,
+    ) -> Result<(), StatefulTransactionVerifierError> {
+        let latest_header = chain.latest_block_header();
+
+        // check that the reference block is part of the chain
+        if find_block(chain, latest_header, transaction.block_ref()).is_none() {
+            return Err(StatefulTransactionVerifierError::ReferenceBlockNotFound(
+                transaction.block_ref(),
+            ));
+        }
+
+        // check that the input notes have not been consumed
+        for nullifier in transaction.get_nullifiers() {
+            if let Some(block_num) = chain.get_nullifier_block_num(&nullifier) {
+                return Err(StatefulTransactionVerifierError::NullifierAlreadySpent {
+                    nullifier,
+                    block_num,
+                });
+            }
+        }
+
+        // check that the transaction starts from the current state of the account, or from the
+        // empty state if the account is new
+        let account_update = transaction.account_update();
+        let current_state_hash =
+            chain.get_account_state_hash(transaction.account_id()).unwrap_or_default();
+        if current_state_hash != account_update.init_state_hash() {
+            return Err(StatefulTransactionVerifierError::AccountStateMismatch {
+                account_id: transaction.account_id(),
+                current_state_hash,
+                init_state_hash: account_update.init_state_hash(),
+            });
+        }
+
+        // check that the transaction has not expired
+        if transaction.expiration_block_num() <= latest_header.block_num() {
+            return Err(StatefulTransactionVerifierError::TransactionExpired {
+                expiration_block_num: transaction.expiration_block_num(),
+                chain_tip: latest_header.block_num(),
+            });
+        }
+
+        // check that the unauthenticated notes are either in the chain or pending
+        for note in transaction.get_unauthenticated_notes() {
+            match chain.get_note_inclusion_proof(&note.id()) {
+                Some(proof) => {
+                    if !is_note_in_chain(chain, latest_header, note, &proof) {
+                        return Err(StatefulTransactionVerifierError::InvalidNoteInclusionProof(
+                            note.id(),
+                        ));
+                    }
+                },
+                None if chain.is_note_pending(&note.id()) => (),
+                None => {
+                    return Err(StatefulTransactionVerifierError::UnauthenticatedNoteNotFound(
+                        note.id(),
+                    ))
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the header of the block with the specified hash if it is the latest block or it is
+/// present in the chain MMR of the provided chain view.
+fn find_block(
+    chain: &impl ChainView,
+    latest_header: BlockHeader,
+    block_hash: Digest,
+) -> Option<BlockHeader> {
+    if latest_header.hash() == block_hash {
+        return Some(latest_header);
+    }
+
+    let chain_mmr = chain.chain_mmr();
+    (0..chain_mmr.chain_length() as u32)
+        .filter_map(|block_num| chain_mmr.get_block(block_num))
+        .find(|header| header.hash() == block_hash)
+        .copied()
+}
+
+/// Returns true if the provided inclusion proof authenticates the note against the note root of
+/// a block known to the provided chain view.
+fn is_note_in_chain(
+    chain: &impl ChainView,
+    latest_header: BlockHeader,
+    note: &NoteHeader,
+    proof: &NoteInclusionProof,
+) -> bool {
+    let block_num = proof.location().block_num();
+    let block_header = if block_num == latest_header.block_num() {
+        latest_header
+    } else {
+        match chain.chain_mmr().get_block(block_num) {
+            Some(header) => *header,
+            None => return false,
+        }
+    };
+
+    let note_index = proof.location().node_index_in_block().into();
+    proof
+        .note_path()
+        .verify(note_index, note.hash(), &block_header.note_root())
+        .is_ok()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use miden_objects::{
+        accounts::AccountId,
+        block::{BlockNoteIndex, BlockNoteTree},
+        crypto::merkle::{Mmr, PartialMmr},
+        notes::{Note, NoteId, NoteInclusionProof, Nullifier},
+        testing::transaction::{mock_account_id, mock_digest},
+        transaction::{ChainMmr, InputNote, ProvenTransaction, ProvenTransactionBuilder},
+        BlockHeader, Digest,
+    };
+
+    use super::{ChainView, StatefulTransactionVerifier};
+    use crate::errors::StatefulTransactionVerifierError;
+
+    struct MockChainView {
+        latest_header: BlockHeader,
+        chain_mmr: ChainMmr,
+        accounts: BTreeMap<AccountId, Digest>,
+        nullifiers: BTreeMap<Nullifier, u32>,
+        note_proofs: BTreeMap<NoteId, NoteInclusionProof>,
+        pending_notes: Vec<NoteId>,
+    }
+
+    impl ChainView for MockChainView {
+        fn latest_block_header(&self) -> BlockHeader {
+            self.latest_header
+        }
+
+        fn chain_mmr(&self) -> &ChainMmr {
+            &self.chain_mmr
+        }
+
+        fn get_account_state_hash(&self, account_id: AccountId) -> Option<Digest> {
+            self.accounts.get(&account_id).copied()
+        }
+
+        fn get_nullifier_block_num(&self, nullifier: &Nullifier) -> Option<u32> {
+            self.nullifiers.get(nullifier).copied()
+        }
+
+        fn get_note_inclusion_proof(&self, note_id: &NoteId) -> Option<NoteInclusionProof> {
+            self.note_proofs.get(note_id).cloned()
+        }
+
+        fn is_note_pending(&self, note_id: &NoteId) -> bool {
+            self.pending_notes.contains(note_id)
+        }
+    }
+
+    fn mock_tx(block_ref: Digest, expiration_block_num: u32, notes: &[Note]) -> ProvenTransaction {
+        ProvenTransactionBuilder::mock(
+            mock_account_id(),
+            mock_digest(1),
+            mock_digest(2),
+            block_ref,
+            expiration_block_num,
+        )
+        .add_input_notes(notes.iter().cloned().map(InputNote::unauthenticated))
+        .build()
+        .unwrap()
+    }
+
+    /// Returns a chain view with two blocks, where the first block contains `note`.
+    fn mock_chain_view(note: &Note) -> MockChainView {
+        let note_index = BlockNoteIndex::new(0, 0).unwrap();
+        let note_tree =
+            BlockNoteTree::with_entries([(note_index, note.id(), *note.metadata())]).unwrap();
+
+        let kernel_root = Digest::default();
+        let genesis = BlockHeader::mock(
+            0,
+            Some(Mmr::new().peaks().hash_peaks()),
+            Some(note_tree.root()),
+            &[],
+            kernel_root,
+        );
+        let mut mmr = Mmr::new();
+        mmr.add(genesis.hash());
+        let latest_header =
+            BlockHeader::mock(1, Some(mmr.peaks().hash_peaks()), None, &[], kernel_root);
+
+        let mut partial_mmr = PartialMmr::from_peaks(Mmr::new().peaks());
+        partial_mmr.add(genesis.hash(), true);
+        let chain_mmr = ChainMmr::new(partial_mmr, vec![genesis]).unwrap();
+
+        let note_proof = NoteInclusionProof::new(
+            0,
+            note_index.leaf_index_value(),
+            note_tree.get_note_path(note_index),
+        )
+        .unwrap();
+
+        MockChainView {
+            latest_header,
+            chain_mmr,
+            accounts: BTreeMap::from([(mock_account_id(), mock_digest(1))]),
+            nullifiers: BTreeMap::new(),
+            note_proofs: BTreeMap::from([(note.id(), note_proof)]),
+            pending_notes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn stateful_verifier_checks_chain_state() {
+        let verifier = StatefulTransactionVerifier::new(0);
+        let note = Note::mock(1);
+        let mut chain = mock_chain_view(&note);
+        let genesis_hash = chain.chain_mmr.get_block(0).unwrap().hash();
+        let latest_hash = chain.latest_header.hash();
+
+        // transactions referencing either block and consuming a note recorded in the chain are
+        // valid
+        let tx = mock_tx(genesis_hash, 5, &[note.clone()]);
+        verifier.verify_chain_state(&tx, &chain).unwrap();
+        verifier.verify_chain_state(&mock_tx(latest_hash, 5, &[]), &chain).unwrap();
+
+        // the proof is verified after the chain state checks
+        assert!(matches!(
+            verifier.verify(tx.clone(), &chain),
+            Err(StatefulTransactionVerifierError::TransactionVerificationFailed(_))
+        ));
+
+        assert_eq!(
+            verifier.verify_chain_state(&mock_tx(mock_digest(7), 5, &[]), &chain),
+            Err(StatefulTransactionVerifierError::ReferenceBlockNotFound(mock_digest(7)))
+        );
+
+        assert_eq!(
+            verifier.verify_chain_state(&mock_tx(latest_hash, 1, &[]), &chain),
+            Err(StatefulTransactionVerifierError::TransactionExpired {
+                expiration_block_num: 1,
+                chain_tip: 1
+            })
+        );
+
+        // notes which are neither in the chain nor pending are rejected
+        let other_note = Note::mock(2);
+        let tx = mock_tx(latest_hash, 5, &[other_note.clone()]);
+        assert_eq!(
+            verifier.verify_chain_state(&tx, &chain),
+            Err(StatefulTransactionVerifierError::UnauthenticatedNoteNotFound(other_note.id()))
+        );
+        chain.pending_notes.push(other_note.id());
+        verifier.verify_chain_state(&tx, &chain).unwrap();
+
+        // inclusion proofs must authenticate the note
+        let proof = chain.note_proofs[&note.id()].clone();
+        chain.note_proofs.insert(other_note.id(), proof);
+        assert_eq!(
+            verifier.verify_chain_state(&tx, &chain),
+            Err(StatefulTransactionVerifierError::InvalidNoteInclusionProof(other_note.id()))
+        );
+
+        let tx = mock_tx(latest_hash, 5, &[note.clone()]);
+        chain.nullifiers.insert(note.nullifier(), 1);
+        assert_eq!(
+            verifier.verify_chain_state(&tx, &chain),
+            Err(StatefulTransactionVerifierError::NullifierAlreadySpent {
+                nullifier: note.nullifier(),
+                block_num: 1
+            })
+        );
+
+        chain.accounts.insert(mock_account_id(), mock_digest(3));
+        assert_eq!(
+            verifier.verify_chain_state(&mock_tx(latest_hash, 5, &[]), &chain),
+            Err(StatefulTransactionVerifierError::AccountStateMismatch {
+                account_id: mock_account_id(),
+                current_state_hash: mock_digest(3),
+                init_state_hash: mock_digest(1),
+            })
+        );
+
+        // transactions against accounts which are not in the chain must start from the empty
+        // state
+        chain.accounts.remove(&mock_account_id());
+        assert_eq!(
+            verifier.verify_chain_state(&mock_tx(latest_hash, 5, &[]), &chain),
+            Err(StatefulTransactionVerifierError::AccountStateMismatch {
+                account_id: mock_account_id(),
+                current_state_hash: Digest::default(),
+                init_state_hash: mock_digest(1),
+            })
+        );
+        let tx = ProvenTransactionBuilder::mock(
+            mock_account_id(),
+            Digest::default(),
+            mock_digest(2),
+            latest_hash,
+            5,
+        )
+        .build()
+        .unwrap();
+        verifier.verify_chain_state(&tx, &chain).unwrap();
+    }
+}
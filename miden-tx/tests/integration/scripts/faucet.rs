@@ -4,14 +4,17 @@ use alloc::sync::Arc;
 
 use miden_lib::accounts::{auth::RpoFalcon512, faucets::BasicFungibleFaucet};
 use miden_objects::{
-    accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, Account, AccountId},
+    accounts::{
+        account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER},
+        Account, AccountId,
+    },
     assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
-    crypto::dsa::rpo_falcon512::PublicKey,
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
     notes::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
     testing::{prepare_word, storage::FAUCET_STORAGE_DATA_SLOT},
     Felt, Word, ZERO,
 };
-use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
+use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor, TransactionRequest};
 
 use crate::{
     build_tx_args_from_script, get_new_pk_and_authenticator,
@@ -99,6 +102,45 @@ fn prove_faucet_contract_mint_fungible_asset_succeeds() {
     );
 }
 
+#[test]
+fn faucet_contract_mint_via_transaction_request() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, None);
+    let target_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+    let mut rng = RpoRandomCoin::new([Felt::new(7); 4]);
+    let asset = FungibleAsset::new(faucet_account.id(), 100).unwrap();
+    let request = TransactionRequest::builder(faucet_account.id())
+        .mint(target_account_id, asset, NoteType::Private, &mut rng)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), Some(falcon_auth.clone()));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executed_transaction = executor
+        .execute_transaction(
+            faucet_account.id(),
+            block_ref,
+            request.input_note_ids(),
+            request.to_transaction_args(),
+        )
+        .unwrap();
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    let output_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(output_note.id(), request.expected_output_notes()[0].id());
+    assert_eq!(output_note.metadata().sender(), faucet_account.id());
+}
+
 #[test]
 fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
@@ -4,19 +4,19 @@ use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
 use miden_objects::{
     accounts::{
         account_id::testing::{
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
-            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
         Account, AccountId, AccountStorage, StorageMap, StorageSlot,
     },
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::SecretKey,
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
     notes::{NoteExecutionHint, NoteTag, NoteType},
     testing::prepare_word,
-    transaction::TransactionArgs,
+    transaction::{OutputNote, TransactionArgs},
     Felt, Word, ONE, ZERO,
 };
-use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
+use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor, TransactionRequest};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
 use crate::{
@@ -270,6 +270,81 @@ fn prove_send_note_with_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+/// Testing sending P2ID and SWAP notes from the basic wallet via a transaction request
+#[test]
+fn send_notes_via_transaction_request() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account = get_account_with_basic_authenticated_wallet(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    // BUILD THE TRANSACTION REQUEST
+    // --------------------------------------------------------------------------------------------
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let payment: Asset = FungibleAsset::new(faucet_id_1, 40).unwrap().into();
+    let offered: Asset = FungibleAsset::new(faucet_id_1, 60).unwrap().into();
+    let requested: Asset = FungibleAsset::new(faucet_id_2, 10).unwrap().into();
+
+    let request = TransactionRequest::builder(sender_account_id)
+        .pay_to_id(target_account_id, vec![payment], NoteType::Public, &mut rng)
+        .unwrap()
+        .swap(offered, requested, NoteType::Private, &mut rng)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(request.expected_future_notes().len(), 1);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(sender_account.clone()).build();
+
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), Some(sender_falcon_auth.clone()));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executed_transaction = executor
+        .execute_transaction(
+            sender_account.id(),
+            block_ref,
+            request.input_note_ids(),
+            request.to_transaction_args(),
+        )
+        .unwrap();
+
+    // the transaction should create exactly the notes described by the request
+    let output_note_ids = executed_transaction
+        .output_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    let expected_note_ids =
+        request.expected_output_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+    assert_eq!(output_note_ids, expected_note_ids);
+
+    // the public P2ID note should be fully reconstructed from the advice inputs
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(request.expected_output_notes()[0].clone())
+    );
+
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&[]).unwrap().commitment()
+    );
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
@@ -41,6 +41,9 @@ pub use header::AccountHeader;
 mod data;
 pub use data::AccountData;
 
+mod partial;
+pub use partial::PartialAccount;
+
 // ACCOUNT
 // ================================================================================================
 
@@ -0,0 +1,254 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::merkle::SmtProof;
+
+use super::{
+    Account, AccountCode, AccountError, AccountHeader, AccountId, AccountStorageHeader, ByteReader,
+    ByteWriter, Deserializable, DeserializationError, Digest, Felt, Serializable, StorageSlotType,
+};
+
+// PARTIAL ACCOUNT
+// ================================================================================================
+
+/// A partial representation of an account which contains only the data required to execute a
+/// specific transaction against the account.
+///
+/// A partial account is composed of:
+/// - The account header, which commits to the full state of the account.
+/// - The account storage header, which contains the values of all storage slots. For storage map
+///   slots, only the root of the map is included.
+/// - The account code.
+/// - Inclusion proofs for the storage map entries which are known to be accessed by the
+///   transaction.
+/// - Inclusion proofs for the vault assets which are known to be accessed by the transaction.
+///
+/// Storage map entries and vault assets which are not covered by the provided proofs may be loaded
+/// on demand during transaction execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialAccount {
+    header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    code: AccountCode,
+    storage_map_proofs: Vec<SmtProof>,
+    vault_proofs: Vec<SmtProof>,
+}
+
+impl PartialAccount {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartialAccount] instantiated from the provided parts.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage header is inconsistent with the storage commitment of the account header.
+    /// - The account code is inconsistent with the code commitment of the account header.
+    /// - Any of the storage map proofs does not open one of the account's storage maps.
+    /// - Any of the vault proofs does not open the account's vault.
+    pub fn new(
+        header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        code: AccountCode,
+        storage_map_proofs: Vec<SmtProof>,
+        vault_proofs: Vec<SmtProof>,
+    ) -> Result<Self, AccountError> {
+        let storage_commitment = storage_header.commitment();
+        if storage_commitment != header.storage_commitment() {
+            return Err(AccountError::PartialAccountInconsistentStorageCommitment {
+                expected: header.storage_commitment(),
+                actual: storage_commitment,
+            });
+        }
+
+        if code.commitment() != header.code_commitment() {
+            return Err(AccountError::PartialAccountInconsistentCodeCommitment {
+                expected: header.code_commitment(),
+                actual: code.commitment(),
+            });
+        }
+
+        for proof in storage_map_proofs.iter() {
+            let map_root = proof.compute_root();
+            let is_storage_map = storage_header.slots().any(|(slot_type, value)| {
+                *slot_type == StorageSlotType::Map && Digest::from(*value) == map_root
+            });
+            if !is_storage_map {
+                return Err(AccountError::PartialAccountStorageMapNotFound(map_root));
+            }
+        }
+
+        for proof in vault_proofs.iter() {
+            let vault_root = proof.compute_root();
+            if vault_root != header.vault_root() {
+                return Err(AccountError::PartialAccountVaultProofInvalid(vault_root));
+            }
+        }
+
+        Ok(Self {
+            header,
+            storage_header,
+            code,
+            storage_map_proofs,
+            vault_proofs,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this account.
+    pub fn id(&self) -> AccountId {
+        self.header.id()
+    }
+
+    /// Returns the nonce of this account.
+    pub fn nonce(&self) -> Felt {
+        self.header.nonce()
+    }
+
+    /// Returns the hash of this account.
+    pub fn hash(&self) -> Digest {
+        self.header.hash()
+    }
+
+    /// Returns the header of this account.
+    pub fn header(&self) -> &AccountHeader {
+        &self.header
+    }
+
+    /// Returns the storage header of this account.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of this account.
+    pub fn code(&self) -> &AccountCode {
+        &self.code
+    }
+
+    /// Returns the inclusion proofs for the storage map entries of this account.
+    pub fn storage_map_proofs(&self) -> &[SmtProof] {
+        &self.storage_map_proofs
+    }
+
+    /// Returns the inclusion proofs for the vault assets of this account.
+    pub fn vault_proofs(&self) -> &[SmtProof] {
+        &self.vault_proofs
+    }
+
+    /// Returns true if this account has not yet been recorded on chain.
+    pub fn is_new(&self) -> bool {
+        self.header.nonce() == Felt::new(0)
+    }
+}
+
+impl From<&Account> for PartialAccount {
+    /// Returns a [PartialAccount] which contains the header, storage header and code of the
+    /// provided account, but no storage map or vault proofs.
+    fn from(account: &Account) -> Self {
+        Self {
+            header: account.into(),
+            storage_header: account.storage().get_header(),
+            code: account.code().clone(),
+            storage_map_proofs: Vec::new(),
+            vault_proofs: Vec::new(),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartialAccount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        self.storage_header.write_into(target);
+        self.code.write_into(target);
+        self.storage_map_proofs.write_into(target);
+        self.vault_proofs.write_into(target);
+    }
+}
+
+impl Deserializable for PartialAccount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let code = AccountCode::read_from(source)?;
+        let storage_map_proofs = Vec::<SmtProof>::read_from(source)?;
+        let vault_proofs = Vec::<SmtProof>::read_from(source)?;
+
+        Self::new(header, storage_header, code, storage_map_proofs, vault_proofs)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::{
+        utils::{Deserializable, Serializable},
+        ONE,
+    };
+
+    use super::PartialAccount;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, Account,
+            AccountCode, AccountId, AccountStorage, AccountStorageHeader,
+        },
+        assets::AssetVault,
+        testing::storage::STORAGE_LEAVES_2,
+    };
+
+    #[test]
+    fn partial_account_proofs_and_serde() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let account = Account::from_parts(
+            account_id,
+            AssetVault::mock(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ONE,
+        );
+
+        let map_proof = AccountStorage::mock_map().open(&STORAGE_LEAVES_2[0].0);
+        let vault_key = account.vault().assets().next().unwrap().vault_key();
+        let vault_proof = account.vault().asset_tree().open(&vault_key.into());
+
+        let partial_account = PartialAccount::new(
+            (&account).into(),
+            account.storage().get_header(),
+            account.code().clone(),
+            vec![map_proof.clone()],
+            vec![vault_proof.clone()],
+        )
+        .unwrap();
+        assert_eq!(partial_account.hash(), account.hash());
+
+        let bytes = partial_account.to_bytes();
+        assert_eq!(PartialAccount::read_from_bytes(&bytes).unwrap(), partial_account);
+
+        // vault proofs must open the vault of the account
+        assert!(PartialAccount::new(
+            (&account).into(),
+            account.storage().get_header(),
+            account.code().clone(),
+            vec![],
+            vec![map_proof],
+        )
+        .is_err());
+
+        // the storage header must be consistent with the account header
+        assert!(PartialAccount::new(
+            (&account).into(),
+            AccountStorageHeader::new(vec![]),
+            account.code().clone(),
+            vec![],
+            vec![vault_proof],
+        )
+        .is_err());
+    }
+}
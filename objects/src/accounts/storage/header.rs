@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{AccountStorage, StorageSlotType, Word};
+use super::{AccountStorage, Digest, Felt, Hasher, StorageSlotType, Word};
 use crate::AccountError;
 
 // ACCOUNT STORAGE HEADER
@@ -55,6 +55,27 @@ impl AccountStorageHeader {
             actual: index as u8,
         })
     }
+
+    /// Returns a commitment to the storage slots described by this header.
+    ///
+    /// The commitment is identical to the commitment of the [AccountStorage] from which this
+    /// header was derived.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.as_elements())
+    }
+
+    /// Converts the storage slots of this header into a vector of field elements.
+    ///
+    /// The elements are laid out in the same way as in [AccountStorage::as_elements()]:
+    /// ```text
+    /// [STORAGE_SLOT_VALUE, storage_slot_type, 0, 0, 0]
+    /// ```
+    pub fn as_elements(&self) -> Vec<Felt> {
+        self.slots
+            .iter()
+            .flat_map(|(slot_type, value)| value.iter().copied().chain(slot_type.as_word()))
+            .collect()
+    }
 }
 
 impl From<AccountStorage> for AccountStorageHeader {
@@ -119,6 +140,15 @@ mod tests {
         assert_eq!(expected_header, AccountStorageHeader::from(account_storage))
     }
 
+    #[test]
+    fn test_storage_header_commitment() {
+        let storage = AccountStorage::mock();
+        let storage_header = storage.get_header();
+
+        assert_eq!(storage.as_elements(), storage_header.as_elements());
+        assert_eq!(storage.commitment(), storage_header.commitment());
+    }
+
     #[test]
     fn test_serde_account_storage_header() {
         // create new storage header
@@ -0,0 +1,376 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::BatchNoteTree;
+use crate::{
+    accounts::{delta::AccountUpdateDetails, AccountId},
+    block::BlockAccountUpdate,
+    notes::{NoteHeader, NoteId, Nullifier},
+    transaction::{InputNoteCommitment, OutputNote, ProvenTransaction, TransactionId},
+    BatchError, Digest, MAX_ACCOUNTS_PER_BATCH, MAX_INPUT_NOTES_PER_BATCH,
+    MAX_OUTPUT_NOTES_PER_BATCH,
+};
+
+// BATCH ACCOUNT UPDATE
+// ================================================================================================
+
+/// Describes the changes made to an account state resulting from executing the transactions
+/// against this account contained in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAccountUpdate {
+    /// ID of the updated account.
+    account_id: AccountId,
+
+    /// Hash of the account state before the first transaction in the batch was executed.
+    init_state_hash: Digest,
+
+    /// Hash of the account state after the last transaction in the batch was executed.
+    final_state_hash: Digest,
+
+    /// IDs of all transactions in the batch that updated the account, in execution order.
+    transactions: Vec<TransactionId>,
+
+    /// The merged updates of all transactions in the batch that updated the account. For private
+    /// accounts, this is set to [AccountUpdateDetails::Private].
+    details: AccountUpdateDetails,
+}
+
+impl BatchAccountUpdate {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BatchAccountUpdate] instantiated from the account update of the provided
+    /// transaction.
+    fn from_transaction(transaction: &ProvenTransaction) -> Self {
+        let update = transaction.account_update();
+        Self {
+            account_id: update.account_id(),
+            init_state_hash: update.init_state_hash(),
+            final_state_hash: update.final_state_hash(),
+            transactions: vec![transaction.id()],
+            details: update.details().clone(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the updated account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state before the batch was executed.
+    pub fn init_state_hash(&self) -> Digest {
+        self.init_state_hash
+    }
+
+    /// Returns the hash of the account state after the batch was executed.
+    pub fn final_state_hash(&self) -> Digest {
+        self.final_state_hash
+    }
+
+    /// Returns the IDs of all transactions in the batch that updated the account.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the merged update details of the account.
+    pub fn details(&self) -> &AccountUpdateDetails {
+        &self.details
+    }
+
+    /// Returns `true` if the account update details are for a private account.
+    pub fn is_private(&self) -> bool {
+        self.details.is_private()
+    }
+
+    /// Converts this update into a [BlockAccountUpdate].
+    pub fn into_block_update(self) -> BlockAccountUpdate {
+        BlockAccountUpdate::new(
+            self.account_id,
+            self.final_state_hash,
+            self.details,
+            self.transactions,
+        )
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges the account update of the provided transaction into this update.
+    ///
+    /// The transaction is assumed to be executed after all transactions already merged into this
+    /// update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The initial state of the account in the transaction does not match the final state of the
+    ///   account after the previous transactions.
+    /// - The update details of the transaction could not be merged into this update.
+    fn merge_transaction(&mut self, transaction: &ProvenTransaction) -> Result<(), BatchError> {
+        let update = transaction.account_update();
+        if self.final_state_hash != update.init_state_hash() {
+            return Err(BatchError::InconsistentAccountStateTransition {
+                account_id: self.account_id,
+                expected: self.final_state_hash,
+                actual: update.init_state_hash(),
+            });
+        }
+
+        let details = core::mem::replace(&mut self.details, AccountUpdateDetails::Private);
+        self.details = details.merge(update.details().clone()).map_err(|error| {
+            BatchError::AccountUpdateError { account_id: self.account_id, error }
+        })?;
+        self.final_state_hash = update.final_state_hash();
+        self.transactions.push(transaction.id());
+
+        Ok(())
+    }
+}
+
+// TRANSACTION BATCH
+// ================================================================================================
+
+/// A batch of proven transactions which are processed together by the block producer.
+///
+/// When a batch is built:
+/// - Updates of the same account are merged in the order in which the transactions appear in the
+///   batch.
+/// - Unauthenticated input notes which are created by an earlier transaction in the same batch
+///   are erased, i.e., they are removed from both the input and the output notes of the batch.
+/// - The remaining output notes are committed to in a [BatchNoteTree].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionBatch {
+    transactions: Vec<TransactionId>,
+    updated_accounts: BTreeMap<AccountId, BatchAccountUpdate>,
+    input_notes: Vec<InputNoteCommitment>,
+    output_notes: Vec<OutputNote>,
+    note_tree: BatchNoteTree,
+}
+
+impl TransactionBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionBatch] built from the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same transaction is included in the batch more than once.
+    /// - Updates of the same account could not be merged, either because the state transitions are
+    ///   not contiguous or because the update details are incompatible.
+    /// - The same note is consumed or created more than once in the batch.
+    /// - An unauthenticated input note matches the ID of a note created by an earlier transaction
+    ///   in the batch, but the note hashes differ.
+    /// - The number of updated accounts, input notes or output notes exceeds the batch limits.
+    pub fn new(transactions: &[ProvenTransaction]) -> Result<Self, BatchError> {
+        // collect transaction IDs and merge account updates
+        let mut tx_ids = BTreeSet::new();
+        let mut updated_accounts = BTreeMap::<AccountId, BatchAccountUpdate>::new();
+        for tx in transactions {
+            if !tx_ids.insert(tx.id()) {
+                return Err(BatchError::DuplicateTransaction(tx.id()));
+            }
+
+            match updated_accounts.get_mut(&tx.account_id()) {
+                Some(update) => update.merge_transaction(tx)?,
+                None => {
+                    updated_accounts
+                        .insert(tx.account_id(), BatchAccountUpdate::from_transaction(tx));
+                },
+            }
+        }
+
+        if updated_accounts.len() > MAX_ACCOUNTS_PER_BATCH {
+            return Err(BatchError::TooManyAccountsInBatch(updated_accounts.len()));
+        }
+
+        // collect input and output notes in execution order; unauthenticated input notes created
+        // by earlier transactions in the batch are erased together with the matching output notes
+        let mut output_notes = Vec::<Option<OutputNote>>::new();
+        let mut output_note_index = BTreeMap::<NoteId, usize>::new();
+        let mut nullifiers = BTreeSet::<Nullifier>::new();
+        let mut input_notes = Vec::new();
+        for tx in transactions {
+            for input_note in tx.input_notes().iter() {
+                if !nullifiers.insert(input_note.nullifier()) {
+                    return Err(BatchError::DuplicateInputNote(input_note.nullifier()));
+                }
+
+                if let Some(header) = input_note.header() {
+                    if let Some(&index) = output_note_index.get(&header.id()) {
+                        let output_note = output_notes[index]
+                            .take()
+                            .expect("output note should be erased at most once");
+                        let output_hash = NoteHeader::from(&output_note).hash();
+                        if output_hash != header.hash() {
+                            return Err(BatchError::NoteHashesMismatch {
+                                id: header.id(),
+                                input_hash: header.hash(),
+                                output_hash,
+                            });
+                        }
+                        continue;
+                    }
+                }
+
+                input_notes.push(input_note.clone());
+            }
+
+            for note in tx.output_notes().iter() {
+                if output_note_index.insert(note.id(), output_notes.len()).is_some() {
+                    return Err(BatchError::DuplicateOutputNote(note.id()));
+                }
+                output_notes.push(Some(note.clone()));
+            }
+        }
+
+        let output_notes: Vec<OutputNote> = output_notes.into_iter().flatten().collect();
+
+        if input_notes.len() > MAX_INPUT_NOTES_PER_BATCH {
+            return Err(BatchError::TooManyInputNotes(input_notes.len()));
+        }
+
+        if output_notes.len() > MAX_OUTPUT_NOTES_PER_BATCH {
+            return Err(BatchError::TooManyOutputNotes(output_notes.len()));
+        }
+
+        let note_tree = BatchNoteTree::with_contiguous_leaves(
+            output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .expect("number of output notes should not exceed the batch note tree capacity");
+
+        Ok(Self {
+            transactions: transactions.iter().map(|tx| tx.id()).collect(),
+            updated_accounts,
+            input_notes,
+            output_notes,
+            note_tree,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the IDs of the transactions in this batch, in the order in which they were added.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns an iterator over the merged account updates of this batch.
+    pub fn updated_accounts(&self) -> impl Iterator<Item = &BatchAccountUpdate> {
+        self.updated_accounts.values()
+    }
+
+    /// Returns the input notes consumed by this batch, excluding the notes created and consumed
+    /// within the batch.
+    pub fn input_notes(&self) -> &[InputNoteCommitment] {
+        &self.input_notes
+    }
+
+    /// Returns the output notes created by this batch, excluding the notes created and consumed
+    /// within the batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the tree committing to the output notes of this batch.
+    pub fn note_tree(&self) -> &BatchNoteTree {
+        &self.note_tree
+    }
+
+    /// Returns an iterator over the nullifiers of the input notes of this batch.
+    pub fn nullifiers(&self) -> impl Iterator<Item = Nullifier> + '_ {
+        self.input_notes.iter().map(InputNoteCommitment::nullifier)
+    }
+
+    /// Returns an iterator over the headers of the unauthenticated input notes of this batch,
+    /// i.e., the notes which must be authenticated against the chain by the block producer.
+    pub fn get_unauthenticated_notes(&self) -> impl Iterator<Item = &NoteHeader> {
+        self.input_notes.iter().filter_map(InputNoteCommitment::header)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{BatchError, TransactionBatch};
+    use crate::{
+        notes::Note,
+        transaction::{InputNote, OutputNote, ProvenTransaction, ProvenTransactionBuilder},
+    };
+
+    fn mock_tx(
+        init_state: u64,
+        final_state: u64,
+        input_notes: Vec<InputNote>,
+        output_notes: Vec<Note>,
+    ) -> ProvenTransaction {
+        ProvenTransactionBuilder::mock_update(init_state, final_state)
+            .add_input_notes(input_notes.iter())
+            .add_output_notes(output_notes.into_iter().map(OutputNote::Full))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn batch_merges_account_updates_and_erases_notes() {
+        let note = Note::mock(1);
+        let tx1 = mock_tx(1, 2, vec![], vec![note.clone(), Note::mock(2)]);
+        let tx2 = mock_tx(2, 3, vec![InputNote::unauthenticated(note)], vec![]);
+
+        let batch = TransactionBatch::new(&[tx1.clone(), tx2.clone()]).unwrap();
+        assert_eq!(batch.transactions(), &[tx1.id(), tx2.id()]);
+
+        let updates: Vec<_> = batch.updated_accounts().collect();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].init_state_hash(), tx1.account_update().init_state_hash());
+        assert_eq!(updates[0].final_state_hash(), tx2.account_update().final_state_hash());
+        assert_eq!(updates[0].transactions(), &[tx1.id(), tx2.id()]);
+
+        // the note created by the first transaction and consumed by the second one is erased
+        assert!(batch.input_notes().is_empty());
+        assert_eq!(batch.output_notes().len(), 1);
+        assert_eq!(batch.output_notes()[0].id(), Note::mock(2).id());
+    }
+
+    #[test]
+    fn batch_does_not_erase_notes_created_by_later_transactions() {
+        let note = Note::mock(1);
+        let tx1 = mock_tx(1, 2, vec![InputNote::unauthenticated(note.clone())], vec![]);
+        let tx2 = mock_tx(2, 3, vec![], vec![note.clone()]);
+
+        // the note is consumed before it is created, so it must stay unauthenticated
+        let batch = TransactionBatch::new(&[tx1, tx2]).unwrap();
+        assert_eq!(batch.input_notes().len(), 1);
+        assert_eq!(batch.get_unauthenticated_notes().next().unwrap().id(), note.id());
+        assert_eq!(batch.output_notes().len(), 1);
+        assert_eq!(batch.output_notes()[0].id(), note.id());
+    }
+
+    #[test]
+    fn batch_rejects_invalid_transactions() {
+        // account state transitions must be contiguous
+        let tx1 = mock_tx(1, 2, vec![], vec![]);
+        let tx2 = mock_tx(3, 4, vec![], vec![]);
+        assert!(matches!(
+            TransactionBatch::new(&[tx1, tx2]),
+            Err(BatchError::InconsistentAccountStateTransition { .. })
+        ));
+
+        // the same note must not be created twice
+        let tx1 = mock_tx(1, 2, vec![], vec![Note::mock(1)]);
+        let tx2 = mock_tx(2, 3, vec![], vec![Note::mock(1)]);
+        assert_eq!(
+            TransactionBatch::new(&[tx1, tx2]),
+            Err(BatchError::DuplicateOutputNote(Note::mock(1).id()))
+        );
+    }
+}
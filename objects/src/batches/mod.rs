@@ -1,2 +1,5 @@
+mod batch;
+pub use batch::{BatchAccountUpdate, TransactionBatch};
+
 mod note_tree;
 pub use note_tree::BatchNoteTree;
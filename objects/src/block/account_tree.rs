@@ -0,0 +1,301 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::merkle::{LeafIndex, MerkleError, MerklePath, SimpleSmt};
+
+use crate::{
+    accounts::AccountId,
+    utils::{
+        read_bounded_vec, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable,
+    },
+    Digest, Felt, Word, ACCOUNT_TREE_DEPTH, EMPTY_WORD,
+};
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// Wrapper over [SimpleSmt<ACCOUNT_TREE_DEPTH>] for the account tree.
+///
+/// Each account is stored in the leaf at the index defined by its ID, and the value of the leaf is
+/// the hash of the current account state. Leaves of accounts which are not yet recorded on chain
+/// are set to [crate::EMPTY_WORD].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTree(SimpleSmt<ACCOUNT_TREE_DEPTH>);
+
+impl AccountTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [AccountTree].
+    pub fn new() -> Self {
+        Self(SimpleSmt::new().expect("Unreachable"))
+    }
+
+    /// Returns a new [AccountTree] instantiated with the provided account state hashes.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain multiple state hashes for the same
+    /// account.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, MerkleError> {
+        let leaves = entries
+            .into_iter()
+            .map(|(account_id, state_hash)| (u64::from(account_id), state_hash.into()));
+
+        SimpleSmt::with_leaves(leaves).map(Self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of accounts in the tree.
+    pub fn num_accounts(&self) -> usize {
+        self.0.num_leaves()
+    }
+
+    /// Returns the state hash of the specified account, or `None` if the account is not in the
+    /// tree.
+    pub fn get(&self, account_id: AccountId) -> Option<Digest> {
+        let value = self.0.get_leaf(&LeafIndex::from(account_id));
+        (value != EMPTY_WORD).then(|| value.into())
+    }
+
+    /// Returns a membership witness for the specified account if it is in the tree, or a
+    /// non-membership witness otherwise.
+    pub fn open(&self, account_id: AccountId) -> AccountWitness {
+        let opening = self.0.open(&LeafIndex::from(account_id));
+        let state_hash = (opening.value != Digest::from(EMPTY_WORD)).then_some(opening.value);
+
+        AccountWitness {
+            account_id,
+            state_hash,
+            path: opening.path,
+        }
+    }
+
+    /// Returns an iterator over the accounts in the tree and their state hashes.
+    pub fn accounts(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
+        self.0.leaves().map(|(index, value)| {
+            let account_id = AccountId::new_unchecked(Felt::new(index));
+            (account_id, (*value).into())
+        })
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the state hashes of the specified accounts and returns their previous state hashes
+    /// in the same order. Previous state hashes of accounts which were not in the tree are set to
+    /// `None`.
+    ///
+    /// Updates are applied in order; if the same account is updated more than once, each update
+    /// overrides the previous one.
+    pub fn apply_updates(
+        &mut self,
+        updates: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Vec<Option<Digest>> {
+        updates
+            .into_iter()
+            .map(|(account_id, state_hash)| {
+                let old_value = self.0.insert(LeafIndex::from(account_id), state_hash.into());
+                (old_value != EMPTY_WORD).then(|| old_value.into())
+            })
+            .collect()
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ACCOUNT WITNESS
+// ================================================================================================
+
+/// A witness of the state of a single account in an [AccountTree].
+///
+/// If the account is in the tree, this is a membership witness for its state hash. Otherwise,
+/// this is a non-membership witness, i.e., a proof that the leaf of the account is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWitness {
+    account_id: AccountId,
+    state_hash: Option<Digest>,
+    path: MerklePath,
+}
+
+impl AccountWitness {
+    /// Returns the ID of the account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state hash of the account, or `None` if this is a non-membership witness.
+    pub fn state_hash(&self) -> Option<Digest> {
+        self.state_hash
+    }
+
+    /// Returns the Merkle path of the account leaf.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns true if this witness proves that the account is in the tree.
+    pub fn is_membership(&self) -> bool {
+        self.state_hash.is_some()
+    }
+
+    /// Returns the root of the account tree this witness was computed against.
+    pub fn compute_root(&self) -> Digest {
+        self.compute_root_with(self.state_hash)
+    }
+
+    /// Returns the root of the account tree after setting the state hash of the account to the
+    /// provided value.
+    pub fn compute_root_with(&self, state_hash: Option<Digest>) -> Digest {
+        let leaf = state_hash.unwrap_or_else(|| EMPTY_WORD.into());
+        self.path
+            .compute_root(u64::from(self.account_id), leaf)
+            .expect("account ID should be a valid leaf index")
+    }
+
+    /// Returns true if this witness is valid against the provided account tree root.
+    pub fn verify(&self, root: Digest) -> bool {
+        self.compute_root() == root
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.num_leaves());
+        target.write_many(self.0.leaves());
+    }
+}
+
+impl Deserializable for AccountTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let leaves: Vec<(u64, Word)> =
+            read_bounded_vec(source, core::mem::size_of::<u64>() + Digest::SERIALIZED_SIZE)?;
+        for (index, _) in leaves.iter() {
+            AccountId::try_from(*index).map_err(|err| {
+                DeserializationError::InvalidValue(format!(
+                    "Account tree leaf index {index} is not a valid account ID: {err}"
+                ))
+            })?;
+        }
+
+        SimpleSmt::with_leaves(leaves)
+            .map(Self)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for AccountWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.state_hash.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let state_hash = Option::<Digest>::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        if path.depth() != ACCOUNT_TREE_DEPTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "Account witness path must have depth {ACCOUNT_TREE_DEPTH}, but has depth {}",
+                path.depth()
+            )));
+        }
+
+        Ok(Self { account_id, state_hash, path })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use vm_core::{
+        utils::{ByteWriter, Deserializable, Serializable},
+        Felt,
+    };
+
+    use super::AccountTree;
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        block::account_tree::AccountWitness,
+        Digest,
+    };
+
+    #[test]
+    fn account_tree_updates_witnesses_and_serde() {
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let account_2 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let hash_1 = Digest::from([Felt::new(1), Felt::new(0), Felt::new(0), Felt::new(0)]);
+        let hash_2 = Digest::from([Felt::new(2), Felt::new(0), Felt::new(0), Felt::new(0)]);
+
+        let mut tree = AccountTree::with_entries([(account_1, hash_1)]).unwrap();
+        let initial_root = tree.root();
+        assert_eq!(tree.get(account_1), Some(hash_1));
+        assert_eq!(tree.get(account_2), None);
+
+        // non-membership witness for an account which is not in the tree
+        let witness = tree.open(account_2);
+        assert!(!witness.is_membership());
+        assert!(witness.verify(initial_root));
+
+        let old_values = tree.apply_updates([(account_1, hash_2), (account_2, hash_1)]);
+        assert_eq!(old_values, vec![Some(hash_1), None]);
+        assert_eq!(tree.num_accounts(), 2);
+
+        let witness_1 = tree.open(account_1);
+        assert!(witness_1.is_membership());
+        assert_eq!(witness_1.state_hash(), Some(hash_2));
+        assert!(witness_1.verify(tree.root()));
+        // reverting the updates restores the initial tree
+        tree.apply_updates([
+            (account_1, old_values[0].unwrap()),
+            (account_2, Digest::from(crate::EMPTY_WORD)),
+        ]);
+        assert_eq!(tree.root(), initial_root);
+
+        let bytes = tree.to_bytes();
+        assert_eq!(AccountTree::read_from_bytes(&bytes).unwrap(), tree);
+        // the leaf count must not exceed the number of serialized leaves
+        let mut bytes = Vec::new();
+        bytes.write_usize(usize::MAX);
+        assert!(AccountTree::read_from_bytes(&bytes).is_err());
+        // leaf indices must be valid account IDs
+        let mut bytes = Vec::new();
+        bytes.write_usize(1);
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&hash_1.to_bytes());
+        assert!(AccountTree::read_from_bytes(&bytes).is_err());
+        let bytes = witness_1.to_bytes();
+        assert_eq!(AccountWitness::read_from_bytes(&bytes).unwrap(), witness_1);
+    }
+}
@@ -0,0 +1,492 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use miden_crypto::merkle::{Mmr, MmrPeaks};
+
+use super::{
+    compute_tx_hash, AccountTree, AccountWitness, Block, BlockAccountUpdate, BlockHeader,
+    BlockNoteIndex, BlockNoteTree, NoteBatch, NullifierTree, NullifierWitness,
+};
+use crate::{
+    accounts::{delta::AccountUpdateDetails, AccountId},
+    batches::TransactionBatch,
+    notes::Nullifier,
+    transaction::TransactionId,
+    BlockError, Digest,
+};
+
+// BLOCK BUILDER
+// ================================================================================================
+
+/// Builds a new block on top of the chain state defined by the previous block.
+///
+/// The chain state consists of the account tree, the nullifier tree and the chain MMR as of the
+/// previous block. The builder validates the state against the header of the previous block, and
+/// then:
+/// - Merges the account updates of all batches and applies them to the account tree.
+/// - Inserts the nullifiers of all batches into the nullifier tree, rejecting notes which have
+///   already been consumed.
+/// - Builds the [BlockNoteTree] from the output notes of all batches.
+/// - Adds the hash of the previous block to the chain MMR.
+///
+/// The chain state is updated only if the block was built successfully.
+///
+/// Unauthenticated input notes of the batches are not authenticated by the builder; this must be
+/// done by the block producer before the batches are added to the builder.
+///
+/// Blocks cannot be proven yet, so the proof hash in the header of the built block is always set to
+/// [Digest::default()].
+pub struct BlockBuilder<'a> {
+    prev_header: BlockHeader,
+    account_tree: &'a mut AccountTree,
+    nullifier_tree: &'a mut NullifierTree,
+    chain_mmr: &'a mut Mmr,
+    timestamp: u32,
+    batches: Vec<TransactionBatch>,
+}
+
+impl<'a> BlockBuilder<'a> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockBuilder] for the block following `prev_header`.
+    ///
+    /// The chain MMR must contain the hashes of all blocks preceding `prev_header`, i.e., its
+    /// peaks must commit to the chain root of the previous block.
+    pub fn new(
+        prev_header: BlockHeader,
+        account_tree: &'a mut AccountTree,
+        nullifier_tree: &'a mut NullifierTree,
+        chain_mmr: &'a mut Mmr,
+        timestamp: u32,
+    ) -> Self {
+        Self {
+            prev_header,
+            account_tree,
+            nullifier_tree,
+            chain_mmr,
+            timestamp,
+            batches: Vec::new(),
+        }
+    }
+
+    /// Adds the specified batches to the block. The batches are applied in the order in which
+    /// they were added.
+    pub fn add_batches(mut self, batches: impl IntoIterator<Item = TransactionBatch>) -> Self {
+        self.batches.extend(batches);
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the new block, updates the chain state and returns the block together with the
+    /// witnesses of all changes made to the chain state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account tree, the nullifier tree or the chain MMR do not match the roots committed to
+    ///   in the previous block header.
+    /// - Updates of the same account in different batches could not be merged.
+    /// - An account update does not start from the current state of the account, or, for accounts
+    ///   which are not yet in the account tree, from the empty state.
+    /// - A nullifier is consumed more than once in the block or has already been consumed in a
+    ///   previous block.
+    /// - The block exceeds any of the block limits.
+    pub fn build(self) -> Result<BlockOutputs, BlockError> {
+        let prev_header = self.prev_header;
+        let block_num = prev_header.block_num() + 1;
+
+        // validate the chain state against the previous block header
+        if self.account_tree.root() != prev_header.account_root() {
+            return Err(BlockError::AccountTreeRootMismatch {
+                expected: prev_header.account_root(),
+                actual: self.account_tree.root(),
+            });
+        }
+        if self.nullifier_tree.root() != prev_header.nullifier_root() {
+            return Err(BlockError::NullifierTreeRootMismatch {
+                expected: prev_header.nullifier_root(),
+                actual: self.nullifier_tree.root(),
+            });
+        }
+        let chain_peaks = self.chain_mmr.peaks();
+        if chain_peaks.hash_peaks() != prev_header.chain_root() {
+            return Err(BlockError::ChainMmrRootMismatch {
+                expected: prev_header.chain_root(),
+                actual: chain_peaks.hash_peaks(),
+            });
+        }
+
+        // check account updates and nullifiers against the current chain state
+        let (updated_accounts, account_witnesses) =
+            check_account_updates(self.account_tree, &self.batches)?;
+        let (nullifiers, nullifier_witnesses) =
+            check_nullifiers(self.nullifier_tree, &self.batches)?;
+
+        // build the note tree
+        let output_note_batches: Vec<NoteBatch> =
+            self.batches.iter().map(|batch| batch.output_notes().to_vec()).collect();
+        let mut note_entries = Vec::new();
+        for (batch_idx, notes) in output_note_batches.iter().enumerate() {
+            for (note_idx_in_batch, note) in notes.iter().enumerate() {
+                let index = BlockNoteIndex::new(batch_idx, note_idx_in_batch)?;
+                note_entries.push((index, note.id(), *note.metadata()));
+            }
+        }
+        let note_tree = BlockNoteTree::with_entries(note_entries)
+            .expect("note indices should be unique and within the bounds of the note tree");
+
+        Block::validate_contents(&updated_accounts, &output_note_batches, &nullifiers)?;
+
+        // all checks passed; update the chain state
+        self.account_tree.apply_updates(
+            account_witnesses
+                .iter()
+                .map(|witness| (witness.initial_state.account_id(), witness.final_state_hash)),
+        );
+        self.nullifier_tree
+            .apply_updates(nullifiers.iter().copied(), block_num)
+            .expect("blocks built on a previous block should not be the genesis block");
+        self.chain_mmr.add(prev_header.hash());
+
+        let tx_hash = compute_tx_hash(updated_accounts.iter().flat_map(|update| {
+            update.transactions().iter().map(|tx_id| (*tx_id, update.account_id()))
+        }));
+
+        // TODO: Set `proof_hash` to the correct value once the block kernel is available. Until
+        // then, it is set to the default digest, as documented on `BlockBuilder`.
+        let header = BlockHeader::new(
+            prev_header.version(),
+            prev_header.hash(),
+            block_num,
+            self.chain_mmr.peaks().hash_peaks(),
+            self.account_tree.root(),
+            self.nullifier_tree.root(),
+            note_tree.root(),
+            tx_hash,
+            prev_header.kernel_root(),
+            Digest::default(),
+            self.timestamp,
+        );
+
+        let block = Block::new(header, updated_accounts, output_note_batches, nullifiers)
+            .expect("block contents should have been validated");
+
+        Ok(BlockOutputs {
+            block,
+            account_witnesses,
+            nullifier_witnesses,
+            chain_peaks,
+        })
+    }
+}
+
+// BLOCK OUTPUTS
+// ================================================================================================
+
+/// The result of building a block with a [BlockBuilder].
+///
+/// Besides the new block, contains the witnesses required to verify the transition of the chain
+/// state from the previous block to the new block. All witnesses open the state trees as of the
+/// previous block.
+#[derive(Debug, Clone)]
+pub struct BlockOutputs {
+    block: Block,
+    account_witnesses: Vec<AccountUpdateWitness>,
+    nullifier_witnesses: Vec<NullifierWitness>,
+    chain_peaks: MmrPeaks,
+}
+
+impl BlockOutputs {
+    /// Returns the new block.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns the header of the new block.
+    pub fn header(&self) -> BlockHeader {
+        self.block.header()
+    }
+
+    /// Returns the witnesses of the account updates applied by the new block.
+    pub fn account_witnesses(&self) -> &[AccountUpdateWitness] {
+        &self.account_witnesses
+    }
+
+    /// Returns the witnesses of the nullifiers created by the new block.
+    pub fn nullifier_witnesses(&self) -> &[NullifierWitness] {
+        &self.nullifier_witnesses
+    }
+
+    /// Returns the peaks of the chain MMR before the hash of the previous block was added to it.
+    pub fn chain_peaks(&self) -> &MmrPeaks {
+        &self.chain_peaks
+    }
+
+    /// Consumes the outputs and returns the new block.
+    pub fn into_block(self) -> Block {
+        self.block
+    }
+}
+
+// WITNESSES
+// ================================================================================================
+
+/// Witness of an update of a single account in the account tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdateWitness {
+    /// Witness of the account state as of the previous block. For new accounts, this is a
+    /// non-membership witness.
+    pub initial_state: AccountWitness,
+
+    /// Hash of the account state as of the new block.
+    pub final_state_hash: Digest,
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Merges the account updates of the provided batches and checks them against the current state
+/// of the account tree.
+///
+/// Returns the merged block account updates together with the witnesses of the updates.
+fn check_account_updates(
+    account_tree: &AccountTree,
+    batches: &[TransactionBatch],
+) -> Result<(Vec<BlockAccountUpdate>, Vec<AccountUpdateWitness>), BlockError> {
+    struct MergedUpdate {
+        init_state_hash: Digest,
+        final_state_hash: Digest,
+        details: AccountUpdateDetails,
+        transactions: Vec<TransactionId>,
+    }
+
+    let mut merged_updates = BTreeMap::<AccountId, MergedUpdate>::new();
+    for update in batches.iter().flat_map(|batch| batch.updated_accounts()) {
+        let account_id = update.account_id();
+        match merged_updates.get_mut(&account_id) {
+            Some(merged) => {
+                if merged.final_state_hash != update.init_state_hash() {
+                    return Err(BlockError::InconsistentAccountStateTransition {
+                        account_id,
+                        expected: merged.final_state_hash,
+                        actual: update.init_state_hash(),
+                    });
+                }
+                let details =
+                    core::mem::replace(&mut merged.details, AccountUpdateDetails::Private);
+                merged.details = details
+                    .merge(update.details().clone())
+                    .map_err(|error| BlockError::AccountUpdateError { account_id, error })?;
+                merged.final_state_hash = update.final_state_hash();
+                merged.transactions.extend_from_slice(update.transactions());
+            },
+            None => {
+                merged_updates.insert(
+                    account_id,
+                    MergedUpdate {
+                        init_state_hash: update.init_state_hash(),
+                        final_state_hash: update.final_state_hash(),
+                        details: update.details().clone(),
+                        transactions: update.transactions().to_vec(),
+                    },
+                );
+            },
+        }
+    }
+
+    let mut updated_accounts = Vec::with_capacity(merged_updates.len());
+    let mut witnesses = Vec::with_capacity(merged_updates.len());
+    for (account_id, update) in merged_updates {
+        let initial_state = account_tree.open(account_id);
+
+        // accounts which are not yet in the tree are created by this block, and must start from
+        // the empty state
+        let current_state_hash = initial_state.state_hash().unwrap_or_default();
+        if current_state_hash != update.init_state_hash {
+            return Err(BlockError::InconsistentAccountStateTransition {
+                account_id,
+                expected: current_state_hash,
+                actual: update.init_state_hash,
+            });
+        }
+
+        witnesses.push(AccountUpdateWitness {
+            initial_state,
+            final_state_hash: update.final_state_hash,
+        });
+        updated_accounts.push(BlockAccountUpdate::new(
+            account_id,
+            update.final_state_hash,
+            update.details,
+            update.transactions,
+        ));
+    }
+
+    Ok((updated_accounts, witnesses))
+}
+
+/// Collects the nullifiers of the provided batches and checks that none of them has been consumed
+/// before.
+///
+/// Returns the nullifiers together with their non-membership witnesses.
+fn check_nullifiers(
+    nullifier_tree: &NullifierTree,
+    batches: &[TransactionBatch],
+) -> Result<(Vec<Nullifier>, Vec<NullifierWitness>), BlockError> {
+    let mut unique_nullifiers = BTreeSet::new();
+    let mut nullifiers = Vec::new();
+    let mut witnesses = Vec::new();
+    for nullifier in batches.iter().flat_map(|batch| batch.nullifiers()) {
+        if !unique_nullifiers.insert(nullifier) {
+            return Err(BlockError::DuplicateNullifier(nullifier));
+        }
+
+        let witness = nullifier_tree.open(&nullifier);
+        if let Some(block_num) = witness.block_num() {
+            return Err(BlockError::NullifierAlreadySpent { nullifier, block_num });
+        }
+
+        witnesses.push(witness);
+        nullifiers.push(nullifier);
+    }
+
+    Ok((nullifiers, witnesses))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::merkle::Mmr;
+
+    use super::BlockBuilder;
+    use crate::{
+        batches::TransactionBatch,
+        block::{AccountTree, NullifierTree},
+        notes::Nullifier,
+        testing::transaction::{mock_account_id, mock_digest},
+        transaction::{ProvenTransaction, ProvenTransactionBuilder},
+        BlockError, BlockHeader, Digest,
+    };
+
+    fn mock_tx(init_state: u64, final_state: u64, nullifier: Nullifier) -> ProvenTransaction {
+        ProvenTransactionBuilder::mock_update(init_state, final_state)
+            .add_input_notes([nullifier])
+            .build()
+            .unwrap()
+    }
+
+    /// Returns the genesis block header for the provided chain state.
+    fn genesis(
+        account_tree: &AccountTree,
+        nullifier_tree: &NullifierTree,
+        chain_mmr: &Mmr,
+    ) -> BlockHeader {
+        BlockHeader::new(
+            0,
+            Digest::default(),
+            0,
+            chain_mmr.peaks().hash_peaks(),
+            account_tree.root(),
+            nullifier_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn block_builder_applies_batches() {
+        let mut account_tree = AccountTree::new();
+        let mut nullifier_tree = NullifierTree::new();
+        let mut chain_mmr = Mmr::new();
+        let genesis = genesis(&account_tree, &nullifier_tree, &chain_mmr);
+
+        // the account is created by the block, so its update starts from the empty state
+        let nullifier = Nullifier::from(mock_digest(10));
+        let batch = TransactionBatch::new(&[mock_tx(0, 2, nullifier)]).unwrap();
+        let outputs =
+            BlockBuilder::new(genesis, &mut account_tree, &mut nullifier_tree, &mut chain_mmr, 1)
+                .add_batches([batch])
+                .build()
+                .unwrap();
+
+        let header = outputs.header();
+        assert_eq!(header.block_num(), 1);
+        assert_eq!(header.prev_hash(), genesis.hash());
+        assert_eq!(header.account_root(), account_tree.root());
+        assert_eq!(header.nullifier_root(), nullifier_tree.root());
+        assert_eq!(header.chain_root(), chain_mmr.peaks().hash_peaks());
+        assert_eq!(outputs.block().nullifiers(), &[nullifier]);
+
+        // witnesses open the trees as of the previous block
+        let witness = &outputs.account_witnesses()[0];
+        assert_eq!(witness.final_state_hash, mock_digest(2));
+        assert!(!witness.initial_state.is_membership());
+        assert!(witness.initial_state.verify(genesis.account_root()));
+        assert_eq!(
+            witness.initial_state.compute_root_with(Some(witness.final_state_hash)),
+            header.account_root()
+        );
+        assert!(outputs.nullifier_witnesses()[0].verify(genesis.nullifier_root()));
+
+        // consuming the same note again must fail and leave the chain state unchanged
+        let batch = TransactionBatch::new(&[mock_tx(2, 3, nullifier)]).unwrap();
+        let account_root = account_tree.root();
+        let result =
+            BlockBuilder::new(header, &mut account_tree, &mut nullifier_tree, &mut chain_mmr, 2)
+                .add_batches([batch])
+                .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BlockError::NullifierAlreadySpent { nullifier, block_num: 1 }
+        );
+        assert_eq!(account_tree.root(), account_root);
+        assert_eq!(chain_mmr.peaks().hash_peaks(), header.chain_root());
+    }
+
+    #[test]
+    fn block_builder_rejects_invalid_updates() {
+        let mut account_tree = AccountTree::new();
+        let mut nullifier_tree = NullifierTree::new();
+        let mut chain_mmr = Mmr::new();
+        let genesis = genesis(&account_tree, &nullifier_tree, &chain_mmr);
+
+        // an account which is not in the tree must start from the empty state
+        let batch =
+            TransactionBatch::new(&[mock_tx(1, 2, Nullifier::from(mock_digest(10)))]).unwrap();
+        let result =
+            BlockBuilder::new(genesis, &mut account_tree, &mut nullifier_tree, &mut chain_mmr, 1)
+                .add_batches([batch])
+                .build();
+        assert_eq!(
+            result.unwrap_err(),
+            BlockError::InconsistentAccountStateTransition {
+                account_id: mock_account_id(),
+                expected: Digest::default(),
+                actual: mock_digest(1),
+            }
+        );
+
+        // the same note must not be consumed twice in a block
+        let nullifier = Nullifier::from(mock_digest(10));
+        let batches = [
+            TransactionBatch::new(&[mock_tx(0, 2, nullifier)]).unwrap(),
+            TransactionBatch::new(&[mock_tx(2, 3, nullifier)]).unwrap(),
+        ];
+        let result =
+            BlockBuilder::new(genesis, &mut account_tree, &mut nullifier_tree, &mut chain_mmr, 1)
+                .add_batches(batches)
+                .build();
+        assert_eq!(result.unwrap_err(), BlockError::DuplicateNullifier(nullifier));
+        assert_eq!(account_tree.root(), genesis.account_root());
+        assert_eq!(nullifier_tree.root(), genesis.nullifier_root());
+    }
+}
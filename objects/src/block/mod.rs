@@ -5,10 +5,16 @@ use super::{
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK, ZERO,
 };
 
+mod account_tree;
+pub use account_tree::{AccountTree, AccountWitness};
+mod builder;
+pub use builder::{AccountUpdateWitness, BlockBuilder, BlockOutputs};
 mod header;
 pub use header::BlockHeader;
 mod note_tree;
 pub use note_tree::{BlockNoteIndex, BlockNoteTree};
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierTree, NullifierWitness};
 
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountId},
@@ -152,26 +158,36 @@ impl Block {
     // --------------------------------------------------------------------------------------------
 
     fn validate(&self) -> Result<(), BlockError> {
-        let account_count = self.updated_accounts.len();
+        Self::validate_contents(&self.updated_accounts, &self.output_note_batches, &self.nullifiers)
+    }
+
+    /// Checks that the provided block contents do not exceed the block limits and that no note is
+    /// created more than once.
+    fn validate_contents(
+        updated_accounts: &[BlockAccountUpdate],
+        output_note_batches: &[NoteBatch],
+        nullifiers: &[Nullifier],
+    ) -> Result<(), BlockError> {
+        let account_count = updated_accounts.len();
         if account_count > MAX_ACCOUNTS_PER_BLOCK {
             return Err(BlockError::TooManyAccountUpdates(account_count));
         }
 
-        let batch_count = self.output_note_batches.len();
+        let batch_count = output_note_batches.len();
         if batch_count > MAX_BATCHES_PER_BLOCK {
             return Err(BlockError::TooManyTransactionBatches(batch_count));
         }
 
         // We can't check input notes here because they're not stored in the block,
         // so we check that nullifier count is not bigger than maximum input notes per block.
-        let nullifier_count = self.nullifiers.len();
+        let nullifier_count = nullifiers.len();
         if nullifier_count > MAX_INPUT_NOTES_PER_BLOCK {
             return Err(BlockError::TooManyNullifiersInBlock(nullifier_count));
         }
 
         let mut output_notes = BTreeSet::new();
         let mut output_note_count = 0;
-        for batch in self.output_note_batches.iter() {
+        for batch in output_note_batches.iter() {
             // TODO: We should construct blocks from something like `TransactionBatch` structs.
             //       Then, we'll check that transaction batches have the right number of
             //       nullifiers/output notes and we won't need to do such checks here.
@@ -0,0 +1,345 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_crypto::merkle::{Smt, SmtProof};
+
+use crate::{
+    notes::Nullifier,
+    utils::{
+        read_bounded_vec, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable,
+    },
+    Digest, Felt, NullifierTreeError, Word, EMPTY_WORD, ZERO,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// Wrapper over [Smt] for the nullifier tree.
+///
+/// Each consumed note is recorded under the key defined by its nullifier, and the value of the
+/// entry is `[block_num, 0, 0, 0]`, where `block_num` is the number of the block in which the note
+/// was consumed. Nullifiers of notes which have not been consumed map to [crate::EMPTY_WORD].
+///
+/// Since the entry of a note consumed in the genesis block would be indistinguishable from the
+/// entry of a note which has not been consumed, nullifiers cannot be created in the genesis block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierTree {
+    smt: Smt,
+    num_nullifiers: usize,
+}
+
+impl NullifierTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self { smt: Smt::new(), num_nullifiers: 0 }
+    }
+
+    /// Returns a new [NullifierTree] instantiated with the provided nullifiers and the numbers of
+    /// the blocks in which they were created.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided entries contain the same nullifier more than once.
+    /// - Any of the nullifiers was created in the genesis block.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, u32)>,
+    ) -> Result<Self, NullifierTreeError> {
+        let entries = entries
+            .into_iter()
+            .map(|(nullifier, block_num)| {
+                if block_num == 0 {
+                    return Err(NullifierTreeError::NullifierInGenesisBlock(nullifier));
+                }
+                Ok((nullifier.inner(), block_num_to_leaf_value(block_num)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let num_nullifiers = entries.len();
+
+        Smt::with_entries(entries)
+            .map(|smt| Self { smt, num_nullifiers })
+            .map_err(NullifierTreeError::DuplicateNullifiers)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.smt.root()
+    }
+
+    /// Returns the number of nullifiers in the tree.
+    pub fn num_nullifiers(&self) -> usize {
+        self.num_nullifiers
+    }
+
+    /// Returns the number of the block in which the specified nullifier was created, or `None`
+    /// if the nullifier is not in the tree.
+    pub fn get_block_num(&self, nullifier: &Nullifier) -> Option<u32> {
+        leaf_value_to_block_num(self.smt.get_value(&nullifier.inner()))
+            .expect("tree should contain only valid entries")
+    }
+
+    /// Returns a membership witness for the specified nullifier if it is in the tree, or a
+    /// non-membership witness otherwise.
+    pub fn open(&self, nullifier: &Nullifier) -> NullifierWitness {
+        NullifierWitness {
+            nullifier: *nullifier,
+            proof: self.smt.open(&nullifier.inner()),
+        }
+    }
+
+    /// Returns an iterator over the nullifiers in the tree and the numbers of the blocks in which
+    /// they were created.
+    pub fn nullifiers(&self) -> impl Iterator<Item = (Nullifier, u32)> + '_ {
+        self.smt.entries().map(|(key, value)| {
+            let block_num = leaf_value_to_block_num(*value)
+                .expect("tree should contain only valid entries")
+                .expect("tree should not contain empty values");
+            (Nullifier::from(*key), block_num)
+        })
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the specified nullifiers as created in the specified block and returns the block
+    /// numbers previously recorded for them, in the same order. Previous block numbers of
+    /// nullifiers which were not in the tree are set to `None`.
+    ///
+    /// This method does not reject nullifiers which are already in the tree; callers are expected
+    /// to check the returned values.
+    ///
+    /// # Errors
+    /// Returns an error if any nullifier is provided for the genesis block, in which case the tree
+    /// is left unchanged.
+    pub fn apply_updates(
+        &mut self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: u32,
+    ) -> Result<Vec<Option<u32>>, NullifierTreeError> {
+        let mut nullifiers = nullifiers.into_iter().peekable();
+        if block_num == 0 {
+            if let Some(nullifier) = nullifiers.peek() {
+                return Err(NullifierTreeError::NullifierInGenesisBlock(*nullifier));
+            }
+        }
+
+        let value = block_num_to_leaf_value(block_num);
+        let old_block_nums = nullifiers
+            .map(|nullifier| {
+                let old_block_num =
+                    leaf_value_to_block_num(self.smt.insert(nullifier.inner(), value))
+                        .expect("tree should contain only valid entries");
+                if old_block_num.is_none() {
+                    self.num_nullifiers += 1;
+                }
+                old_block_num
+            })
+            .collect();
+
+        Ok(old_block_nums)
+    }
+}
+
+impl Default for NullifierTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// NULLIFIER WITNESS
+// ================================================================================================
+
+/// A witness of the state of a single nullifier in a [NullifierTree].
+///
+/// If the nullifier is in the tree, this is a membership witness for the number of the block in
+/// which the nullifier was created. Otherwise, this is a non-membership witness, i.e., a proof
+/// that the note has not been consumed yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierWitness {
+    nullifier: Nullifier,
+    proof: SmtProof,
+}
+
+impl NullifierWitness {
+    /// Returns the nullifier.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the underlying proof of the nullifier entry.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the number of the block in which the nullifier was created, or `None` if this is a
+    /// non-membership witness.
+    pub fn block_num(&self) -> Option<u32> {
+        leaf_value_to_block_num(
+            self.proof
+                .get(&self.nullifier.inner())
+                .expect("proof should open the nullifier"),
+        )
+        .expect("proof should open a valid nullifier tree entry")
+    }
+
+    /// Returns true if this witness proves that the nullifier is in the tree.
+    pub fn is_membership(&self) -> bool {
+        self.block_num().is_some()
+    }
+
+    /// Returns the root of the nullifier tree this witness was computed against.
+    pub fn compute_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns true if this witness is valid against the provided nullifier tree root.
+    pub fn verify(&self, root: Digest) -> bool {
+        self.compute_root() == root
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the value of the nullifier tree entry for a nullifier created in the specified block.
+fn block_num_to_leaf_value(block_num: u32) -> Word {
+    [Felt::from(block_num), ZERO, ZERO, ZERO]
+}
+
+/// Returns the block number encoded in the provided nullifier tree entry, or `None` if the entry
+/// is empty.
+///
+/// # Errors
+/// Returns an error if the entry is neither empty nor a valid nullifier tree entry.
+fn leaf_value_to_block_num(value: Word) -> Result<Option<u32>, String> {
+    if value == EMPTY_WORD {
+        return Ok(None);
+    }
+    if value[1..] != [ZERO, ZERO, ZERO] {
+        return Err(format!("Invalid nullifier tree entry {value:?}"));
+    }
+
+    u32::try_from(value[0].as_int())
+        .map(Some)
+        .map_err(|_| format!("Block number {} of nullifier tree entry exceeds u32::MAX", value[0]))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let nullifiers: Vec<(Nullifier, u32)> = self.nullifiers().collect();
+        nullifiers.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifiers: Vec<(Nullifier, u32)> =
+            read_bounded_vec(source, Digest::SERIALIZED_SIZE + core::mem::size_of::<u32>())?;
+
+        Self::with_entries(nullifiers)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for NullifierWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        let value = proof.get(&nullifier.inner()).ok_or_else(|| {
+            DeserializationError::InvalidValue(
+                "Nullifier witness proof does not open the nullifier".to_string(),
+            )
+        })?;
+        leaf_value_to_block_num(value).map_err(DeserializationError::InvalidValue)?;
+
+        Ok(Self { nullifier, proof })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::{
+        utils::{Deserializable, Serializable},
+        Felt,
+    };
+
+    use miden_crypto::merkle::Smt;
+
+    use super::{NullifierTree, NullifierWitness};
+    use crate::{notes::Nullifier, Digest, NullifierTreeError, ONE, ZERO};
+
+    fn nullifier(value: u64) -> Nullifier {
+        Nullifier::from(Digest::from([Felt::new(value), Felt::new(0), Felt::new(0), Felt::new(0)]))
+    }
+
+    #[test]
+    fn nullifier_tree_updates_witnesses_and_serde() {
+        let mut tree = NullifierTree::with_entries([(nullifier(1), 5), (nullifier(3), 1)]).unwrap();
+        let initial_root = tree.root();
+        assert_eq!(tree.get_block_num(&nullifier(1)), Some(5));
+        assert_eq!(tree.get_block_num(&nullifier(2)), None);
+        assert_eq!(tree.get_block_num(&nullifier(3)), Some(1));
+
+        // entries are stored as `[block_num, 0, 0, 0]`
+        let smt = Smt::with_entries([
+            (nullifier(1).inner(), [Felt::new(5), ZERO, ZERO, ZERO]),
+            (nullifier(3).inner(), [ONE, ZERO, ZERO, ZERO]),
+        ])
+        .unwrap();
+        assert_eq!(initial_root, smt.root());
+
+        // nullifiers cannot be created in the genesis block, since they would be indistinguishable
+        // from unspent ones
+        assert_eq!(
+            NullifierTree::with_entries([(nullifier(2), 0)]),
+            Err(NullifierTreeError::NullifierInGenesisBlock(nullifier(2)))
+        );
+        assert_eq!(
+            tree.apply_updates([nullifier(2)], 0),
+            Err(NullifierTreeError::NullifierInGenesisBlock(nullifier(2)))
+        );
+        assert_eq!(tree.apply_updates([], 0), Ok(vec![]));
+        assert_eq!(tree.root(), initial_root);
+
+        // non-membership witness for a nullifier which is not in the tree
+        let witness = tree.open(&nullifier(2));
+        assert!(!witness.is_membership());
+        assert!(witness.verify(initial_root));
+
+        let old_values = tree.apply_updates([nullifier(1), nullifier(2)], 7).unwrap();
+        assert_eq!(old_values, vec![Some(5), None]);
+        assert_eq!(tree.num_nullifiers(), 3);
+
+        let witness = tree.open(&nullifier(2));
+        assert_eq!(witness.block_num(), Some(7));
+        assert!(witness.verify(tree.root()));
+        assert!(!witness.verify(initial_root));
+
+        let bytes = tree.to_bytes();
+        assert_eq!(NullifierTree::read_from_bytes(&bytes).unwrap(), tree);
+        let bytes = witness.to_bytes();
+        assert_eq!(NullifierWitness::read_from_bytes(&bytes).unwrap(), witness);
+    }
+}
@@ -7,9 +7,11 @@ use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
-    Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
-    MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
+    Digest, Word, MAX_ACCOUNTS_PER_BATCH, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK,
+    MAX_INPUT_NOTES_PER_BATCH, MAX_INPUT_NOTES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH,
+    MAX_OUTPUT_NOTES_PER_BLOCK,
 };
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountType},
@@ -48,6 +50,16 @@ pub enum AccountError {
         current: u64,
         new: u64,
     },
+    PartialAccountInconsistentCodeCommitment {
+        expected: Digest,
+        actual: Digest,
+    },
+    PartialAccountInconsistentStorageCommitment {
+        expected: Digest,
+        actual: Digest,
+    },
+    PartialAccountStorageMapNotFound(Digest),
+    PartialAccountVaultProofInvalid(Digest),
     SeedDigestTooFewTrailingZeros {
         expected: u32,
         actual: u32,
@@ -282,12 +294,15 @@ pub enum TransactionInputError {
     AccountSeedNotProvidedForNewAccount,
     AccountSeedProvidedForExistingAccount,
     DuplicateInputNote(Digest),
+    ForeignAccountNotInAccountTree(AccountId),
     InconsistentAccountSeed { expected: AccountId, actual: AccountId },
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
     InputNoteBlockNotInChainMmr(NoteId),
     InputNoteNotInBlock(NoteId, u32),
     InvalidAccountSeed(AccountError),
+    PartialAccountMustNotBeNew(AccountId),
+    TooManyForeignAccounts { max: usize, actual: usize },
     TooManyInputNotes { max: usize, actual: usize },
 }
 
@@ -384,12 +399,102 @@ impl fmt::Display for ProvenTransactionError {
 #[cfg(feature = "std")]
 impl std::error::Error for ProvenTransactionError {}
 
+// TRANSACTION BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    AccountUpdateError {
+        account_id: AccountId,
+        error: AccountDeltaError,
+    },
+    DuplicateInputNote(Nullifier),
+    DuplicateOutputNote(NoteId),
+    DuplicateTransaction(TransactionId),
+    InconsistentAccountStateTransition {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    NoteHashesMismatch {
+        id: NoteId,
+        input_hash: Digest,
+        output_hash: Digest,
+    },
+    TooManyAccountsInBatch(usize),
+    TooManyInputNotes(usize),
+    TooManyOutputNotes(usize),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::AccountUpdateError { account_id, error } => {
+                write!(f, "Failed to merge the updates of account {account_id}: {error}")
+            },
+            BatchError::DuplicateInputNote(nullifier) => {
+                write!(f, "Note with nullifier {nullifier} is consumed more than once in the batch")
+            },
+            BatchError::DuplicateOutputNote(id) => {
+                write!(f, "Note {id} is created more than once in the batch")
+            },
+            BatchError::DuplicateTransaction(id) => {
+                write!(f, "Transaction {id} is included more than once in the batch")
+            },
+            BatchError::InconsistentAccountStateTransition { account_id, expected, actual } => {
+                write!(f, "Transaction against account {account_id} starts from state {actual}, but the previous transaction in the batch ended in state {expected}")
+            },
+            BatchError::NoteHashesMismatch { id, input_hash, output_hash } => {
+                write!(f, "Unauthenticated input note {id} has hash {input_hash}, but the note created in the batch has hash {output_hash}")
+            },
+            BatchError::TooManyAccountsInBatch(actual) => {
+                write!(f, "Too many accounts updated in a batch. Max: {MAX_ACCOUNTS_PER_BATCH}, actual: {actual}")
+            },
+            BatchError::TooManyInputNotes(actual) => {
+                write!(f, "Too many input notes in a batch. Max: {MAX_INPUT_NOTES_PER_BATCH}, actual: {actual}")
+            },
+            BatchError::TooManyOutputNotes(actual) => {
+                write!(f, "Too many output notes in a batch. Max: {MAX_OUTPUT_NOTES_PER_BATCH}, actual: {actual}")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
 // BLOCK VALIDATION ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockError {
+    AccountTreeRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    AccountUpdateError {
+        account_id: AccountId,
+        error: AccountDeltaError,
+    },
+    ChainMmrRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     DuplicateNoteFound(NoteId),
+    DuplicateNullifier(Nullifier),
+    InconsistentAccountStateTransition {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: u32,
+    },
+    NullifierTreeRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
     TooManyAccountUpdates(usize),
     TooManyNotesInBatch(usize),
     TooManyNotesInBlock(usize),
@@ -400,9 +505,30 @@ pub enum BlockError {
 impl fmt::Display for BlockError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            BlockError::AccountTreeRootMismatch { expected, actual } => {
+                write!(f, "Account tree root {actual} does not match the account root {expected} of the previous block")
+            },
+            BlockError::AccountUpdateError { account_id, error } => {
+                write!(f, "Failed to merge the updates of account {account_id}: {error}")
+            },
+            BlockError::ChainMmrRootMismatch { expected, actual } => {
+                write!(f, "Chain MMR root {actual} does not match the chain root {expected} of the previous block")
+            },
             BlockError::DuplicateNoteFound(id) => {
                 write!(f, "Duplicate note {id} found in the block")
             },
+            BlockError::DuplicateNullifier(nullifier) => {
+                write!(f, "Nullifier {nullifier} is consumed more than once in the block")
+            },
+            BlockError::InconsistentAccountStateTransition { account_id, expected, actual } => {
+                write!(f, "Update of account {account_id} starts from state {actual}, but the current state of the account is {expected}")
+            },
+            BlockError::NullifierAlreadySpent { nullifier, block_num } => {
+                write!(f, "Nullifier {nullifier} was already spent in block {block_num}")
+            },
+            BlockError::NullifierTreeRootMismatch { expected, actual } => {
+                write!(f, "Nullifier tree root {actual} does not match the nullifier root {expected} of the previous block")
+            },
             BlockError::TooManyAccountUpdates(actual) => {
                 write!(f, "Too many accounts updated in a block. Max: {MAX_ACCOUNTS_PER_BLOCK}, actual: {actual}")
             },
@@ -430,3 +556,28 @@ impl fmt::Display for BlockError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for BlockError {}
+
+// NULLIFIER TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NullifierTreeError {
+    DuplicateNullifiers(MerkleError),
+    NullifierInGenesisBlock(Nullifier),
+}
+
+impl fmt::Display for NullifierTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NullifierTreeError::DuplicateNullifiers(err) => {
+                write!(f, "Nullifier tree entries contain the same nullifier more than once: {err}")
+            },
+            NullifierTreeError::NullifierInGenesisBlock(nullifier) => {
+                write!(f, "Nullifier {nullifier} cannot be created in the genesis block")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NullifierTreeError {}
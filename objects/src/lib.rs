@@ -25,9 +25,9 @@ mod errors;
 pub use block::BlockHeader;
 pub use constants::*;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, AssetVaultError, BlockError, ChainMmrError,
-    NoteError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
-    TransactionScriptError,
+    AccountDeltaError, AccountError, AssetError, AssetVaultError, BatchError, BlockError,
+    ChainMmrError, NoteError, NullifierTreeError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
@@ -53,6 +53,36 @@ pub mod utils {
         };
     }
 
+    use alloc::vec::Vec;
+
+    /// Reads a length-prefixed vector of elements from the provided source, where each element is
+    /// known to take up at least `min_element_size` bytes.
+    ///
+    /// Unlike [ByteReader::read_many], this does not preallocate memory for a length which the
+    /// remaining input cannot hold, and thus can be used safely with untrusted input.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of elements times `min_element_size` exceeds the remaining input or
+    ///   `isize::MAX` bytes.
+    /// - Any of the elements could not be deserialized.
+    pub fn read_bounded_vec<R: ByteReader, T: Deserializable>(
+        source: &mut R,
+        min_element_size: usize,
+    ) -> Result<Vec<T>, DeserializationError> {
+        let num_elements = source.read_usize()?;
+        let num_bytes = num_elements
+            .checked_mul(min_element_size)
+            .filter(|num_bytes| *num_bytes <= isize::MAX as usize)
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue(format!(
+                    "Too many elements to read: {num_elements}"
+                ))
+            })?;
+        source.check_eor(num_bytes)?;
+        source.read_many(num_elements)
+    }
+
     /// Construct a new `Digest` from four `u64` values.
     #[macro_export]
     macro_rules! digest {
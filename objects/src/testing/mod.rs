@@ -13,6 +13,7 @@ pub mod block;
 pub mod constants;
 pub mod notes;
 pub mod storage;
+pub mod transaction;
 
 /// Converts a word to MASM
 pub fn prepare_word(word: &Word) -> String {
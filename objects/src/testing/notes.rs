@@ -7,8 +7,10 @@ use assembly::Assembler;
 use rand::Rng;
 
 use crate::{
-    accounts::AccountId,
-    assets::Asset,
+    accounts::{
+        account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, AccountId,
+    },
+    assets::{Asset, FungibleAsset},
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
         NoteTag, NoteType,
@@ -111,6 +113,36 @@ impl NoteBuilder {
     }
 }
 
+// NOTE
+// ================================================================================================
+
+impl Note {
+    /// Returns a public note sent by the account with ID
+    /// [ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN], which carries 10 units of the mock
+    /// fungible asset.
+    ///
+    /// The serial number of the note is derived from `num`, so that the notes created for
+    /// different numbers are distinct.
+    pub fn mock(num: u64) -> Self {
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
+            .expect("mock account ID should be valid");
+        let serial_num = [Felt::new(num), Felt::new(1), Felt::new(2), Felt::new(3)];
+        let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), NoteInputs::default());
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(123),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .expect("mock note metadata should be valid");
+        let assets = NoteAssets::new(vec![FungibleAsset::mock(10)])
+            .expect("mock note assets should be valid");
+
+        Note::new(assets, metadata, recipient)
+    }
+}
+
 // NOTE SCRIPT
 // ================================================================================================
 
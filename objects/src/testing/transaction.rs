@@ -0,0 +1,72 @@
+use miden_verifier::ExecutionProof;
+
+use crate::{
+    accounts::{
+        account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, AccountId,
+    },
+    transaction::ProvenTransactionBuilder,
+    utils::Deserializable,
+    Digest, Felt, ZERO,
+};
+
+/// Serialized execution proof of an empty STARK proof for a trace of width 1 and length 8,
+/// generated with BLAKE3 (192-bit).
+///
+/// The proof is kept in its serialized form so that mock transactions can be created without
+/// depending on the prover.
+const DUMMY_PROOF_BYTES: [u8; 60] = [
+    1, 0, 0, 3, 0, 0, 8, 1, 0, 0, 0, 255, 255, 255, 255, 1, 2, 2, 1, 8, 1, 1, 1, 0, 0, 0, 1, 1, 17,
+    1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Returns the ID of the account updated by the transactions of
+/// [ProvenTransactionBuilder::mock_update].
+pub fn mock_account_id() -> AccountId {
+    AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN)
+        .expect("mock account ID should be valid")
+}
+
+/// Returns a digest whose first element is `value` and whose other elements are zero.
+pub fn mock_digest(value: u64) -> Digest {
+    Digest::from([Felt::new(value), ZERO, ZERO, ZERO])
+}
+
+impl ProvenTransactionBuilder {
+    /// Returns a [ProvenTransactionBuilder] for a transaction which carries a dummy proof.
+    ///
+    /// The resulting transaction cannot be verified, and is meant to test the processing of
+    /// proven transactions, e.g. their aggregation into batches and blocks.
+    pub fn mock(
+        account_id: AccountId,
+        initial_account_hash: Digest,
+        final_account_hash: Digest,
+        block_ref: Digest,
+        expiration_block_num: u32,
+    ) -> Self {
+        let proof = ExecutionProof::read_from_bytes(&DUMMY_PROOF_BYTES)
+            .expect("dummy proof should be valid");
+        Self::new(
+            account_id,
+            initial_account_hash,
+            final_account_hash,
+            block_ref,
+            expiration_block_num,
+            proof,
+        )
+    }
+
+    /// Returns a [ProvenTransactionBuilder] for a transaction of the account with ID
+    /// [mock_account_id] which carries a dummy proof.
+    ///
+    /// The transaction updates the state hash of the account from `mock_digest(initial_state)`
+    /// to `mock_digest(final_state)`, references the default block and never expires.
+    pub fn mock_update(initial_state: u64, final_state: u64) -> Self {
+        Self::mock(
+            mock_account_id(),
+            mock_digest(initial_state),
+            mock_digest(final_state),
+            Digest::default(),
+            u32::MAX,
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use super::Word;
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// ACCOUNT EVENT
+// ================================================================================================
+
+/// A user-defined event emitted by the account code during transaction execution.
+///
+/// Account events allow account components to signal application-level occurrences (e.g., a
+/// token transfer or a filled order) to the clients observing the transaction. An event consists
+/// of:
+/// - event_id: an identifier of the event. Event IDs are defined by the account components, but
+///   must fall into the range reserved for account events by the transaction kernel.
+/// - data: a single word of arbitrary data attached to the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountEvent {
+    event_id: u32,
+    data: Word,
+}
+
+impl AccountEvent {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountEvent] instantiated from the provided event ID and data.
+    pub fn new(event_id: u32, data: Word) -> Self {
+        Self { event_id, data }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this event.
+    pub fn event_id(&self) -> u32 {
+        self.event_id
+    }
+
+    /// Returns the data word attached to this event.
+    pub fn data(&self) -> Word {
+        self.data
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountEvent {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.event_id);
+        target.write(self.data);
+    }
+}
+
+impl Deserializable for AccountEvent {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let event_id = source.read_u32()?;
+        let data = source.read()?;
+
+        Ok(Self { event_id, data })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::AccountEvent;
+    use crate::{Felt, ONE, ZERO};
+
+    #[test]
+    fn account_event_serde() {
+        let event = AccountEvent::new(0x3_0001, [ONE, ZERO, Felt::new(7), Felt::new(42)]);
+        let deserialized = AccountEvent::read_from_bytes(&event.to_bytes()).unwrap();
+        assert_eq!(event, deserialized);
+    }
+}
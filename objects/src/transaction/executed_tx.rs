@@ -2,11 +2,18 @@ use alloc::vec::Vec;
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
-    InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
+    AccountDelta, AccountEvent, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputAccount,
+    InputNote, InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
     TransactionOutputs, TransactionWitness,
 };
-use crate::accounts::AccountCode;
+use crate::{
+    accounts::AccountCode,
+    utils::{
+        read_bounded_vec,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
+    Digest,
+};
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -79,7 +86,7 @@ impl ExecutedTransaction {
     }
 
     /// Returns the description of the account before the transaction was executed.
-    pub fn initial_account(&self) -> &Account {
+    pub fn initial_account(&self) -> &InputAccount {
         self.tx_inputs.account()
     }
 
@@ -98,6 +105,11 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the user-defined events emitted by the account code in this transaction.
+    pub fn account_events(&self) -> &[AccountEvent] {
+        &self.tx_outputs.account_events
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -147,6 +159,48 @@ impl ExecutedTransaction {
     }
 }
 
+impl Serializable for ExecutedTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.tx_outputs.write_into(target);
+        self.account_codes.write_into(target);
+        self.account_delta.write_into(target);
+        self.tx_args.write_into(target);
+        self.advice_witness.write_into(target);
+        self.tx_measurements.write_into(target);
+    }
+}
+
+impl Deserializable for ExecutedTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let tx_outputs = TransactionOutputs::read_from(source)?;
+        let account_codes = Vec::<AccountCode>::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+        let advice_witness = AdviceInputs::read_from(source)?;
+        let tx_measurements = TransactionMeasurements::read_from(source)?;
+
+        if tx_inputs.account().id() != tx_outputs.account.id() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "input account ID {} does not match output account ID {}",
+                tx_inputs.account().id(),
+                tx_outputs.account.id()
+            )));
+        }
+
+        Ok(Self::new(
+            tx_inputs,
+            tx_outputs,
+            account_codes,
+            account_delta,
+            tx_args,
+            advice_witness,
+            tx_measurements,
+        ))
+    }
+}
+
 impl From<ExecutedTransaction> for TransactionWitness {
     fn from(tx: ExecutedTransaction) -> Self {
         let (_, _, tx_witness, _) = tx.into_parts();
@@ -166,7 +220,7 @@ impl From<ExecutedTransaction> for TransactionMeasurements {
 
 /// Stores the resulting number of cycles for each transaction execution stage obtained from the
 /// `TransactionProgress` struct.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionMeasurements {
     pub prologue: usize,
     pub notes_processing: usize,
@@ -188,3 +242,37 @@ impl TransactionMeasurements {
         total_cycles.next_power_of_two()
     }
 }
+
+impl Serializable for TransactionMeasurements {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.prologue);
+        target.write_usize(self.notes_processing);
+        target.write_usize(self.note_execution.len());
+        for (note_id, cycles) in self.note_execution.iter() {
+            note_id.write_into(target);
+            target.write_usize(*cycles);
+        }
+        target.write_usize(self.tx_script_processing);
+        target.write_usize(self.epilogue);
+    }
+}
+
+impl Deserializable for TransactionMeasurements {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prologue = source.read_usize()?;
+        let notes_processing = source.read_usize()?;
+        // each entry holds a note ID and a cycle count which takes at least one byte
+        let note_execution: Vec<(NoteId, usize)> =
+            read_bounded_vec(source, Digest::SERIALIZED_SIZE + 1)?;
+        let tx_script_processing = source.read_usize()?;
+        let epilogue = source.read_usize()?;
+
+        Ok(Self {
+            prologue,
+            notes_processing,
+            note_execution,
+            tx_script_processing,
+            epilogue,
+        })
+    }
+}
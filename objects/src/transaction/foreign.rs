@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+
+use miden_crypto::merkle::MerklePath;
+
+use super::{AccountId, Digest};
+use crate::{
+    accounts::{Account, PartialAccount, StorageSlot},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    TransactionInputError,
+};
+
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Contains the data required to invoke procedures of a foreign account from within a
+/// transaction.
+///
+/// The foreign account inputs consist of:
+/// - The foreign account as a [PartialAccount], i.e., its header, storage header and code, as well
+///   as optional inclusion proofs for the entries of its storage maps. These are required only for
+///   the map entries which are accessed by the invoked procedures.
+/// - A Merkle path authenticating the account hash against the account root of a block header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    partial_account: PartialAccount,
+    account_proof: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated from the provided partial account and the
+    /// Merkle path of the account in the account tree.
+    ///
+    /// The Merkle path is not validated by the constructor; use [Self::verify()] to check it
+    /// against the account root of a block.
+    pub fn new(partial_account: PartialAccount, account_proof: MerklePath) -> Self {
+        Self { partial_account, account_proof }
+    }
+
+    /// Returns new [ForeignAccountInputs] for the provided account and the Merkle path of the
+    /// account in the account tree.
+    ///
+    /// Inclusion proofs for all entries of all storage maps of the account are included.
+    pub fn from_account(account: &Account, account_proof: MerklePath) -> Self {
+        let storage_map_proofs = account
+            .storage()
+            .slots()
+            .iter()
+            .filter_map(|slot| match slot {
+                StorageSlot::Map(map) => Some(map),
+                StorageSlot::Value(_) => None,
+            })
+            .flat_map(|map| {
+                map.leaves()
+                    .filter_map(|(_, leaf)| leaf.entries().first().map(|(key, _)| map.open(key)))
+            })
+            .collect();
+
+        let partial_account = PartialAccount::new(
+            account.into(),
+            account.storage().get_header(),
+            account.code().clone(),
+            storage_map_proofs,
+            Vec::new(),
+        )
+        .expect("the parts of an account should be consistent with its header");
+
+        Self::new(partial_account, account_proof)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the foreign account.
+    pub fn account_id(&self) -> AccountId {
+        self.partial_account.id()
+    }
+
+    /// Returns the foreign account as a partial account.
+    pub fn partial_account(&self) -> &PartialAccount {
+        &self.partial_account
+    }
+
+    /// Returns the Merkle path of the foreign account hash in the account tree.
+    pub fn account_proof(&self) -> &MerklePath {
+        &self.account_proof
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies these inputs against the provided account root.
+    ///
+    /// The consistency of the partial account with its header is guaranteed by [PartialAccount]
+    /// itself, so only the Merkle path of the account hash needs to be checked.
+    ///
+    /// # Errors
+    /// Returns an error if the account hash is not a leaf of the account tree with the specified
+    /// root.
+    pub fn verify(&self, account_root: Digest) -> Result<(), TransactionInputError> {
+        let account_id = self.account_id();
+
+        self.account_proof
+            .verify(account_id.into(), self.partial_account.hash(), &account_root)
+            .map_err(|_| TransactionInputError::ForeignAccountNotInAccountTree(account_id))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.partial_account.write_into(target);
+        self.account_proof.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let partial_account = PartialAccount::read_from(source)?;
+        let account_proof = MerklePath::read_from(source)?;
+
+        Ok(Self::new(partial_account, account_proof))
+    }
+}
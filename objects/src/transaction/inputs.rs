@@ -1,9 +1,11 @@
-use alloc::{collections::BTreeSet, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
 use core::fmt::Debug;
 
 use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
 use crate::{
-    accounts::{Account, AccountId},
+    accounts::{
+        Account, AccountCode, AccountHeader, AccountId, AccountStorageHeader, PartialAccount,
+    },
     notes::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     TransactionInputError, MAX_INPUT_NOTES_PER_TX,
@@ -15,7 +17,7 @@ use crate::{
 /// Contains the data required to execute a transaction.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionInputs {
-    account: Account,
+    account: InputAccount,
     account_seed: Option<Word>,
     block_header: BlockHeader,
     block_chain: ChainMmr,
@@ -31,13 +33,16 @@ impl TransactionInputs {
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - A partial account is provided for a new account.
     pub fn new(
-        account: Account,
+        account: impl Into<InputAccount>,
         account_seed: Option<Word>,
         block_header: BlockHeader,
         block_chain: ChainMmr,
         input_notes: InputNotes<InputNote>,
     ) -> Result<Self, TransactionInputError> {
+        let account = account.into();
+
         // validate the seed
         validate_account_seed(&account, account_seed)?;
 
@@ -87,7 +92,7 @@ impl TransactionInputs {
     // --------------------------------------------------------------------------------------------
 
     /// Returns account against which the transaction is to be executed.
-    pub fn account(&self) -> &Account {
+    pub fn account(&self) -> &InputAccount {
         &self.account
     }
 
@@ -118,7 +123,7 @@ impl TransactionInputs {
     /// Consumes these transaction inputs and returns their underlying components.
     pub fn into_parts(
         self,
-    ) -> (Account, Option<Word>, BlockHeader, ChainMmr, InputNotes<InputNote>) {
+    ) -> (InputAccount, Option<Word>, BlockHeader, ChainMmr, InputNotes<InputNote>) {
         (
             self.account,
             self.account_seed,
@@ -141,7 +146,7 @@ impl Serializable for TransactionInputs {
 
 impl Deserializable for TransactionInputs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let account = Account::read_from(source)?;
+        let account = InputAccount::read_from(source)?;
         let account_seed = source.read()?;
         let block_header = BlockHeader::read_from(source)?;
         let block_chain = ChainMmr::read_from(source)?;
@@ -151,6 +156,152 @@ impl Deserializable for TransactionInputs {
     }
 }
 
+// INPUT ACCOUNT
+// ================================================================================================
+
+/// The account against which a transaction is executed.
+///
+/// The account can be provided either in full, or as a [PartialAccount]. In the latter case, the
+/// storage map entries and vault assets which are not included in the partial account must be
+/// loaded on demand during transaction execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputAccount {
+    Full(Account),
+    Partial(Box<PartialAccount>),
+}
+
+impl InputAccount {
+    /// Returns the ID of the account.
+    pub fn id(&self) -> AccountId {
+        match self {
+            Self::Full(account) => account.id(),
+            Self::Partial(account) => account.id(),
+        }
+    }
+
+    /// Returns the nonce of the account.
+    pub fn nonce(&self) -> Felt {
+        match self {
+            Self::Full(account) => account.nonce(),
+            Self::Partial(account) => account.nonce(),
+        }
+    }
+
+    /// Returns the hash of the account.
+    pub fn hash(&self) -> Digest {
+        match self {
+            Self::Full(account) => account.hash(),
+            Self::Partial(account) => account.hash(),
+        }
+    }
+
+    /// Returns the hash of the account as used for the initial account state hash in transaction
+    /// proofs. See [Account::init_hash()] for details.
+    pub fn init_hash(&self) -> Digest {
+        if self.is_new() {
+            Digest::default()
+        } else {
+            self.hash()
+        }
+    }
+
+    /// Returns the root of the account's asset vault.
+    pub fn vault_root(&self) -> Digest {
+        match self {
+            Self::Full(account) => account.vault().commitment(),
+            Self::Partial(account) => account.header().vault_root(),
+        }
+    }
+
+    /// Returns the commitment to the account's storage.
+    pub fn storage_commitment(&self) -> Digest {
+        match self {
+            Self::Full(account) => account.storage().commitment(),
+            Self::Partial(account) => account.header().storage_commitment(),
+        }
+    }
+
+    /// Returns the header of the account's storage.
+    pub fn storage_header(&self) -> AccountStorageHeader {
+        match self {
+            Self::Full(account) => account.storage().get_header(),
+            Self::Partial(account) => account.storage_header().clone(),
+        }
+    }
+
+    /// Returns the code of the account.
+    pub fn code(&self) -> &AccountCode {
+        match self {
+            Self::Full(account) => account.code(),
+            Self::Partial(account) => account.code(),
+        }
+    }
+
+    /// Returns true if the account has not yet been recorded on chain.
+    pub fn is_new(&self) -> bool {
+        match self {
+            Self::Full(account) => account.is_new(),
+            Self::Partial(account) => account.is_new(),
+        }
+    }
+
+    /// Returns the full account, or None if only a partial account was provided.
+    pub fn full_account(&self) -> Option<&Account> {
+        match self {
+            Self::Full(account) => Some(account),
+            Self::Partial(_) => None,
+        }
+    }
+}
+
+impl From<Account> for InputAccount {
+    fn from(account: Account) -> Self {
+        Self::Full(account)
+    }
+}
+
+impl From<PartialAccount> for InputAccount {
+    fn from(account: PartialAccount) -> Self {
+        Self::Partial(Box::new(account))
+    }
+}
+
+impl From<&InputAccount> for AccountHeader {
+    fn from(account: &InputAccount) -> Self {
+        match account {
+            InputAccount::Full(account) => account.into(),
+            InputAccount::Partial(account) => account.header().clone(),
+        }
+    }
+}
+
+impl Serializable for InputAccount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Full(account) => {
+                target.write_u8(0);
+                account.write_into(target);
+            },
+            Self::Partial(account) => {
+                target.write_u8(1);
+                account.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for InputAccount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Full(Account::read_from(source)?)),
+            1 => Ok(Self::Partial(Box::new(PartialAccount::read_from(source)?))),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid input account tag: {tag}")))
+            },
+        }
+    }
+}
+
 // TO INPUT NOTE COMMITMENT
 // ================================================================================================
 
@@ -469,13 +620,19 @@ impl Deserializable for InputNote {
 
 /// Validates that the provided seed is valid for this account.
 pub fn validate_account_seed(
-    account: &Account,
+    account: &InputAccount,
     account_seed: Option<Word>,
 ) -> Result<(), TransactionInputError> {
+    if let InputAccount::Partial(account) = account {
+        if account.is_new() {
+            return Err(TransactionInputError::PartialAccountMustNotBeNew(account.id()));
+        }
+    }
+
     match (account.is_new(), account_seed) {
         (true, Some(seed)) => {
             let account_id =
-                AccountId::new(seed, account.code().commitment(), account.storage().commitment())
+                AccountId::new(seed, account.code().commitment(), account.storage_commitment())
                     .map_err(TransactionInputError::InvalidAccountSeed)?;
             if account_id != account.id() {
                 return Err(TransactionInputError::InconsistentAccountSeed {
@@ -1,26 +1,32 @@
 use super::{
-    accounts::{Account, AccountDelta, AccountHeader, AccountId},
+    accounts::{AccountDelta, AccountHeader, AccountId},
     notes::{NoteId, Nullifier},
     vm::AdviceInputs,
     BlockHeader, Digest, Felt, Hasher, Word, WORD_SIZE, ZERO,
 };
 
+mod account_event;
 mod chain_mmr;
 mod executed_tx;
+mod foreign;
 mod inputs;
 mod outputs;
 mod proven_tx;
+mod receipt;
 mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
+pub use account_event::AccountEvent;
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
-pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use foreign::ForeignAccountInputs;
+pub use inputs::{InputAccount, InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
 };
+pub use receipt::TransactionReceipt;
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
 pub use tx_witness::TransactionWitness;
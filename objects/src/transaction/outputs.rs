@@ -4,6 +4,7 @@ use core::fmt::Debug;
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
+use super::AccountEvent;
 use crate::{
     accounts::AccountHeader,
     notes::{compute_note_hash, Note, NoteAssets, NoteHeader, NoteId, NoteMetadata, PartialNote},
@@ -21,6 +22,33 @@ pub struct TransactionOutputs {
     pub output_notes: OutputNotes,
     /// Defines up to which block the transaction is considered valid.
     pub expiration_block_num: u32,
+    /// List of user-defined events emitted by the account code, in the order of emission.
+    pub account_events: Vec<AccountEvent>,
+}
+
+impl Serializable for TransactionOutputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.output_notes.write_into(target);
+        target.write_u32(self.expiration_block_num);
+        self.account_events.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionOutputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = AccountHeader::read_from(source)?;
+        let output_notes = OutputNotes::read_from(source)?;
+        let expiration_block_num = source.read_u32()?;
+        let account_events = Vec::<AccountEvent>::read_from(source)?;
+
+        Ok(Self {
+            account,
+            output_notes,
+            expiration_block_num,
+            account_events,
+        })
+    }
 }
 
 // OUTPUT NOTES
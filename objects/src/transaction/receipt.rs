@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+
+use miden_crypto::merkle::SmtProof;
+
+use super::TransactionId;
+use crate::{
+    accounts::{
+        Account, AccountDelta, AccountHeader, AccountId, AccountStorageHeader, StorageSlot,
+    },
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountError, Digest, ZERO,
+};
+
+// TRANSACTION RECEIPT
+// ================================================================================================
+
+/// A selective-disclosure receipt of the changes a transaction made to an account.
+///
+/// For private accounts, a [super::ProvenTransaction] contains only the initial and final state
+/// hashes of the account. A receipt allows the account owner to prove to a third party what the
+/// transaction did to the account without revealing the rest of the account. It consists of:
+/// - The ID of the transaction the receipt was issued for.
+/// - The initial and final headers of the account, which hash to the initial and final account
+///   state hashes of the transaction.
+/// - The initial storage header of the account, which commits to the initial storage commitment.
+///   Note that this reveals the values of all value slots, but only the roots of storage maps.
+/// - The [AccountDelta] of the transaction.
+/// - Openings of the initial storage map entries and vault assets which are updated by the delta.
+///
+/// A receipt is verified by applying the delta to the opened entries, and checking that the
+/// resulting storage commitment and vault root match the ones in the final account header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionReceipt {
+    transaction_id: TransactionId,
+    initial_header: AccountHeader,
+    final_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_delta: AccountDelta,
+    storage_map_proofs: Vec<SmtProof>,
+    vault_proofs: Vec<SmtProof>,
+}
+
+impl TransactionReceipt {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionReceipt] for the transaction with the specified ID, which applied
+    /// the provided delta to the provided initial state of the account.
+    ///
+    /// # Errors
+    /// Returns an error if the delta cannot be applied to the account.
+    pub fn new(
+        transaction_id: TransactionId,
+        initial_account: &Account,
+        account_delta: AccountDelta,
+    ) -> Result<Self, AccountError> {
+        let mut final_account = initial_account.clone();
+        final_account.apply_delta(&account_delta)?;
+
+        let storage_slots = initial_account.storage().slots();
+        let mut storage_map_proofs = Vec::new();
+        for (&slot_index, map_delta) in account_delta.storage().maps() {
+            let map = match storage_slots.get(slot_index as usize) {
+                Some(StorageSlot::Map(map)) => map,
+                _ => return Err(AccountError::StorageSlotNotMap(slot_index)),
+            };
+            storage_map_proofs.extend(map_delta.leaves().keys().map(|key| map.open(key)));
+        }
+
+        let vault_tree = initial_account.vault().asset_tree();
+        let vault_proofs = account_delta
+            .vault()
+            .fungible()
+            .iter()
+            .map(|(&faucet_id, _)| fungible_asset_vault_key(faucet_id))
+            .chain(
+                account_delta
+                    .vault()
+                    .non_fungible()
+                    .iter()
+                    .map(|(asset, _)| Digest::from(asset.vault_key())),
+            )
+            .map(|vault_key| vault_tree.open(&vault_key))
+            .collect();
+
+        Ok(Self {
+            transaction_id,
+            initial_header: initial_account.into(),
+            final_header: (&final_account).into(),
+            storage_header: initial_account.storage().get_header(),
+            account_delta,
+            storage_map_proofs,
+            vault_proofs,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the transaction this receipt was issued for.
+    pub fn transaction_id(&self) -> TransactionId {
+        self.transaction_id
+    }
+
+    /// Returns the ID of the account updated by the transaction.
+    pub fn account_id(&self) -> AccountId {
+        self.initial_header.id()
+    }
+
+    /// Returns the header of the account before the transaction was executed.
+    pub fn initial_header(&self) -> &AccountHeader {
+        &self.initial_header
+    }
+
+    /// Returns the header of the account after the transaction was executed.
+    pub fn final_header(&self) -> &AccountHeader {
+        &self.final_header
+    }
+
+    /// Returns the storage header of the account before the transaction was executed.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the changes the transaction made to the account.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the openings of the initial storage map entries updated by the transaction.
+    pub fn storage_map_proofs(&self) -> &[SmtProof] {
+        &self.storage_map_proofs
+    }
+
+    /// Returns the openings of the initial vault assets updated by the transaction.
+    pub fn vault_proofs(&self) -> &[SmtProof] {
+        &self.vault_proofs
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the key under which the fungible asset issued by the specified faucet is stored in an
+/// asset vault.
+fn fungible_asset_vault_key(faucet_id: AccountId) -> Digest {
+    Digest::from([ZERO, ZERO, ZERO, faucet_id.into()])
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionReceipt {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.transaction_id.write_into(target);
+        self.initial_header.write_into(target);
+        self.final_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_delta.write_into(target);
+        self.storage_map_proofs.write_into(target);
+        self.vault_proofs.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionReceipt {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            transaction_id: TransactionId::read_from(source)?,
+            initial_header: AccountHeader::read_from(source)?,
+            final_header: AccountHeader::read_from(source)?,
+            storage_header: AccountStorageHeader::read_from(source)?,
+            account_delta: AccountDelta::read_from(source)?,
+            storage_map_proofs: Vec::<SmtProof>::read_from(source)?,
+            vault_proofs: Vec::<SmtProof>::read_from(source)?,
+        })
+    }
+}
@@ -1,4 +1,9 @@
-use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    sync::Arc,
+    vec::Vec,
+};
 use core::ops::Deref;
 
 use assembly::{Assembler, Compile};
@@ -10,10 +15,11 @@ use vm_core::{
 };
 use vm_processor::{AdviceInputs, AdviceMap, DeserializationError};
 
-use super::{Digest, Felt, Word};
+use super::{Digest, Felt, ForeignAccountInputs, Word, ZERO};
 use crate::{
+    accounts::AccountId,
     notes::{NoteDetails, NoteId},
-    TransactionScriptError,
+    TransactionInputError, TransactionScriptError,
 };
 
 // TRANSACTION ARGS
@@ -27,11 +33,14 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - Foreign accounts: IDs of the accounts whose procedures may be invoked by the transaction. The
+///   inputs for these accounts are fetched by the transaction executor before execution.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    foreign_accounts: BTreeSet<AccountId>,
 }
 
 impl TransactionArgs {
@@ -59,6 +68,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            foreign_accounts: BTreeSet::new(),
         }
     }
 
@@ -79,6 +89,16 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns the provided [TransactionArgs] with the specified accounts added to the set of
+    /// foreign accounts whose procedures may be invoked by the transaction.
+    pub fn with_foreign_accounts(
+        mut self,
+        account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> Self {
+        self.foreign_accounts.extend(account_ids);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -97,6 +117,11 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns the IDs of the foreign accounts whose procedures may be invoked by the transaction.
+    pub fn foreign_accounts(&self) -> &BTreeSet<AccountId> {
+        &self.foreign_accounts
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -141,6 +166,67 @@ impl TransactionArgs {
         }
     }
 
+    /// Populates the advice inputs with the data of the specified foreign account.
+    ///
+    /// The advice inputs' map is extended with the following keys:
+    ///
+    /// - [account_id, 0, 0, 0] |-> [[account_id, 0, 0, nonce], VAULT_ROOT, STORAGE_COMMITMENT,
+    ///   CODE_COMMITMENT].
+    /// - storage_commitment |-> storage slot data.
+    /// - code_commitment |-> account procedure data.
+    /// - leaf hash |-> leaf data, for every leaf of the storage map proofs.
+    ///
+    /// The advice inputs' Merkle store is extended with the Merkle path of the account in the
+    /// account tree and with the paths of the storage map proofs.
+    ///
+    /// Note: the inputs are not validated by this method, use [ForeignAccountInputs::verify()] to
+    /// check them against the account root of the reference block.
+    ///
+    /// # Errors
+    /// Returns an error if the account ID is not a valid index for the Merkle path of the account
+    /// in the account tree. The advice inputs are left unchanged in this case.
+    pub fn add_foreign_account_inputs(
+        &mut self,
+        inputs: &ForeignAccountInputs,
+    ) -> Result<(), TransactionInputError> {
+        let partial_account = inputs.partial_account();
+        let header = partial_account.header();
+        let account_id = header.id();
+        let account_key = Digest::from([account_id.into(), ZERO, ZERO, ZERO]);
+        let account_data = [
+            &[account_id.into(), ZERO, ZERO, header.nonce()],
+            header.vault_root().as_elements(),
+            header.storage_commitment().as_elements(),
+            header.code_commitment().as_elements(),
+        ]
+        .concat();
+        let account_nodes = inputs
+            .account_proof()
+            .inner_nodes(account_id.into(), header.hash())
+            .map_err(|_| TransactionInputError::ForeignAccountNotInAccountTree(account_id))?;
+
+        self.advice_inputs.extend_map([
+            (account_key, account_data),
+            (header.storage_commitment(), partial_account.storage_header().as_elements()),
+            (header.code_commitment(), partial_account.code().as_elements()),
+        ]);
+
+        self.advice_inputs.extend_merkle_store(account_nodes);
+
+        for proof in partial_account.storage_map_proofs() {
+            let leaf = proof.leaf();
+            self.advice_inputs.extend_merkle_store(
+                proof
+                    .path()
+                    .inner_nodes(leaf.index().value(), leaf.hash())
+                    .expect("storage map proof paths always have the depth of the storage map"),
+            );
+            self.advice_inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+        }
+
+        Ok(())
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
@@ -157,6 +243,7 @@ impl Serializable for TransactionArgs {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.foreign_accounts.write_into(target);
     }
 }
 
@@ -165,8 +252,14 @@ impl Deserializable for TransactionArgs {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let foreign_accounts = BTreeSet::<AccountId>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_inputs,
+            foreign_accounts,
+        })
     }
 }
 
@@ -273,10 +366,18 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use miden_crypto::merkle::MerklePath;
     use vm_core::utils::{Deserializable, Serializable};
     use vm_processor::AdviceMap;
 
-    use crate::transaction::TransactionArgs;
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, AccountCode,
+            AccountHeader, AccountId, AccountStorageHeader, PartialAccount,
+        },
+        transaction::{ForeignAccountInputs, TransactionArgs},
+        Digest, Felt, TransactionInputError,
+    };
 
     #[test]
     fn test_tx_args_serialization() {
@@ -286,4 +387,28 @@ mod tests {
 
         assert_eq!(args, decoded);
     }
+
+    #[test]
+    fn foreign_account_inputs_with_invalid_account_path_are_rejected() {
+        let account_code = AccountCode::mock();
+        let storage_header = AccountStorageHeader::new(vec![]);
+        let header = AccountHeader::new(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+            Felt::new(1),
+            Digest::default(),
+            storage_header.commitment(),
+            account_code.commitment(),
+        );
+        // an empty path cannot open any leaf other than the root
+        let partial_account =
+            PartialAccount::new(header, storage_header, account_code, vec![], vec![]).unwrap();
+        let inputs = ForeignAccountInputs::new(partial_account, MerklePath::new(vec![]));
+
+        let mut args = TransactionArgs::default();
+        assert_eq!(
+            args.add_foreign_account_inputs(&inputs),
+            Err(TransactionInputError::ForeignAccountNotInAccountTree(inputs.account_id()))
+        );
+        assert_eq!(args, TransactionArgs::default());
+    }
 }
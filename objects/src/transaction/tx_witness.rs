@@ -23,10 +23,9 @@ use crate::accounts::AccountCode;
 /// - Advice witness which contains all data requested by the VM from the advice provider while
 ///   executing the transaction program.
 ///
-/// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
-/// and tx outputs; account codes and a subset of that data in advice inputs).
-/// We should optimize it to contain only the minimum data required for executing/proving the
-/// transaction.
+/// The witness of an executed transaction contains redundant data (e.g., the full account, and the
+/// advice data which can be derived from the transaction inputs). `miden_tx::prune_witness` can be
+/// used to reduce the witness to the minimum data required for proving the transaction.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransactionWitness {
     pub tx_inputs: TransactionInputs,